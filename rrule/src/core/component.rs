@@ -0,0 +1,244 @@
+use crate::parser::{
+    parse_components, split_unquoted, unescape_text, unquote, ComponentGrammar, ParseError,
+};
+use crate::{RRuleError, RRuleSet};
+use std::str::FromStr;
+
+/// The kind of an iCalendar component that can hold recurrence properties.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ComponentKind {
+    /// A `VEVENT` component.
+    Event,
+    /// A `VTODO` component.
+    Todo,
+    /// A `VJOURNAL` component.
+    Journal,
+}
+
+impl ComponentKind {
+    /// Returns the kind of component for the name used in `BEGIN:<name>`, if it
+    /// is a component that can hold recurrence properties.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match &name.to_uppercase()[..] {
+            "VEVENT" => Some(Self::Event),
+            "VTODO" => Some(Self::Todo),
+            "VJOURNAL" => Some(Self::Journal),
+            _ => None,
+        }
+    }
+}
+
+/// A property of a component which isn't used for the recurrence, like `UID` or `SUMMARY`.
+///
+/// The value is kept as it was found in the input, and as text with the escaped
+/// characters replaced.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Property {
+    name: String,
+    parameters: Vec<(String, String)>,
+    value: String,
+    text: String,
+}
+
+impl Property {
+    pub(crate) fn new(name: &str, parameters: &str, value: &str) -> Self {
+        let parameters = split_unquoted(parameters, ';')
+            .into_iter()
+            .filter(|parameter| !parameter.is_empty())
+            .map(|parameter| match parameter.split_once('=') {
+                Some((key, value)) => (key.to_uppercase(), unquote(value).to_string()),
+                None => (parameter.to_uppercase(), String::new()),
+            })
+            .collect();
+
+        Self {
+            name: name.to_uppercase(),
+            parameters,
+            value: value.into(),
+            text: unescape_text(value),
+        }
+    }
+
+    /// Returns the name of the property in uppercase, for example `SUMMARY`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the parameters of the property as key value pairs.
+    /// The keys are in uppercase and quoted values are unquoted.
+    #[must_use]
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    /// Returns the value of the parameter with the given name, if present.
+    #[must_use]
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the raw value of the property.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the value of the property as text, with escaped characters like `\,`
+    /// and `\n` replaced by the characters they represent.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A `VEVENT`, `VTODO` or `VJOURNAL` component together with its recurrence.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Component {
+    kind: ComponentKind,
+    rrule_set: Option<RRuleSet>,
+    properties: Vec<Property>,
+}
+
+impl Component {
+    /// Returns the kind of the component.
+    #[must_use]
+    pub fn kind(&self) -> ComponentKind {
+        self.kind
+    }
+
+    /// Returns the recurrence of the component.
+    ///
+    /// This is `None` if the component doesn't have a `DTSTART`. When the component
+    /// has a `DTSTART`, but no `RRULE` or `RDATE`, the set contains the `DTSTART` as
+    /// its only `RDATE`, so that it has exactly one occurrence.
+    #[must_use]
+    pub fn rrule_set(&self) -> Option<&RRuleSet> {
+        self.rrule_set.as_ref()
+    }
+
+    /// Returns all the properties of the component that aren't part of the recurrence.
+    #[must_use]
+    pub fn properties(&self) -> &[Property] {
+        &self.properties
+    }
+
+    /// Returns the first property with the given name, if present.
+    #[must_use]
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .find(|property| property.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the unescaped value of the `UID` property, if present.
+    #[must_use]
+    pub fn uid(&self) -> Option<&str> {
+        self.property("UID").map(Property::text)
+    }
+
+    /// Returns the unescaped value of the `SUMMARY` property, if present.
+    #[must_use]
+    pub fn summary(&self) -> Option<&str> {
+        self.property("SUMMARY").map(Property::text)
+    }
+}
+
+impl TryFrom<ComponentGrammar> for Component {
+    type Error = RRuleError;
+
+    fn try_from(component: ComponentGrammar) -> Result<Self, Self::Error> {
+        let ComponentGrammar {
+            kind,
+            grammar,
+            properties,
+        } = component;
+        let has_date_generation_rules = grammar.has_date_generation_rules();
+
+        let rrule_set = match grammar.start {
+            Some(start) => {
+                let rrule_set = RRuleSet::from_start_date(&start)
+                    .set_from_content_lines(grammar.content_lines)?;
+                if has_date_generation_rules {
+                    Some(rrule_set)
+                } else {
+                    let dt_start = *rrule_set.get_dt_start();
+                    Some(rrule_set.rdate(dt_start))
+                }
+            }
+            None if grammar.content_lines.is_empty() => None,
+            None => return Err(ParseError::MissingStartDate.into()),
+        };
+
+        Ok(Self {
+            kind,
+            rrule_set,
+            properties,
+        })
+    }
+}
+
+/// The recurring components of an iCalendar object, like a `.ics` file.
+///
+/// All the `VEVENT`, `VTODO` and `VJOURNAL` components are collected, both when they
+/// are wrapped in a `VCALENDAR` and when they are not. Properties that aren't related
+/// to the recurrence are kept as raw [`Property`]s and other components, like
+/// `VTIMEZONE` or `VALARM`, are ignored.
+///
+/// # Usage
+///
+/// ```
+/// use rrule::Calendar;
+///
+/// let calendar: Calendar = "BEGIN:VCALENDAR\n\
+///     BEGIN:VEVENT\n\
+///     UID:standup\n\
+///     DTSTART:20120201T093000Z\n\
+///     RRULE:FREQ=DAILY;COUNT=3\n\
+///     END:VEVENT\n\
+///     END:VCALENDAR".parse().unwrap();
+///
+/// let event = &calendar.components()[0];
+/// assert_eq!(event.uid(), Some("standup"));
+/// assert_eq!(event.rrule_set().unwrap().clone().all(10).dates.len(), 3);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Calendar {
+    components: Vec<Component>,
+}
+
+impl Calendar {
+    /// Returns the recurring components in the order they were found.
+    #[must_use]
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+
+    /// Consumes the calendar and returns its components.
+    #[must_use]
+    pub fn into_components(self) -> Vec<Component> {
+        self.components
+    }
+}
+
+impl FromStr for Calendar {
+    type Err = RRuleError;
+
+    /// Creates a [`Calendar`] from an iCalendar string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`] if the components aren't properly nested, or if the
+    /// recurrence properties of a component are invalid.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = parse_components(s)?
+            .into_iter()
+            .map(Component::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { components })
+    }
+}
@@ -1,5 +1,5 @@
 use super::timezone::Tz;
-use chrono::{Datelike, Duration, NaiveTime, Timelike};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Timelike};
 
 pub(crate) fn duration_from_midnight(time: NaiveTime) -> Duration {
     Duration::hours(i64::from(time.hour()))
@@ -27,6 +27,56 @@ pub(crate) fn get_second(dt: &chrono::DateTime<Tz>) -> u8 {
     u8::try_from(dt.second()).expect("second is between 0-59 which is covered by u8")
 }
 
+/// Returns the start of the day of `date` in the given timezone, which is the first instant
+/// of that local day. This is midnight, unless midnight is skipped by a DST change.
+pub(crate) fn start_of_day(date: NaiveDate, tz: Tz) -> chrono::DateTime<Tz> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    if let Some(dt) = tz.from_local_datetime(&midnight).earliest() {
+        return dt;
+    }
+    // Search the first instant of the day between UTC times which are on the day before
+    // and after it in any timezone.
+    let (mut before, mut after) = (-26 * 60 * 60, 26 * 60 * 60);
+    while after - before > 1 {
+        let middle = (before + after) / 2;
+        if tz
+            .from_utc_datetime(&(midnight + Duration::seconds(middle)))
+            .date_naive()
+            < date
+        {
+            before = middle;
+        } else {
+            after = middle;
+        }
+    }
+    tz.from_utc_datetime(&(midnight + Duration::seconds(after)))
+}
+
+/// Returns `time` on `date` in the given timezone. If the time is skipped by a DST change,
+/// it is counted from the start of the day instead.
+pub(crate) fn local_datetime(date: NaiveDate, time: NaiveTime, tz: Tz) -> chrono::DateTime<Tz> {
+    tz.from_local_datetime(&date.and_time(time))
+        .earliest()
+        .unwrap_or_else(|| start_of_day(date, tz) + duration_from_midnight(time))
+}
+
+/// Formats a datetime in `tz` in the extended format of jCal and xCal, like
+/// `2024-01-01T09:00:00Z`, with a `Z` suffix for UTC.
+#[cfg(any(feature = "jcal", feature = "xcal"))]
+pub(crate) fn datetime_to_extended_format(dt: &chrono::DateTime<Tz>, tz: Tz) -> String {
+    let dt = dt.with_timezone(&tz);
+    match tz {
+        Tz::Tz(chrono_tz::UTC) => dt.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        _ => dt.format("%Y-%m-%dT%H:%M:%S").to_string(),
+    }
+}
+
+/// Generates an iCalendar date string format with the prefix symbols, like `;VALUE=DATE:19970714`.
+/// The date is the one in the timezone of `dt`, which isn't printed, as dates can't have a `TZID`.
+pub(crate) fn date_to_ical_format(dt: &chrono::DateTime<Tz>) -> String {
+    format!(";VALUE=DATE:{}", dt.format("%Y%m%d"))
+}
+
 /// Generates an iCalendar date-time string format with the prefix symbols.
 /// Like: `:19970714T173000Z` or `;TZID=America/New_York:19970714T133000`
 /// ref: <https://tools.ietf.org/html/rfc5545#section-3.3.5>
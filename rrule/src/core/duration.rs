@@ -0,0 +1,234 @@
+use crate::parser::{parse_duration, ParseError};
+use crate::Tz;
+use chrono::{DateTime, Duration, TimeZone};
+#[cfg(feature = "serde")]
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A duration as described in [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.6),
+/// like `P1D`, `PT1H30M` or `-P2W`.
+///
+/// Weeks and days are nominal, so adding `P1D` keeps the time of day, even when
+/// the day has 23 or 25 hours because of a DST change. Hours, minutes and seconds
+/// are exact.
+///
+/// # Usage
+///
+/// ```
+/// use rrule::ICalDuration;
+///
+/// let duration: ICalDuration = "PT1H30M".parse().unwrap();
+/// assert_eq!(duration.hours, 1);
+/// assert_eq!(duration.minutes, 30);
+/// assert_eq!(duration.to_string(), "PT1H30M");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(DeserializeFromStr, SerializeDisplay))]
+pub struct ICalDuration {
+    /// If the duration goes back in time.
+    pub negative: bool,
+    /// Number of nominal weeks.
+    pub weeks: u32,
+    /// Number of nominal days.
+    pub days: u32,
+    /// Number of exact hours.
+    pub hours: u32,
+    /// Number of exact minutes.
+    pub minutes: u32,
+    /// Number of exact seconds.
+    pub seconds: u32,
+}
+
+impl ICalDuration {
+    /// Returns `true` if the duration has no length.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.weeks == 0 && self.days == 0 && self.exact_duration().is_zero()
+    }
+
+    /// Returns the datetime this duration after `dt`, or before `dt` if the duration
+    /// is negative, or `None` if that datetime can't be represented.
+    ///
+    /// The nominal part is added in the local time of the timezone of `dt`, and
+    /// the exact part is added afterwards.
+    #[must_use]
+    pub fn add_to(&self, dt: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let mut days = Duration::days(i64::from(self.weeks) * 7 + i64::from(self.days));
+        let mut exact = self.exact_duration();
+        if self.negative {
+            days = -days;
+            exact = -exact;
+        }
+
+        let nominal = if days.is_zero() {
+            *dt
+        } else {
+            let tz = dt.timezone();
+            match tz
+                .from_local_datetime(&dt.naive_local().checked_add_signed(days)?)
+                .earliest()
+            {
+                Some(nominal) => nominal,
+                // The local time doesn't exist because of a DST change, so add an exact duration.
+                None => dt.checked_add_signed(days)?,
+            }
+        };
+
+        nominal.checked_add_signed(exact)
+    }
+
+    fn exact_duration(&self) -> Duration {
+        Duration::hours(i64::from(self.hours))
+            + Duration::minutes(i64::from(self.minutes))
+            + Duration::seconds(i64::from(self.seconds))
+    }
+}
+
+impl FromStr for ICalDuration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_duration(s)
+    }
+}
+
+impl Display for ICalDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+
+        let has_time = self.hours > 0 || self.minutes > 0 || self.seconds > 0;
+        // Weeks can't be combined with other units.
+        if self.weeks > 0 && self.days == 0 && !has_time {
+            return write!(f, "{}W", self.weeks);
+        }
+
+        let days = u64::from(self.weeks) * 7 + u64::from(self.days);
+        if days > 0 {
+            write!(f, "{days}D")?;
+        }
+        if has_time || days == 0 {
+            write!(f, "T")?;
+            if self.hours > 0 {
+                write!(f, "{}H", self.hours)?;
+            }
+            if self.minutes > 0 {
+                write!(f, "{}M", self.minutes)?;
+            }
+            if self.seconds > 0 || !has_time {
+                write!(f, "{}S", self.seconds)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn formats_durations() {
+        let tests = [
+            (
+                ICalDuration {
+                    weeks: 2,
+                    ..Default::default()
+                },
+                "P2W",
+            ),
+            (
+                ICalDuration {
+                    weeks: 1,
+                    days: 1,
+                    ..Default::default()
+                },
+                "P8D",
+            ),
+            (
+                ICalDuration {
+                    negative: true,
+                    hours: 1,
+                    seconds: 5,
+                    ..Default::default()
+                },
+                "-PT1H5S",
+            ),
+            (
+                ICalDuration {
+                    days: 1,
+                    minutes: 30,
+                    ..Default::default()
+                },
+                "P1DT30M",
+            ),
+            (ICalDuration::default(), "PT0S"),
+        ];
+        for (input, expected_output) in tests {
+            assert_eq!(input.to_string(), expected_output);
+            assert_eq!(
+                ICalDuration::from_str(expected_output).map(|duration| duration.to_string()),
+                Ok(expected_output.to_string())
+            );
+        }
+
+        // The days are more than a `u32` can hold.
+        let longest = ICalDuration {
+            weeks: u32::MAX,
+            days: u32::MAX,
+            ..Default::default()
+        };
+        assert_eq!(longest.to_string(), "P34359738360D");
+    }
+
+    #[test]
+    fn adds_nominal_days_across_dst() {
+        let berlin = Tz::Europe__Berlin;
+        // Clocks moved forward on 2023-03-26 in Berlin.
+        let dt = berlin.with_ymd_and_hms(2023, 3, 25, 10, 0, 0).unwrap();
+
+        let one_day = ICalDuration {
+            days: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            one_day.add_to(&dt),
+            berlin.with_ymd_and_hms(2023, 3, 26, 10, 0, 0).single()
+        );
+
+        let twenty_four_hours = ICalDuration {
+            hours: 24,
+            ..Default::default()
+        };
+        assert_eq!(
+            twenty_four_hours.add_to(&dt),
+            berlin.with_ymd_and_hms(2023, 3, 26, 11, 0, 0).single()
+        );
+
+        let minus_one_week = ICalDuration {
+            negative: true,
+            weeks: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            minus_one_week.add_to(&dt),
+            berlin.with_ymd_and_hms(2023, 3, 18, 10, 0, 0).single()
+        );
+
+        let too_long = ICalDuration {
+            days: 99_999_999,
+            ..Default::default()
+        };
+        assert_eq!(too_long.add_to(&dt), None);
+        let too_long = ICalDuration {
+            hours: u32::MAX,
+            ..Default::default()
+        };
+        assert_eq!(too_long.add_to(&dt), None);
+    }
+}
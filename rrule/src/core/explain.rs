@@ -0,0 +1,119 @@
+use crate::{ExclusionSource, OccurrenceSource, Tz};
+use chrono::DateTime;
+
+/// A part of an [`crate::RRule`] which can accept or reject a date.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RRulePart {
+    /// The date has to be at or after `DTSTART`.
+    DtStart,
+    /// The date has to be at or before `UNTIL`.
+    Until,
+    /// The date has to be in a period which is a multiple of `INTERVAL` after `DTSTART`.
+    Interval,
+    /// `BYMONTH`
+    ByMonth,
+    /// `BYWEEKNO`
+    ByWeekNo,
+    /// The weekdays of `BYDAY` without a number, like `MO`.
+    ByWeekday,
+    /// The weekdays of `BYDAY` with a number, like `-1FR`.
+    ByNthWeekday,
+    /// `BYEASTER`
+    ByEaster,
+    /// `BYMONTHDAY`
+    ByMonthDay,
+    /// `BYYEARDAY`
+    ByYearDay,
+    /// `BYHOUR`
+    ByHour,
+    /// `BYMINUTE`
+    ByMinute,
+    /// `BYSECOND`
+    BySecond,
+    /// `BYSETPOS`
+    BySetPos,
+    /// The date has to be one of the first `COUNT` dates of the rule.
+    Count,
+}
+
+/// Whether a part of an [`crate::RRule`] accepts a date.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RRuleCheck {
+    /// The part of the rule.
+    pub part: RRulePart,
+    /// `true` if the part accepts the date.
+    pub passed: bool,
+}
+
+/// Why an [`crate::RRule`] does or doesn't generate a date.
+///
+/// Only the parts which are set in the rule are checked. `BYSETPOS` and `COUNT` are only
+/// checked when all the other parts accept the date.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RRuleExplanation {
+    /// The checks of the parts of the rule, in the order they are applied.
+    pub checks: Vec<RRuleCheck>,
+}
+
+impl RRuleExplanation {
+    /// Returns `true` if the rule generates the date.
+    #[must_use]
+    pub fn matches(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    /// Returns the parts of the rule which reject the date.
+    pub fn rejected_by(&self) -> impl Iterator<Item = RRulePart> + '_ {
+        self.checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.part)
+    }
+}
+
+/// Why a date is or isn't a recurrence of an [`crate::RRuleSet`].
+///
+/// Created with [`crate::RRuleSet::explain`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Explanation {
+    /// The explained date.
+    pub date: DateTime<Tz>,
+    /// The explanation for each rrule, in the order of [`crate::RRuleSet::get_rrule`].
+    pub rrules: Vec<RRuleExplanation>,
+    /// The rdate or period which starts at the date, if any.
+    pub rdate: Option<OccurrenceSource>,
+    /// The explanation for each exrule, in the order of [`crate::RRuleSet::get_exrule`].
+    pub exrules: Vec<RRuleExplanation>,
+    /// The index of the exdate which is equal to the date, if any.
+    pub exdate: Option<usize>,
+}
+
+impl Explanation {
+    /// Returns `true` if the date is a recurrence of the set.
+    #[must_use]
+    pub fn is_occurrence(&self) -> bool {
+        self.source().is_some() && self.excluded_by().is_none()
+    }
+
+    /// Returns the first rrule or the rdate which generates the date, if any.
+    #[must_use]
+    pub fn source(&self) -> Option<OccurrenceSource> {
+        self.rrules
+            .iter()
+            .position(RRuleExplanation::matches)
+            .map(OccurrenceSource::RRule)
+            .or(self.rdate)
+    }
+
+    /// Returns what removes the date from the set, if anything.
+    /// An exdate takes precedence over the exrules.
+    #[must_use]
+    pub fn excluded_by(&self) -> Option<ExclusionSource> {
+        self.exdate.map(ExclusionSource::ExDate).or_else(|| {
+            self.exrules
+                .iter()
+                .position(RRuleExplanation::matches)
+                .map(ExclusionSource::ExRule)
+        })
+    }
+}
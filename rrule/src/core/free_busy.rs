@@ -0,0 +1,372 @@
+use crate::core::datetime::local_datetime;
+use crate::iter::rrule_iter::WasLimited;
+use crate::parser::fold_content_line;
+use crate::{Period, RRuleSet, TerminationReason, Tz};
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc, Weekday};
+use std::fmt::Display;
+
+/// A range of time `[start, end)` in UTC.
+type Range = (DateTime<Tz>, DateTime<Tz>);
+
+/// The hours of the week in which free time can be found, see [`FreeBusyQuery::working_hours`].
+///
+/// # Usage
+///
+/// ```
+/// use chrono::{NaiveTime, Weekday};
+/// use rrule::{Tz, WorkingHours};
+///
+/// let start = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+/// let end = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+/// let working_hours = WorkingHours::new(start, end, Tz::Europe__Berlin)
+///     .weekdays(vec![Weekday::Mon, Weekday::Tue, Weekday::Wed]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkingHours {
+    start: NaiveTime,
+    end: NaiveTime,
+    tz: Tz,
+    weekdays: Vec<Weekday>,
+}
+
+impl WorkingHours {
+    /// Creates working hours from `start` to `end` in the timezone `tz`, from Monday to Friday.
+    ///
+    /// If `end` isn't after `start`, the working hours end on the next day.
+    #[must_use]
+    pub fn new(start: NaiveTime, end: NaiveTime, tz: Tz) -> Self {
+        Self {
+            start,
+            end,
+            tz,
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+        }
+    }
+
+    /// Sets the days of the week on which the working hours start.
+    #[must_use]
+    pub fn weekdays(mut self, weekdays: Vec<Weekday>) -> Self {
+        self.weekdays = weekdays;
+        self
+    }
+
+    /// Returns the time of the day at which the working hours start.
+    #[must_use]
+    pub fn get_start(&self) -> NaiveTime {
+        self.start
+    }
+
+    /// Returns the time of the day at which the working hours end.
+    #[must_use]
+    pub fn get_end(&self) -> NaiveTime {
+        self.end
+    }
+
+    /// Returns the timezone of the working hours.
+    #[must_use]
+    pub fn get_timezone(&self) -> Tz {
+        self.tz
+    }
+
+    /// Returns the days of the week on which the working hours start.
+    #[must_use]
+    pub fn get_weekdays(&self) -> &[Weekday] {
+        &self.weekdays
+    }
+
+    /// Returns the working hours which overlap `[start, end)`, in the order of their start.
+    fn ranges(&self, start: &DateTime<Tz>, end: &DateTime<Tz>) -> Vec<Range> {
+        let mut ranges = vec![];
+        // Working hours which end on the next day can start the day before the range.
+        let mut date = start.with_timezone(&self.tz).date_naive().pred_opt();
+        let last_date = end.with_timezone(&self.tz).date_naive();
+        while let Some(day) = date.filter(|day| *day <= last_date) {
+            if self.weekdays.contains(&day.weekday()) {
+                let end_day = if self.end > self.start {
+                    Some(day)
+                } else {
+                    day.succ_opt()
+                };
+                if let Some(end_day) = end_day {
+                    ranges.push((
+                        local_datetime(day, self.start, self.tz).with_timezone(&Tz::UTC),
+                        local_datetime(end_day, self.end, self.tz).with_timezone(&Tz::UTC),
+                    ));
+                }
+            }
+            date = day.succ_opt();
+        }
+        merge(ranges)
+    }
+}
+
+/// A query for the busy and free time of [`RRuleSet`]s within a window.
+///
+/// # Usage
+///
+/// ```
+/// use chrono::{Duration, NaiveTime, TimeZone};
+/// use rrule::{FreeBusyQuery, RRuleSet, Tz, WorkingHours};
+///
+/// let standup: RRuleSet = "DTSTART:20230102T090000Z\nDURATION:PT30M\nRRULE:FREQ=DAILY"
+///     .parse()
+///     .unwrap();
+/// let review: RRuleSet = "DTSTART:20230102T092000Z\nDURATION:PT1H\nRRULE:FREQ=WEEKLY"
+///     .parse()
+///     .unwrap();
+///
+/// let start = Tz::UTC.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+/// let end = Tz::UTC.with_ymd_and_hms(2023, 1, 3, 0, 0, 0).unwrap();
+/// let working_hours = WorkingHours::new(
+///     NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+///     NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+///     Tz::UTC,
+/// );
+/// let free_busy = FreeBusyQuery::new(start, end)
+///     .working_hours(working_hours)
+///     .min_free_slot(Duration::minutes(90))
+///     .compute([&standup, &review]);
+///
+/// // The free hour between 08:00 and 09:00 is too short.
+/// assert_eq!(free_busy.free.len(), 1);
+/// assert_eq!(free_busy.free[0].to_string(), "20230102T102000Z/20230102T120000Z");
+/// assert_eq!(
+///     free_busy.to_string().lines().next().unwrap(),
+///     "FREEBUSY;FBTYPE=BUSY:20230102T090000Z/20230102T102000Z"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FreeBusyQuery {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    min_free_slot: Duration,
+    working_hours: Option<WorkingHours>,
+}
+
+impl FreeBusyQuery {
+    /// Creates a query for the window `[start, end)`.
+    #[must_use]
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Self {
+        Self {
+            start,
+            end,
+            min_free_slot: Duration::zero(),
+            working_hours: None,
+        }
+    }
+
+    /// Only returns the free periods which last at least `duration`.
+    #[must_use]
+    pub fn min_free_slot(mut self, duration: Duration) -> Self {
+        self.min_free_slot = duration;
+        self
+    }
+
+    /// Only returns free periods within the working hours. The time outside of the working
+    /// hours is returned as [`FreeBusy::unavailable`].
+    #[must_use]
+    pub fn working_hours(mut self, working_hours: WorkingHours) -> Self {
+        self.working_hours = Some(working_hours);
+        self
+    }
+
+    /// Returns the start of the window.
+    #[must_use]
+    pub fn get_start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the window.
+    #[must_use]
+    pub fn get_end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Returns the minimum length of the free periods.
+    #[must_use]
+    pub fn get_min_free_slot(&self) -> Duration {
+        self.min_free_slot
+    }
+
+    /// Returns the working hours, if set.
+    #[must_use]
+    pub fn get_working_hours(&self) -> Option<&WorkingHours> {
+        self.working_hours.as_ref()
+    }
+
+    /// Computes the busy and free time of the given sets within the window.
+    ///
+    /// The occurrences of the sets are busy from their start to their end, which is derived
+    /// like in [`RRuleSet::occurrences`]. Occurrences without a length are ignored.
+    #[must_use]
+    pub fn compute<'a>(&self, rrule_sets: impl IntoIterator<Item = &'a RRuleSet>) -> FreeBusy {
+        let window = (
+            self.start.with_timezone(&Tz::UTC),
+            self.end.with_timezone(&Tz::UTC),
+        );
+        let mut busy = vec![];
+        let mut reason = TerminationReason::Finished;
+        for rrule_set in rrule_sets {
+            // Occurrences that start before this can't overlap the window.
+            let earliest_start = self
+                .start
+                .checked_sub_signed(rrule_set.max_duration())
+                .unwrap_or_else(|| DateTime::<Utc>::MIN_UTC.with_timezone(&Tz::UTC));
+            let mut iter = rrule_set
+                .clone()
+                .after(earliest_start)
+                .limit()
+                .occurrences();
+            loop {
+                let Some(occurrence) = iter.next() else {
+                    if reason == TerminationReason::Finished {
+                        reason = iter.stopped_early().unwrap_or(TerminationReason::Finished);
+                    }
+                    break;
+                };
+                if occurrence.start >= self.end {
+                    break;
+                }
+                let start = std::cmp::max(occurrence.start, self.start);
+                let end = std::cmp::min(occurrence.end, self.end);
+                if start < end {
+                    busy.push((start.with_timezone(&Tz::UTC), end.with_timezone(&Tz::UTC)));
+                }
+            }
+        }
+        busy.sort_unstable();
+        let busy = merge(busy);
+
+        let (available, unavailable) = match &self.working_hours {
+            Some(working_hours) => {
+                let working = clip(working_hours.ranges(&window.0, &window.1), &window);
+                let unavailable = subtract(&[window], &working);
+                (working, unavailable)
+            }
+            None => (vec![window], vec![]),
+        };
+        let free = subtract(&available, &busy)
+            .into_iter()
+            .filter(|(start, end)| *end - *start >= self.min_free_slot)
+            .collect::<Vec<_>>();
+
+        let to_periods = |ranges: Vec<Range>| {
+            ranges
+                .into_iter()
+                .map(|(start, end)| Period::new(start, end))
+                .collect()
+        };
+        FreeBusy {
+            busy: to_periods(busy),
+            free: to_periods(free),
+            unavailable: to_periods(unavailable),
+            limited: reason.is_limited(),
+            reason,
+        }
+    }
+}
+
+/// The busy and free time of [`RRuleSet`]s within a window, see [`FreeBusyQuery`].
+///
+/// The periods are in UTC, sorted and don't overlap each other. `Display` prints them as
+/// `FREEBUSY` properties of a `VFREEBUSY` component, like
+/// `FREEBUSY;FBTYPE=BUSY:19970308T160000Z/19970308T163000Z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FreeBusy {
+    /// The periods in which an occurrence takes place.
+    pub busy: Vec<Period>,
+    /// The periods without any occurrence, within the working hours if they are set.
+    pub free: Vec<Period>,
+    /// The periods outside of the working hours.
+    pub unavailable: Vec<Period>,
+    /// It is being true if the busy periods are limited, when `reason` is
+    /// [`TerminationReason::Limit`] or [`TerminationReason::MaxIterations`].
+    /// To indicate that the busy periods can be incomplete.
+    pub limited: bool,
+    /// Why the iterations over the sets stopped. Anything but
+    /// [`TerminationReason::Finished`] means that the busy periods can be incomplete.
+    pub reason: TerminationReason,
+}
+
+impl Display for FreeBusy {
+    /// Prints a `FREEBUSY` property for each type of period that isn't empty.
+    /// Lines longer than 75 octets are folded.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let content_lines = [
+            ("BUSY", &self.busy),
+            ("BUSY-UNAVAILABLE", &self.unavailable),
+            ("FREE", &self.free),
+        ]
+        .into_iter()
+        .filter(|(_, periods)| !periods.is_empty())
+        .map(|(fb_type, periods)| {
+            let periods = periods
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            fold_content_line(&format!("FREEBUSY;FBTYPE={fb_type}:{periods}"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+        write!(f, "{content_lines}")
+    }
+}
+
+/// Merges the ranges which overlap or touch. The ranges have to be sorted by their start.
+fn merge(ranges: Vec<Range>) -> Vec<Range> {
+    let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = std::cmp::max(last.1, end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Cuts the ranges to the window, and drops the ones outside of it.
+fn clip(ranges: Vec<Range>, window: &Range) -> Vec<Range> {
+    ranges
+        .into_iter()
+        .map(|(start, end)| (std::cmp::max(start, window.0), std::cmp::min(end, window.1)))
+        .filter(|(start, end)| start < end)
+        .collect()
+}
+
+/// Removes the `holes` from the `ranges`. Both have to be sorted and merged.
+fn subtract(ranges: &[Range], holes: &[Range]) -> Vec<Range> {
+    let mut remaining = vec![];
+    let mut holes = holes.iter().peekable();
+    for &(mut start, end) in ranges {
+        while let Some(hole) = holes.peek() {
+            if hole.1 <= start {
+                holes.next();
+                continue;
+            }
+            if hole.0 >= end {
+                break;
+            }
+            if hole.0 > start {
+                remaining.push((start, hole.0));
+            }
+            if hole.1 >= end {
+                start = end;
+                break;
+            }
+            start = hole.1;
+            holes.next();
+        }
+        if start < end {
+            remaining.push((start, end));
+        }
+    }
+    remaining
+}
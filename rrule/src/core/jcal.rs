@@ -0,0 +1,299 @@
+//! Conversion of [`RRule`] and [`RRuleSet`] from and to [jCal](https://datatracker.ietf.org/doc/html/rfc7265).
+use super::datetime::datetime_to_extended_format;
+use super::{RRule, RRuleSet, Tz, Unvalidated};
+use crate::parser::{
+    extended_to_basic_format, props_to_rrule, ContentLine, ParseError, PropertyName, RRuleProperty,
+};
+use crate::RRuleError;
+use chrono::DateTime;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+impl<S> RRule<S> {
+    /// Returns the rrule as a jCal `recur` value, like `{"freq": "WEEKLY", "byday": ["MO", "FR"]}`.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRule;
+    ///
+    /// let rrule: rrule::RRule<rrule::Unvalidated> = "FREQ=WEEKLY;COUNT=4;BYDAY=MO,FR".parse().unwrap();
+    /// assert_eq!(
+    ///     rrule.to_jcal(),
+    ///     serde_json::json!({"freq": "WEEKLY", "count": 4, "byday": ["MO", "FR"]})
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_jcal(&self) -> Value {
+        recur_to_jcal(self, None)
+    }
+}
+
+impl RRule<Unvalidated> {
+    /// Parses a jCal `recur` value, like `{"freq": "WEEKLY", "byday": ["MO", "FR"]}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidJCalValue`] if `recur` isn't an object of rule parts,
+    /// and the same errors as an `RRULE` string for invalid rule parts.
+    pub fn from_jcal(recur: &Value) -> Result<Self, ParseError> {
+        jcal_to_rrule(recur)
+    }
+}
+
+impl RRuleSet {
+    /// Returns the `DTSTART`, `DTEND` or `DURATION`, `RRULE`, `EXRULE`, `RDATE` and `EXDATE`
+    /// properties of the set as jCal properties, like `["dtstart", {}, "date-time", "2024-01-01T09:00:00Z"]`.
+    ///
+    /// Dates are in the timezone of `DTSTART`, with a `tzid` parameter for a named timezone.
+    /// Periods are in UTC, like in [`Period`]'s `Display`.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20240101T090000\nRRULE:FREQ=DAILY;COUNT=3"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     rrule_set.to_jcal()[0],
+    ///     serde_json::json!(["dtstart", {"tzid": "Europe/Berlin"}, "date-time", "2024-01-01T09:00:00"])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_jcal(&self) -> Vec<Value> {
+        let tz = self.dt_start.timezone();
+        let date_only_tz = self.date_only.then_some(tz);
+        let parameters = match tz {
+            // Dates can't have a `TZID`.
+            Tz::Tz(tz) if tz != chrono_tz::UTC && !self.date_only => json!({ "tzid": tz.name() }),
+            _ => json!({}),
+        };
+        let value_type = if self.date_only { "date" } else { "date-time" };
+        let format_date = |date: &DateTime<Tz>| {
+            if self.date_only {
+                date.with_timezone(&tz).format("%Y-%m-%d").to_string()
+            } else {
+                datetime_to_extended_format(date, tz)
+            }
+        };
+        let date_property = |name: &str, dates: &[DateTime<Tz>]| {
+            let mut property = vec![json!(name), parameters.clone(), json!(value_type)];
+            property.extend(dates.iter().map(|date| json!(format_date(date))));
+            Value::Array(property)
+        };
+
+        let mut properties = vec![date_property("dtstart", &[self.dt_start])];
+        if let Some(dt_end) = &self.dt_end {
+            properties.push(date_property("dtend", &[*dt_end]));
+        }
+        if let Some(duration) = &self.duration {
+            properties.push(json!(["duration", {}, "duration", duration.to_string()]));
+        }
+        for rrule in &self.rrule {
+            properties.push(json!([
+                "rrule",
+                {},
+                "recur",
+                recur_to_jcal(rrule, date_only_tz)
+            ]));
+        }
+        for exrule in &self.exrule {
+            properties.push(json!([
+                "exrule",
+                {},
+                "recur",
+                recur_to_jcal(exrule, date_only_tz)
+            ]));
+        }
+        if !self.rdate.is_empty() {
+            properties.push(date_property("rdate", &self.rdate));
+        }
+        if !self.rdate_period.is_empty() {
+            let mut property = vec![json!("rdate"), json!({}), json!("period")];
+            property.extend(self.rdate_period.iter().map(|period| {
+                let (start, end) = period.to_extended_format();
+                json!([start, end])
+            }));
+            properties.push(Value::Array(property));
+        }
+        if !self.exdate.is_empty() {
+            properties.push(date_property("exdate", &self.exdate));
+        }
+        properties
+    }
+
+    /// Creates an [`RRuleSet`] from jCal properties, like the properties of a `vevent`.
+    /// Properties other than `DTSTART`, `DTEND`, `DURATION`, `RRULE`, `EXRULE`, `RDATE`
+    /// and `EXDATE` are ignored.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    /// use serde_json::json;
+    ///
+    /// let rrule_set = RRuleSet::from_jcal(&[
+    ///     json!(["summary", {}, "text", "Stand-up"]),
+    ///     json!(["dtstart", {"tzid": "Europe/Berlin"}, "date-time", "2024-01-01T09:00:00"]),
+    ///     json!(["rrule", {}, "recur", {"freq": "DAILY", "count": 3}]),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(rrule_set.all(10).dates.len(), 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidJCalProperty`] or [`ParseError::InvalidJCalValue`] if a
+    /// property isn't valid jCal, and the same errors as an iCalendar string otherwise.
+    pub fn from_jcal(properties: &[Value]) -> Result<Self, RRuleError> {
+        let mut lines = vec![];
+        let mut rrules = vec![];
+        for property in properties {
+            let invalid_property = || ParseError::InvalidJCalProperty(property.to_string());
+            let Some(
+                [Value::String(name), Value::Object(parameters), Value::String(value_type), values @ ..],
+            ) = property.as_array().map(Vec::as_slice)
+            else {
+                return Err(invalid_property().into());
+            };
+            let Ok(property_name) = name.parse::<PropertyName>() else {
+                continue;
+            };
+            if values.is_empty() {
+                return Err(invalid_property().into());
+            }
+
+            match property_name {
+                PropertyName::RRule | PropertyName::ExRule => {
+                    if !value_type.eq_ignore_ascii_case("recur") {
+                        return Err(unsupported_value_type(property_name, value_type).into());
+                    }
+                    for recur in values {
+                        rrules.push((property_name, jcal_to_rrule(recur)?));
+                    }
+                }
+                PropertyName::Duration => {
+                    if !value_type.eq_ignore_ascii_case("duration") {
+                        return Err(unsupported_value_type(property_name, value_type).into());
+                    }
+                    let [Value::String(duration)] = values else {
+                        return Err(invalid_property().into());
+                    };
+                    lines.push((property_name, None, duration.clone()));
+                }
+                _ => {
+                    let mut line_parameters = format!("VALUE={}", value_type.to_uppercase());
+                    match parameters.get("tzid") {
+                        Some(Value::String(tzid)) => {
+                            line_parameters = format!("TZID={tzid};{line_parameters}");
+                        }
+                        Some(tzid) => {
+                            return Err(ParseError::InvalidJCalValue(tzid.to_string()).into())
+                        }
+                        None => {}
+                    }
+                    let values = values
+                        .iter()
+                        .map(jcal_to_ical_date)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    lines.push((property_name, Some(line_parameters), values.join(",")));
+                }
+            }
+        }
+
+        let rules = rrules
+            .into_iter()
+            .map(|(property_name, rrule)| {
+                if property_name == PropertyName::RRule {
+                    ContentLine::RRule(rrule)
+                } else {
+                    ContentLine::ExRule(rrule)
+                }
+            })
+            .collect();
+        Self::from_content_line_parts(&lines, rules)
+    }
+}
+
+fn unsupported_value_type(property_name: PropertyName, value_type: &str) -> ParseError {
+    ParseError::UnsupportedValueType {
+        property: property_name.to_string(),
+        value: value_type.to_uppercase(),
+    }
+}
+
+/// Returns the `recur` value of an rrule, see [`RRule::to_recur_parts`].
+/// Parts with a single value aren't in an array, and numbers are JSON numbers.
+fn recur_to_jcal<S>(rrule: &RRule<S>, date_only_tz: Option<Tz>) -> Value {
+    let to_value = |value: String| {
+        value
+            .parse::<i64>()
+            .map_or(Value::String(value), Value::from)
+    };
+    Value::Object(
+        rrule
+            .to_recur_parts(date_only_tz)
+            .into_iter()
+            .map(|(name, mut values)| {
+                let value = if values.len() == 1 {
+                    to_value(values.remove(0))
+                } else {
+                    Value::Array(values.into_iter().map(to_value).collect())
+                };
+                (name.to_string(), value)
+            })
+            .collect::<Map<_, _>>(),
+    )
+}
+
+/// Parses the rule parts of a `recur` value like the ones of an `RRULE` string.
+fn jcal_to_rrule(recur: &Value) -> Result<RRule<Unvalidated>, ParseError> {
+    fn to_string(value: &Value) -> Result<String, ParseError> {
+        match value {
+            Value::String(value) => Ok(value.clone()),
+            Value::Number(value) => Ok(value.to_string()),
+            _ => Err(ParseError::InvalidJCalValue(value.to_string())),
+        }
+    }
+
+    let Value::Object(parts) = recur else {
+        return Err(ParseError::InvalidJCalValue(recur.to_string()));
+    };
+    let mut props = HashMap::new();
+    for (name, value) in parts {
+        let property = name.parse::<RRuleProperty>()?;
+        let value = match value {
+            Value::Array(values) => values
+                .iter()
+                .map(to_string)
+                .collect::<Result<Vec<_>, _>>()?
+                .join(","),
+            _ if property == RRuleProperty::Until => jcal_to_ical_date(value)?,
+            value => to_string(value)?,
+        };
+        props.insert(property, value);
+    }
+    props_to_rrule(&props)
+}
+
+/// Converts a jCal date, date-time or period to the iCalendar format.
+fn jcal_to_ical_date(value: &Value) -> Result<String, ParseError> {
+    match value {
+        Value::String(date) => Ok(extended_to_basic_format(date)),
+        Value::Array(period) => match &period[..] {
+            [Value::String(start), Value::String(end)] => {
+                // The end is either a date-time or a duration like `PT1H`.
+                let end = if end.starts_with(|c: char| c.is_ascii_digit()) {
+                    extended_to_basic_format(end)
+                } else {
+                    end.clone()
+                };
+                Ok(format!("{}/{end}", extended_to_basic_format(start)))
+            }
+            _ => Err(ParseError::InvalidJCalValue(value.to_string())),
+        },
+        _ => Err(ParseError::InvalidJCalValue(value.to_string())),
+    }
+}
@@ -1,14 +1,34 @@
+mod component;
 mod datetime;
+mod duration;
+mod explain;
+mod free_busy;
+#[cfg(feature = "jcal")]
+mod jcal;
+mod occurrence;
+mod period;
 mod rrule;
 mod rruleset;
 mod timezone;
 mod timezone_impl;
 pub(crate) mod utils;
+#[cfg(feature = "xcal")]
+mod xcal;
 
+pub use self::component::{Calendar, Component, ComponentKind, Property};
+pub use self::duration::ICalDuration;
+pub use self::explain::{Explanation, RRuleCheck, RRuleExplanation, RRulePart};
+pub use self::free_busy::{FreeBusy, FreeBusyQuery, WorkingHours};
+pub(crate) use self::occurrence::{conflicts, overlaps, EventLength};
+pub use self::occurrence::{
+    Conflict, ConflictResult, ExclusionSource, Occurrence, OccurrenceResult, OccurrenceSource,
+    SourcedDateTime,
+};
+pub use self::period::{Period, PeriodEnd};
 pub use self::rrule::{Frequency, NWeekday, RRule};
 pub use self::rruleset::{RRuleResult, RRuleSet};
 pub(crate) use datetime::{
-    duration_from_midnight, get_day, get_hour, get_minute, get_month, get_second,
+    duration_from_midnight, get_day, get_hour, get_minute, get_month, get_second, start_of_day,
 };
 pub use timezone::Tz;
 
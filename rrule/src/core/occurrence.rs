@@ -0,0 +1,163 @@
+use crate::{ICalDuration, TerminationReason, Tz};
+use chrono::{DateTime, Duration};
+
+/// Where an [`Occurrence`] comes from.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum OccurrenceSource {
+    /// The occurrence was generated by the rrule with this index in [`crate::RRuleSet::get_rrule`].
+    RRule(usize),
+    /// The occurrence is the rdate with this index in [`crate::RRuleSet::get_rdate`].
+    RDate(usize),
+    /// The occurrence is the period with this index in [`crate::RRuleSet::get_rdate_period`].
+    RDatePeriod(usize),
+}
+
+/// What removed a date from an [`crate::RRuleSet`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ExclusionSource {
+    /// The date was generated by the exrule with this index in [`crate::RRuleSet::get_exrule`].
+    ExRule(usize),
+    /// The date is the exdate with this index in [`crate::RRuleSet::get_exdate`].
+    ExDate(usize),
+}
+
+/// A date of an [`crate::RRuleSet`] together with where it comes from.
+///
+/// Returned by [`crate::SourcedIter`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SourcedDateTime {
+    /// The date.
+    pub date: DateTime<Tz>,
+    /// Where the date comes from.
+    pub source: OccurrenceSource,
+    /// What removed the date from the set, if it was excluded. Excluded dates are only
+    /// returned by [`crate::SourcedIter::with_exclusions`].
+    pub excluded_by: Option<ExclusionSource>,
+}
+
+/// A single occurrence of an [`crate::RRuleSet`] with its start and end.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Occurrence {
+    /// The start of the occurrence.
+    pub start: DateTime<Tz>,
+    /// The end of the occurrence. This is the same as the start if the set has neither
+    /// a `DTEND` nor a `DURATION`, unless the set is date-only, in which case the
+    /// occurrence lasts for one day.
+    pub end: DateTime<Tz>,
+    /// Where the occurrence comes from.
+    pub source: OccurrenceSource,
+}
+
+impl Occurrence {
+    /// Returns the exact length of the occurrence.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// The return result of [`crate::RRuleSet::overlapping`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OccurrenceResult {
+    /// List of occurrences.
+    pub occurrences: Vec<Occurrence>,
+    /// It is being true if the list of occurrences is limited.
+    /// To indicate that it can potentially contain more occurrences.
+    pub limited: bool,
+}
+
+/// A pair of overlapping occurrences of two [`crate::RRuleSet`]s.
+///
+/// Returned by [`crate::RRuleSet::conflicts`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Conflict {
+    /// The occurrence of the set [`crate::RRuleSet::conflicts`] is called on.
+    pub first: Occurrence,
+    /// The occurrence of the other set.
+    pub second: Occurrence,
+}
+
+impl Conflict {
+    /// Returns when the occurrences start to overlap.
+    #[must_use]
+    pub fn start(&self) -> DateTime<Tz> {
+        std::cmp::max(self.first.start, self.second.start)
+    }
+
+    /// Returns when the occurrences stop to overlap. This is the same as the start if
+    /// one of the occurrences doesn't have a length.
+    #[must_use]
+    pub fn end(&self) -> DateTime<Tz> {
+        std::cmp::max(self.start(), std::cmp::min(self.first.end, self.second.end))
+    }
+}
+
+/// The return result of [`crate::RRuleSet::conflicts`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConflictResult {
+    /// List of conflicts, ordered by their start.
+    pub conflicts: Vec<Conflict>,
+    /// It is being true if the list of conflicts is limited, when `reason` is
+    /// [`crate::TerminationReason::Limit`] or [`crate::TerminationReason::MaxIterations`].
+    /// To indicate that it can potentially contain more conflicts.
+    pub limited: bool,
+    /// Why the search stopped. Only [`crate::TerminationReason::Finished`] means that all the
+    /// conflicts before the horizon were found.
+    pub reason: TerminationReason,
+}
+
+/// The length of the occurrences of an [`crate::RRuleSet`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum EventLength {
+    /// An exact length, like the difference between `DTSTART` and `DTEND`.
+    Exact(Duration),
+    /// A nominal length which is added in local time, like a `DURATION` of `P1D`.
+    Nominal(ICalDuration),
+}
+
+impl EventLength {
+    /// Returns the end of an occurrence that starts at `start`, or `None` if it can't be
+    /// represented.
+    pub(crate) fn end(&self, start: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        match self {
+            Self::Exact(duration) => start.checked_add_signed(*duration),
+            Self::Nominal(duration) => duration.add_to(start),
+        }
+    }
+
+    /// Returns the longest exact duration an occurrence can have.
+    /// Nominal days can be up to 25 hours long because of DST changes.
+    pub(crate) fn max_duration(&self) -> Duration {
+        match self {
+            Self::Exact(duration) => *duration,
+            Self::Nominal(duration) if duration.negative => Duration::zero(),
+            Self::Nominal(duration) => {
+                let days = i64::from(duration.weeks) * 7 + i64::from(duration.days);
+                Duration::hours(days * 25 + i64::from(duration.hours))
+                    + Duration::minutes(i64::from(duration.minutes))
+                    + Duration::seconds(i64::from(duration.seconds))
+            }
+        }
+    }
+}
+
+/// Returns `true` if the occurrence overlaps the range `[start, end)`.
+///
+/// Occurrences without a length overlap the range if they start in it.
+pub(crate) fn overlaps(occurrence: &Occurrence, start: &DateTime<Tz>, end: &DateTime<Tz>) -> bool {
+    if occurrence.end <= occurrence.start {
+        (start..end).contains(&&occurrence.start)
+    } else {
+        occurrence.start < *end && occurrence.end > *start
+    }
+}
+
+/// Returns `true` if the occurrences overlap. Occurrences without a length overlap
+/// occurrences that contain their start, or that start at the same time.
+pub(crate) fn conflicts(first: &Occurrence, second: &Occurrence) -> bool {
+    match (first.end <= first.start, second.end <= second.start) {
+        (true, true) => first.start == second.start,
+        (true, false) => overlaps(first, &second.start, &second.end),
+        (false, _) => overlaps(second, &first.start, &first.end),
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(any(feature = "jcal", feature = "xcal"))]
+use crate::core::datetime::datetime_to_extended_format;
+use crate::{ICalDuration, Tz};
+use chrono::DateTime;
+use std::fmt::Display;
+
+/// The end of a [`Period`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PeriodEnd {
+    /// The period ends at an explicit datetime.
+    DateTime(DateTime<Tz>),
+    /// The period ends after a duration, starting from the start of the period.
+    Duration(ICalDuration),
+}
+
+/// A period of time as described in [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.9),
+/// like `RDATE;VALUE=PERIOD:19970101T180000Z/PT5H30M`.
+///
+/// # Usage
+///
+/// ```
+/// use chrono::TimeZone;
+/// use rrule::{ICalDuration, Period, Tz};
+///
+/// let start = Tz::UTC.with_ymd_and_hms(1997, 1, 1, 18, 0, 0).unwrap();
+/// let period = Period::with_duration(start, "PT5H30M".parse().unwrap());
+///
+/// assert_eq!(period.get_end(), Tz::UTC.with_ymd_and_hms(1997, 1, 1, 23, 30, 0).single());
+/// assert_eq!(period.to_string(), "19970101T180000Z/PT5H30M");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Period {
+    start: DateTime<Tz>,
+    end: PeriodEnd,
+}
+
+impl Period {
+    /// Creates a period between `start` and `end`.
+    #[must_use]
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Self {
+        Self {
+            start,
+            end: PeriodEnd::DateTime(end),
+        }
+    }
+
+    /// Creates a period starting at `start` which lasts for `duration`.
+    #[must_use]
+    pub fn with_duration(start: DateTime<Tz>, duration: ICalDuration) -> Self {
+        Self {
+            start,
+            end: PeriodEnd::Duration(duration),
+        }
+    }
+
+    /// Returns the start of the period.
+    #[must_use]
+    pub fn get_start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the period, as it was specified.
+    #[must_use]
+    pub fn get_period_end(&self) -> &PeriodEnd {
+        &self.end
+    }
+
+    /// Returns the end of the period. A duration is added to the start, see [`ICalDuration::add_to`],
+    /// which gives `None` if the end can't be represented.
+    #[must_use]
+    pub fn get_end(&self) -> Option<DateTime<Tz>> {
+        match &self.end {
+            PeriodEnd::DateTime(end) => Some(*end),
+            PeriodEnd::Duration(duration) => duration.add_to(&self.start),
+        }
+    }
+
+    /// Returns the start and the end or duration of the period in the extended format of
+    /// jCal and xCal. Like in `Display`, datetimes are in UTC unless they are in local time.
+    #[cfg(any(feature = "jcal", feature = "xcal"))]
+    pub(crate) fn to_extended_format(self) -> (String, String) {
+        let format = |dt: &DateTime<Tz>| {
+            let tz = if dt.timezone().is_local() {
+                dt.timezone()
+            } else {
+                Tz::UTC
+            };
+            datetime_to_extended_format(dt, tz)
+        };
+        let end = match &self.end {
+            PeriodEnd::DateTime(end) => format(end),
+            PeriodEnd::Duration(duration) => duration.to_string(),
+        };
+        (format(&self.start), end)
+    }
+}
+
+/// Formats a datetime of a period, which is either in local time or in UTC.
+fn period_datetime_to_ical_format(dt: &DateTime<Tz>) -> String {
+    if dt.timezone().is_local() {
+        dt.format("%Y%m%dT%H%M%S").to_string()
+    } else {
+        dt.with_timezone(&Tz::UTC)
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string()
+    }
+}
+
+impl Display for Period {
+    /// Prints the period in the iCalendar format, like `19970101T180000Z/19970102T070000Z`.
+    /// Datetimes are printed in UTC, unless they are in the local timezone.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let start = period_datetime_to_ical_format(&self.start);
+        match &self.end {
+            PeriodEnd::DateTime(end) => {
+                write!(f, "{start}/{}", period_datetime_to_ical_format(end))
+            }
+            PeriodEnd::Duration(duration) => write!(f, "{start}/{duration}"),
+        }
+    }
+}
@@ -1,15 +1,20 @@
+#[cfg(any(feature = "jcal", feature = "xcal"))]
+use crate::core::datetime::datetime_to_extended_format;
 use crate::core::get_day;
 use crate::core::get_hour;
 use crate::core::get_minute;
 use crate::core::get_month;
 use crate::core::get_second;
-use crate::iter::RRuleIter;
+use crate::iter::{Budget, RRuleIter};
+use crate::parser::parse_phrase;
 use crate::parser::str_to_weekday;
 use crate::parser::ContentLineCaptures;
 use crate::parser::ParseError;
 use crate::validator::validate_rrule;
 use crate::validator::ValidationError;
+use crate::Limits;
 use crate::Tz;
+use crate::{English, Locale};
 use crate::{RRuleError, RRuleSet, Unvalidated, Validated};
 use chrono::DateTime;
 use chrono::{Datelike, Month, Weekday};
@@ -145,6 +150,15 @@ impl NWeekday {
             None => Self::Every(weekday),
         }
     }
+
+    /// Formats the weekday like in `BYDAY`, keeping the `1` of `1MO` which `Display` leaves out.
+    #[cfg(any(feature = "serde", feature = "jcal", feature = "xcal"))]
+    pub(crate) fn to_ical(self) -> String {
+        match self {
+            Self::Every(weekday) => weekday_to_str(weekday),
+            Self::Nth(n, weekday) => format!("{n}{}", weekday_to_str(weekday)),
+        }
+    }
 }
 
 impl FromStr for NWeekday {
@@ -442,6 +456,39 @@ impl RRule<Unvalidated> {
         self
     }
 
+    /// Parses a recurrence described in English, like "every other Tuesday until March 3",
+    /// "the last Friday of each month" or "every weekday at 9am".
+    ///
+    /// Dates without a year, like "March 3", are the next one on or after `reference`, and
+    /// all dates are in its timezone. Returns the start date too if the text has one, like
+    /// "every day starting May 1".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidRecurrenceText`] with the first word that couldn't be
+    /// parsed, or [`ParseError::IncompleteRecurrenceText`] if the text ends too early.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRule, Tz};
+    ///
+    /// let reference = Tz::UTC.with_ymd_and_hms(2023, 1, 2, 9, 0, 0).unwrap();
+    /// let (rrule, dt_start) = RRule::from_text("every other Tuesday until March 3", &reference).unwrap();
+    /// assert_eq!(
+    ///     rrule.to_string(),
+    ///     "FREQ=WEEKLY;UNTIL=20230303T235959Z;INTERVAL=2;BYDAY=TU"
+    /// );
+    /// assert_eq!(dt_start, None);
+    /// ```
+    pub fn from_text(
+        text: &str,
+        reference: &DateTime<Tz>,
+    ) -> Result<(Self, Option<DateTime<Tz>>), ParseError> {
+        parse_phrase(text, reference)
+    }
+
     /// Fills in some additional fields in order to make iter work correctly.
     pub(crate) fn finalize_parsed_rrule(mut self, dt_start: &DateTime<Tz>) -> Self {
         // TEMP: move negative months to another list
@@ -550,11 +597,35 @@ impl RRule<Unvalidated> {
     ///
     /// If the properties aren't valid, it will return [`RRuleError`].
     pub fn validate(self, dt_start: DateTime<Tz>) -> Result<RRule<Validated>, RRuleError> {
+        self.validate_with_limits(dt_start, &Limits::default())
+    }
+
+    /// Validates the [`RRule`] with the given `dt_start`, like [`RRule::validate`],
+    /// but with `limits` instead of the default [`Limits`].
+    ///
+    /// # Errors
+    ///
+    /// If the properties aren't valid, it will return [`RRuleError`].
+    /// [`ValidationError::TooBigInterval`] is returned if `INTERVAL` is above the limit,
+    /// and [`ValidationError::StartYearOutOfRange`] if `dt_start` is outside of the year range.
+    pub fn validate_with_limits(
+        self,
+        dt_start: DateTime<Tz>,
+        limits: &Limits,
+    ) -> Result<RRule<Validated>, RRuleError> {
         let rrule = self.finalize_parsed_rrule(&dt_start);
 
         // Validate required checks (defined by RFC 5545)
         validate_rrule::validate_rrule_forced(&rrule, &dt_start)?;
 
+        // Validate the crate limits
+        if rrule.interval > limits.max_interval {
+            return Err(ValidationError::TooBigInterval(rrule.interval).into());
+        }
+        if !limits.get_year_range().contains(&dt_start.year()) {
+            return Err(ValidationError::StartYearOutOfRange(dt_start.year()).into());
+        }
+
         // Check if it is possible to generate a timeset
         match rrule.freq {
             Frequency::Hourly => {
@@ -612,8 +683,47 @@ impl RRule<Unvalidated> {
 }
 
 impl RRule {
-    pub(crate) fn iter_with_ctx(&self, dt_start: DateTime<Tz>, limited: bool) -> RRuleIter {
-        RRuleIter::new(self, &dt_start, limited)
+    pub(crate) fn iter_with_ctx(
+        &self,
+        dt_start: DateTime<Tz>,
+        limited: bool,
+        limits: Limits,
+        budget: Budget,
+    ) -> RRuleIter {
+        RRuleIter::new(self, &dt_start, limited, limits, budget)
+    }
+
+    /// Describes the rule in English, like "every 2 weeks on Monday and Friday at 09:00".
+    ///
+    /// The rule is described as it was validated, so the parts which were derived from
+    /// the start date, like the time, are included.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRule, Tz, Unvalidated};
+    /// use chrono::TimeZone;
+    ///
+    /// let rrule: RRule<Unvalidated> = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=10".parse().unwrap();
+    /// let rrule = rrule
+    ///     .validate(Tz::UTC.with_ymd_and_hms(2023, 1, 2, 9, 0, 0).unwrap())
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     rrule.to_text(),
+    ///     "every 2 weeks on Monday and Friday at 09:00 for 10 times"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        self.to_localized_text(&English)
+    }
+
+    /// Describes the rule in the language of `locale`.
+    ///
+    /// See [`RRule::to_text`] and [`Locale`].
+    #[must_use]
+    pub fn to_localized_text(&self, locale: &dyn Locale) -> String {
+        crate::text::rrule_to_text(self, false, locale)
     }
 }
 
@@ -632,18 +742,36 @@ impl<S> Display for RRule<S> {
     /// When you call this function on [`RRule<Unvalidated>`], it can generate an invalid string, like 'FREQ=YEARLY;INTERVAL=-1'
     /// But it is supposed to always generate a valid string on [`RRule<Validated>`].
     /// So if you want a valid string, it's smarter to always use `rrule.validate(ds_start)?.to_string()`.
-    #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_ical_string(None))
+    }
+}
+
+impl<S> RRule<S> {
+    /// Generates the iCalendar string of the rrule, see the [`Display`] implementation.
+    ///
+    /// If `date_only_tz` is set, the rrule belongs to a set with `VALUE=DATE` dates. Then
+    /// `UNTIL` is written as a date in that timezone, and `BYHOUR`, `BYMINUTE` and `BYSECOND`
+    /// are left out.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn to_ical_string(&self, date_only_tz: Option<Tz>) -> String {
         let mut res = Vec::with_capacity(15);
         res.push(format!("FREQ={}", &self.freq));
 
         if let Some(until) = &self.until {
-            let maybe_zulu = if until.timezone().is_local() { "" } else { "Z" };
-            res.push(format!(
-                "UNTIL={}{}",
-                until.format("%Y%m%dT%H%M%S"),
-                maybe_zulu
-            ));
+            if let Some(tz) = date_only_tz {
+                res.push(format!(
+                    "UNTIL={}",
+                    until.with_timezone(&tz).format("%Y%m%d")
+                ));
+            } else {
+                let maybe_zulu = if until.timezone().is_local() { "" } else { "Z" };
+                res.push(format!(
+                    "UNTIL={}{}",
+                    until.format("%Y%m%dT%H%M%S"),
+                    maybe_zulu
+                ));
+            }
         }
 
         if let Some(count) = &self.count {
@@ -704,7 +832,7 @@ impl<S> Display for RRule<S> {
             ));
         }
 
-        if !self.by_hour.is_empty() {
+        if !self.by_hour.is_empty() && date_only_tz.is_none() {
             res.push(format!(
                 "BYHOUR={}",
                 self.by_hour
@@ -715,7 +843,7 @@ impl<S> Display for RRule<S> {
             ));
         }
 
-        if !self.by_minute.is_empty() {
+        if !self.by_minute.is_empty() && date_only_tz.is_none() {
             res.push(format!(
                 "BYMINUTE={}",
                 self.by_minute
@@ -726,7 +854,7 @@ impl<S> Display for RRule<S> {
             ));
         }
 
-        if !self.by_second.is_empty() {
+        if !self.by_second.is_empty() && date_only_tz.is_none() {
             res.push(format!(
                 "BYSECOND={}",
                 self.by_second
@@ -764,7 +892,88 @@ impl<S> Display for RRule<S> {
             res.push(format!("BYEASTER={}", by_easter));
         }
 
-        write!(f, "{}", res.join(";"))
+        res.join(";")
+    }
+
+    /// Returns the rule parts which are set, with their lowercase names like `byday`, as
+    /// they are written in jCal and xCal.
+    /// If `date_only_tz` is set, the rrule belongs to a set with `VALUE=DATE` dates, so
+    /// `until` is a date in that timezone and the time parts are left out.
+    #[cfg(any(feature = "jcal", feature = "xcal"))]
+    pub(crate) fn to_recur_parts(
+        &self,
+        date_only_tz: Option<Tz>,
+    ) -> Vec<(&'static str, Vec<String>)> {
+        fn strings<T: ToString>(values: &[T]) -> Vec<String> {
+            values.iter().map(ToString::to_string).collect()
+        }
+
+        let until = self.until.map(|until| match date_only_tz {
+            Some(tz) => until.with_timezone(&tz).format("%Y-%m-%d").to_string(),
+            None => datetime_to_extended_format(&until, until.timezone()),
+        });
+        let (by_hour, by_minute, by_second) = if date_only_tz.is_some() {
+            (vec![], vec![], vec![])
+        } else {
+            (
+                strings(&self.by_hour),
+                strings(&self.by_minute),
+                strings(&self.by_second),
+            )
+        };
+        let parts = [
+            ("freq", vec![self.freq.to_string()]),
+            ("until", until.into_iter().collect()),
+            (
+                "count",
+                strings(&self.count.into_iter().collect::<Vec<_>>()),
+            ),
+            (
+                "interval",
+                // One interval is the default, no need to expose it.
+                if self.interval == 1 {
+                    vec![]
+                } else {
+                    vec![self.interval.to_string()]
+                },
+            ),
+            ("bysecond", by_second),
+            ("byminute", by_minute),
+            ("byhour", by_hour),
+            (
+                "byday",
+                self.by_weekday
+                    .iter()
+                    .map(|weekday| weekday.to_ical())
+                    .collect(),
+            ),
+            (
+                "bymonthday",
+                strings(&[&self.by_month_day[..], &self.by_n_month_day[..]].concat()),
+            ),
+            ("byyearday", strings(&self.by_year_day)),
+            ("byweekno", strings(&self.by_week_no)),
+            ("bymonth", strings(&self.by_month)),
+            ("bysetpos", strings(&self.by_set_pos)),
+            (
+                "wkst",
+                // Monday is the default, no need to expose it.
+                if self.week_start == Weekday::Mon {
+                    vec![]
+                } else {
+                    vec![weekday_to_str(self.week_start)]
+                },
+            ),
+            #[cfg(feature = "by-easter")]
+            (
+                "byeaster",
+                strings(&self.by_easter.into_iter().collect::<Vec<_>>()),
+            ),
+        ];
+        parts
+            .into_iter()
+            .filter(|(_, values)| !values.is_empty())
+            .collect()
     }
 }
 
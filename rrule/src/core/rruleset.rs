@@ -1,12 +1,26 @@
-use crate::core::datetime::datetime_to_ical_format;
+use crate::core::datetime::{date_to_ical_format, datetime_to_ical_format, start_of_day};
 use crate::core::utils::collect_with_error;
-use crate::parser::{ContentLine, Grammar};
-use crate::{ParseError, RRule, RRuleError, Tz};
-use chrono::DateTime;
+use crate::core::{overlaps, EventLength};
+use crate::iter::rrule_iter::WasLimited;
+use crate::iter::{Budget, DateTimeIter};
+use crate::parser::{
+    fold_content_line, ContentLine, DateContentLine, Grammar, StartDateContentLine,
+};
+#[cfg(any(feature = "jcal", feature = "xcal"))]
+use crate::parser::{ContentLineCaptures, PropertyName};
+use crate::validator::validate_rrule::validate_date_only_times;
+use crate::{
+    CancellationToken, ConflictResult, English, Explanation, ICalDuration, Limits, Locale,
+    OccurrenceIter, OccurrenceResult, ParseError, Period, RRule, RRuleError, RRuleSetCursor,
+    RRuleSetDateIter, RRuleSetIter, RRuleSetRevIter, SourcedIter, TerminationReason, Tz,
+    Unvalidated, ValidationError,
+};
+use chrono::{DateTime, Utc};
 #[cfg(feature = "serde")]
 use serde_with::{serde_as, DeserializeFromStr, SerializeDisplay};
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::Instant;
 
 /// A validated Recurrence Rule that can be used to create an iterator.
 #[cfg_attr(feature = "serde", serde_as)]
@@ -17,18 +31,30 @@ pub struct RRuleSet {
     pub(crate) rrule: Vec<RRule>,
     /// List of rdates.
     pub(crate) rdate: Vec<DateTime<Tz>>,
+    /// List of rdates with a period (`RDATE;VALUE=PERIOD`).
+    pub(crate) rdate_period: Vec<Period>,
     /// List of exules.
     pub(crate) exrule: Vec<RRule>,
     /// List of exdates.
     pub(crate) exdate: Vec<DateTime<Tz>>,
     /// The start datetime of the recurring event.
     pub(crate) dt_start: DateTime<Tz>,
+    /// The end datetime of the first occurrence, from `DTEND`.
+    pub(crate) dt_end: Option<DateTime<Tz>>,
+    /// The duration of the occurrences, from `DURATION`.
+    pub(crate) duration: Option<ICalDuration>,
     /// If set, all returned recurrences must be before this date.
     pub(crate) before: Option<DateTime<Tz>>,
     /// If set, all returned recurrences must be after this date.
     pub(crate) after: Option<DateTime<Tz>>,
     /// If validation limits are enabled
     pub(crate) limited: bool,
+    /// The limits used to validate the rrules and to iterate.
+    pub(crate) limits: Limits,
+    /// When iterations over the set have to stop.
+    pub(crate) budget: Budget,
+    /// If the set consists of dates without a time (`VALUE=DATE`).
+    pub(crate) date_only: bool,
 }
 
 /// The return result of `RRuleSet::all`.
@@ -36,9 +62,21 @@ pub struct RRuleSet {
 pub struct RRuleResult {
     /// List of recurrences.
     pub dates: Vec<DateTime<Tz>>,
-    /// It is being true if the list of dates is limited.
+    /// It is being true if the list of dates is limited, when `reason` is
+    /// [`TerminationReason::Limit`] or [`TerminationReason::MaxIterations`].
     /// To indicate that it can potentially contain more dates.
     pub limited: bool,
+    /// Why the iteration stopped.
+    pub reason: TerminationReason,
+}
+
+impl RRuleResult {
+    /// Returns `true` if the iteration stopped because the deadline passed or it was cancelled,
+    /// see [`RRuleSet::deadline`] and [`RRuleSet::cancellation_token`].
+    #[must_use]
+    pub fn timed_out(&self) -> bool {
+        self.reason == TerminationReason::TimedOut
+    }
 }
 
 impl RRuleSet {
@@ -47,16 +85,70 @@ impl RRuleSet {
     pub fn new(dt_start: DateTime<Tz>) -> Self {
         Self {
             dt_start,
+            dt_end: None,
+            duration: None,
             rrule: vec![],
             rdate: vec![],
+            rdate_period: vec![],
             exrule: vec![],
             exdate: vec![],
             before: None,
             after: None,
             limited: false,
+            limits: Limits::default(),
+            budget: Budget::default(),
+            date_only: false,
+        }
+    }
+
+    /// Creates an [`RRuleSet`] from a recurrence described in English, see [`RRule::from_text`].
+    ///
+    /// The set starts at the start date of the text, like "starting May 1", or at `reference`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::ParserError`] if the text can't be parsed, or
+    /// [`RRuleError::ValidationError`] if the rrule is invalid.
+    pub fn from_text(text: &str, reference: &DateTime<Tz>) -> Result<Self, RRuleError> {
+        let (rrule, dt_start) = RRule::from_text(text, reference)?;
+        rrule.build(dt_start.unwrap_or(*reference))
+    }
+
+    /// Creates an empty [`RRuleSet`] from a parsed `DTSTART`.
+    pub(crate) fn from_start_date(start: &StartDateContentLine) -> Self {
+        let rrule_set = Self::new(start.datetime);
+        if start.is_date() {
+            rrule_set.at_start_of_day()
+        } else {
+            rrule_set
         }
     }
 
+    /// Creates an [`RRuleSet`] from the name, parameters and value of its date properties
+    /// and its already parsed `RRULE` and `EXRULE` lines, with the same checks as [`FromStr`].
+    #[cfg(any(feature = "jcal", feature = "xcal"))]
+    pub(crate) fn from_content_line_parts(
+        parts: &[(PropertyName, Option<String>, String)],
+        rules: Vec<ContentLine>,
+    ) -> Result<Self, RRuleError> {
+        let captures = parts
+            .iter()
+            .map(|(property_name, parameters, value)| ContentLineCaptures {
+                property_name: *property_name,
+                parameters: parameters.as_deref(),
+                value,
+            })
+            .collect::<Vec<_>>();
+        let mut grammar = Grammar::try_from(captures)?;
+        grammar.content_lines.extend(rules);
+        if !grammar.has_date_generation_rules() {
+            return Err(ParseError::MissingDateGenerationRules.into());
+        }
+
+        let start = grammar.start.ok_or(ParseError::MissingStartDate)?;
+        Self::from_start_date(&start).set_from_content_lines(grammar.content_lines)
+    }
+
     /// Enable validation limits.
     ///
     /// This is only needed if you are going to use the Iterator api directly.
@@ -66,18 +158,127 @@ impl RRuleSet {
         self
     }
 
-    /// Only return recurrences that comes before this `DateTime`.
+    /// Sets the limits used to validate the rrules parsed into the set, and to iterate.
+    ///
+    /// The limit on the number of iterations only applies when validation limits are
+    /// enabled, see [`RRuleSet::limit`].
+    #[must_use]
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Stops the iterations over the set once `deadline` has passed.
+    ///
+    /// The dates generated until then are returned, and [`RRuleResult::timed_out`] returns `true`.
     ///
-    /// This value will not be used if you use the `Iterator` API directly.
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    /// let result = rrule_set.deadline(Instant::now() + Duration::from_secs(1)).all(10);
+    /// assert_eq!(result.dates.len(), 10);
+    /// assert!(!result.timed_out());
+    /// ```
+    #[must_use]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.budget.deadline = Some(deadline);
+        self
+    }
+
+    /// Stops the iterations over the set once `token` is cancelled, like with
+    /// [`RRuleSet::deadline`].
+    #[must_use]
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.budget.token = Some(token);
+        self
+    }
+
+    /// Marks the set as a set of all-day dates, like `DTSTART;VALUE=DATE:20230101`.
+    ///
+    /// The start, rdates and exdates are then treated as dates in the timezone of the
+    /// start datetime, occurrences are at the start of their day and the set is printed
+    /// with `VALUE=DATE`. This is set automatically when parsing a date-only `DTSTART`.
+    ///
+    /// `VALUE=DATE` dates can't have a `TZID`, so the timezone isn't printed, and a printed
+    /// set is parsed back in UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::ByTimeRuleWithDateStart`] if an rrule of the set has a
+    /// `BYHOUR`, `BYMINUTE` or `BYSECOND` other than the start of the day, like the time of
+    /// a start datetime it was created with.
+    pub fn date_only(self) -> Result<Self, RRuleError> {
+        let rrule_set = self.at_start_of_day();
+        for rrule in rrule_set.rrule.iter().chain(&rrule_set.exrule) {
+            validate_date_only_times(rrule, &rrule_set.dt_start)?;
+        }
+        Ok(rrule_set)
+    }
+
+    /// Marks the set as date-only and moves the start to the start of its day.
+    fn at_start_of_day(mut self) -> Self {
+        self.date_only = true;
+        self.dt_start = start_of_day(self.dt_start.date_naive(), self.dt_start.timezone());
+        self
+    }
+
+    /// Sets the end of the first occurrence, like `DTEND`.
+    ///
+    /// All the occurrences have the same exact length, except in a date-only set,
+    /// where they have the same number of days. This replaces any duration set before.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::DtEndBeforeDtStart`] if `dt_end` is before the start datetime.
+    pub fn dt_end(mut self, dt_end: DateTime<Tz>) -> Result<Self, RRuleError> {
+        if dt_end < self.dt_start {
+            return Err(ParseError::DtEndBeforeDtStart.into());
+        }
+        self.dt_end = Some(dt_end);
+        self.duration = None;
+        Ok(self)
+    }
+
+    /// Sets the duration of the occurrences, like `DURATION`.
+    ///
+    /// This replaces any end set before with [`RRuleSet::dt_end`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::NegativeDuration`] if `duration` is negative, and
+    /// [`ParseError::InvalidDuration`] if the end of the first occurrence can't be represented.
+    pub fn duration(mut self, duration: ICalDuration) -> Result<Self, RRuleError> {
+        if duration.negative && !duration.is_zero() {
+            return Err(ParseError::NegativeDuration.into());
+        }
+        if duration.add_to(&self.dt_start).is_none() {
+            return Err(ParseError::InvalidDuration(duration.to_string()).into());
+        }
+        self.duration = Some(duration);
+        self.dt_end = None;
+        Ok(self)
+    }
+
+    /// Only return recurrences that comes before this `DateTime`, or at this `DateTime`.
+    ///
+    /// This value will not be used if you use the `Iterator` API directly, but
+    /// [`RRuleSet::iter_rev`] starts at it.
     #[must_use]
     pub fn before(mut self, dt: DateTime<Tz>) -> Self {
         self.before = Some(dt);
         self
     }
 
-    /// Only return recurrences that comes after this `DateTime`.
+    /// Only return recurrences that comes after this `DateTime`, or at this `DateTime`.
     ///
-    /// This value will not be used if you use the `Iterator` API directly.
+    /// This value is also used by the `Iterator` API, which jumps directly to the
+    /// first recurrences at or after this `DateTime` instead of generating all the
+    /// recurrences before it. Rules with a `COUNT` still have to go through all their
+    /// recurrences to count them.
     #[must_use]
     pub fn after(mut self, dt: DateTime<Tz>) -> Self {
         self.after = Some(dt);
@@ -106,6 +307,16 @@ impl RRuleSet {
         self
     }
 
+    /// Adds a new rdate with a period to the set.
+    ///
+    /// The occurrence starts at the start of the period, and its end can be
+    /// retrieved with [`crate::RRuleSetIter::next_with_end`].
+    #[must_use]
+    pub fn rdate_period(mut self, period: Period) -> Self {
+        self.rdate_period.push(period);
+        self
+    }
+
     /// Adds a new exdate to the set.
     #[must_use]
     pub fn exdate(mut self, exdate: DateTime<Tz>) -> Self {
@@ -135,6 +346,13 @@ impl RRuleSet {
         self
     }
 
+    /// Sets the rdates with a period of the set.
+    #[must_use]
+    pub fn set_rdate_periods(mut self, periods: Vec<Period>) -> Self {
+        self.rdate_period = periods;
+        self
+    }
+
     /// Set the exdates of the set.
     #[must_use]
     pub fn set_exdates(mut self, exdates: Vec<DateTime<Tz>>) -> Self {
@@ -160,6 +378,12 @@ impl RRuleSet {
         &self.rdate
     }
 
+    /// Returns the rdates with a period of the set.
+    #[must_use]
+    pub fn get_rdate_period(&self) -> &Vec<Period> {
+        &self.rdate_period
+    }
+
     /// Returns the exdates of the set.
     #[must_use]
     pub fn get_exdate(&self) -> &Vec<DateTime<Tz>> {
@@ -172,6 +396,690 @@ impl RRuleSet {
         &self.dt_start
     }
 
+    /// Returns the end of the first occurrence, if set with `DTEND`.
+    #[must_use]
+    pub fn get_dt_end(&self) -> Option<&DateTime<Tz>> {
+        self.dt_end.as_ref()
+    }
+
+    /// Returns the duration of the occurrences, if set with `DURATION`.
+    #[must_use]
+    pub fn get_duration(&self) -> Option<&ICalDuration> {
+        self.duration.as_ref()
+    }
+
+    /// Returns the limits used to validate the rrules and to iterate.
+    #[must_use]
+    pub fn get_limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    /// Returns the deadline of the iterations over the set.
+    #[must_use]
+    pub fn get_deadline(&self) -> Option<&Instant> {
+        self.budget.deadline.as_ref()
+    }
+
+    /// Returns the cancellation token of the iterations over the set.
+    #[must_use]
+    pub fn get_cancellation_token(&self) -> Option<&CancellationToken> {
+        self.budget.token.as_ref()
+    }
+
+    /// Returns `true` if the set consists of dates without a time (`VALUE=DATE`).
+    #[must_use]
+    pub fn is_date_only(&self) -> bool {
+        self.date_only
+    }
+
+    /// Returns an iterator over the dates of the recurrences, in the timezone of the start datetime.
+    ///
+    /// This is mostly useful for sets of all-day dates, see [`RRuleSet::date_only`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;VALUE=DATE:20230101\nRRULE:FREQ=YEARLY;COUNT=2"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let dates = rrule_set.iter_dates().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     dates,
+    ///     vec![
+    ///         NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn iter_dates(&self) -> RRuleSetDateIter {
+        RRuleSetDateIter::new(self.into_iter(), self.dt_start.timezone())
+    }
+
+    /// Returns an iterator over the [`crate::Occurrence`]s of the set, with their start, end and source.
+    ///
+    /// The end is derived from the `DTEND` or `DURATION` of the set. Nominal durations,
+    /// like `P1D`, keep the time of day across DST changes. Occurrences from an
+    /// `RDATE;VALUE=PERIOD` end at the end of their period.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nDURATION:PT1H30M\nRRULE:FREQ=DAILY;COUNT=2"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let occurrence = rrule_set.occurrences().next().unwrap();
+    /// assert_eq!(occurrence.end.to_rfc3339(), "2023-01-01T10:30:00+00:00");
+    /// ```
+    #[must_use]
+    pub fn occurrences(&self) -> OccurrenceIter {
+        OccurrenceIter::new(self.into_iter(), self.event_length())
+    }
+
+    /// Returns an iterator over the recurrences together with the rrule or rdate they
+    /// come from.
+    ///
+    /// Dates removed by an exrule or exdate can be included with
+    /// [`crate::SourcedIter::with_exclusions`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{ExclusionSource, OccurrenceSource, RRuleSet};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nRRULE:FREQ=DAILY;COUNT=2\nRDATE:20230105T090000Z\nEXDATE:20230102T090000Z"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let sources = rrule_set
+    ///     .iter_sourced()
+    ///     .with_exclusions()
+    ///     .map(|date| (date.source, date.excluded_by))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     sources,
+    ///     vec![
+    ///         (OccurrenceSource::RRule(0), None),
+    ///         (OccurrenceSource::RRule(0), Some(ExclusionSource::ExDate(0))),
+    ///         (OccurrenceSource::RDate(0), None),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn iter_sourced(&self) -> SourcedIter {
+        SourcedIter::new(self.into_iter())
+    }
+
+    /// Returns an iterator over the recurrences in descending order, starting with the
+    /// latest one.
+    ///
+    /// The rrules are walked backwards from their `UNTIL`, or from the date set with
+    /// [`RRuleSet::before`], which is inclusive. Rrules with a `COUNT` are counted from the
+    /// start first. Iteration stops at the date set with [`RRuleSet::after`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`] if an rrule has neither an `UNTIL` nor a `COUNT`, and no
+    /// [`RRuleSet::before`] date is set.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nRRULE:FREQ=DAILY\nEXDATE:20240101T090000Z"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let before = Tz::UTC.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    /// let mut iter = rrule_set.before(before).iter_rev().unwrap();
+    /// assert_eq!(iter.next().unwrap().to_rfc3339(), "2023-12-31T09:00:00+00:00");
+    /// assert_eq!(iter.next().unwrap().to_rfc3339(), "2023-12-30T09:00:00+00:00");
+    /// ```
+    pub fn iter_rev(&self) -> Result<RRuleSetRevIter, RRuleError> {
+        RRuleSetRevIter::new(self)
+    }
+
+    /// Returns an iterator which resumes the iteration where it stopped when the cursor
+    /// was created with [`crate::RRuleSetIter::cursor`].
+    ///
+    /// The cursor has to be created by an iterator over this set, or an equal set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`] if the cursor doesn't match the rules and dates of the set.
+    pub fn iter_from_cursor(&self, cursor: &RRuleSetCursor) -> Result<RRuleSetIter, RRuleError> {
+        RRuleSetIter::from_cursor(self, cursor)
+    }
+
+    /// Returns the first recurrence after `dt`, or at `dt` if `inclusive` is set.
+    ///
+    /// The iteration jumps directly to `dt`, see [`RRuleSet::after`], and the bounds set
+    /// with [`RRuleSet::after`] and [`RRuleSet::before`] are ignored. `None` is returned
+    /// if there is no such recurrence, or if the validation limits were reached.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:19900101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    ///
+    /// let dt = Tz::UTC.with_ymd_and_hms(2030, 1, 1, 9, 0, 0).unwrap();
+    /// assert_eq!(rrule_set.next_after(dt, true), Some(dt));
+    /// assert_eq!(
+    ///     rrule_set.next_after(dt, false),
+    ///     Some(Tz::UTC.with_ymd_and_hms(2030, 1, 2, 9, 0, 0).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn next_after(&self, dt: DateTime<Tz>, inclusive: bool) -> Option<DateTime<Tz>> {
+        let rrule_set = Self {
+            after: Some(dt),
+            before: None,
+            limited: true,
+            ..self.clone()
+        };
+        rrule_set.into_iter().find(|date| inclusive || *date > dt)
+    }
+
+    /// Returns the last recurrence before `dt`, or at `dt` if `inclusive` is set.
+    ///
+    /// The recurrences are walked backwards from `dt`, see [`RRuleSet::iter_rev`], and the
+    /// bounds set with [`RRuleSet::after`] and [`RRuleSet::before`] are ignored. `None` is
+    /// returned if there is no such recurrence, or if the validation limits were reached.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:19900101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    ///
+    /// let dt = Tz::UTC.with_ymd_and_hms(2030, 1, 1, 9, 0, 0).unwrap();
+    /// assert_eq!(rrule_set.previous_before(dt, true), Some(dt));
+    /// assert_eq!(
+    ///     rrule_set.previous_before(dt, false),
+    ///     Some(Tz::UTC.with_ymd_and_hms(2029, 12, 31, 9, 0, 0).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn previous_before(&self, dt: DateTime<Tz>, inclusive: bool) -> Option<DateTime<Tz>> {
+        let rrule_set = Self {
+            after: None,
+            before: Some(dt),
+            limited: true,
+            ..self.clone()
+        };
+        rrule_set
+            .iter_rev()
+            .ok()?
+            .find(|date| inclusive || *date < dt)
+    }
+
+    /// Returns `true` if `dt` is one of the recurrences of the set.
+    ///
+    /// This takes the exrules and exdates into account, and jumps directly to `dt`
+    /// like [`RRuleSet::next_after`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:19900101T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// assert!(rrule_set.contains(&Tz::UTC.with_ymd_and_hms(2030, 1, 2, 9, 0, 0).unwrap()));
+    /// assert!(!rrule_set.contains(&Tz::UTC.with_ymd_and_hms(2030, 1, 3, 9, 0, 0).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, dt: &DateTime<Tz>) -> bool {
+        self.next_after(*dt, true).as_ref() == Some(dt)
+    }
+
+    /// Explains why `dt` is or isn't a recurrence of the set.
+    ///
+    /// The report tells which parts of each rrule and exrule accept or reject `dt`,
+    /// and which rdate or exdate is equal to it. The bounds set with [`RRuleSet::after`]
+    /// and [`RRuleSet::before`] are not taken into account.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRulePart, RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20230102T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let explanation = rrule_set.explain(&Tz::UTC.with_ymd_and_hms(2023, 1, 5, 9, 0, 0).unwrap());
+    /// assert!(!explanation.is_occurrence());
+    /// assert_eq!(
+    ///     explanation.rrules[0].rejected_by().collect::<Vec<_>>(),
+    ///     vec![RRulePart::ByWeekday]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn explain(&self, dt: &DateTime<Tz>) -> Explanation {
+        crate::iter::explain(self, dt)
+    }
+
+    /// Describes the set in English, with its rrules, rdates, exrules and exdates.
+    ///
+    /// See [`RRule::to_text`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY;UNTIL=20230131T090000Z\nEXDATE:20230105T090000Z"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     rrule_set.to_text(),
+    ///     "every day at 09:00 until January 31, 2023, except on January 5, 2023 at 09:00"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        self.to_localized_text(&English)
+    }
+
+    /// Describes the set in the language of `locale`.
+    ///
+    /// See [`RRuleSet::to_text`] and [`Locale`].
+    #[must_use]
+    pub fn to_localized_text(&self, locale: &dyn Locale) -> String {
+        crate::text::rrule_set_to_text(self, locale)
+    }
+
+    /// Returns the occurrences that overlap the range `[start, end)`, including the ones
+    /// that started before `start` but haven't ended yet.
+    ///
+    /// The end of an occurrence is derived like in [`RRuleSet::occurrences`]. Occurrences
+    /// without a length are returned when they start in the range.
+    /// Limit must be set in order to prevent infinite loops, like in [`RRuleSet::all`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20230101T220000Z\nDURATION:PT4H\nRRULE:FREQ=DAILY"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let start = Tz::UTC.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+    /// let end = Tz::UTC.with_ymd_and_hms(2023, 1, 3, 0, 0, 0).unwrap();
+    /// let result = rrule_set.overlapping(start, end, 10);
+    ///
+    /// // The occurrence of the 1st ends on the 2nd, so it overlaps the range.
+    /// assert_eq!(result.occurrences.len(), 2);
+    /// assert_eq!(result.occurrences[0].start.to_rfc3339(), "2023-01-01T22:00:00+00:00");
+    /// ```
+    #[must_use]
+    pub fn overlapping(
+        &self,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        limit: u16,
+    ) -> OccurrenceResult {
+        // Occurrences that start before this can't overlap the range.
+        let earliest_start = start
+            .checked_sub_signed(self.max_duration())
+            .unwrap_or_else(|| DateTime::<Utc>::MIN_UTC.with_timezone(&Tz::UTC));
+
+        let mut iter = self.clone().after(earliest_start).limit().occurrences();
+        let mut occurrences = vec![];
+        let mut limited = false;
+        loop {
+            if occurrences.len() >= usize::from(limit) {
+                limited = true;
+                break;
+            }
+            let Some(occurrence) = iter.next() else {
+                limited = iter.was_limited();
+                break;
+            };
+            if occurrence.start >= end {
+                break;
+            }
+            if overlaps(&occurrence, &start, &end) {
+                occurrences.push(occurrence);
+            }
+        }
+
+        OccurrenceResult {
+            occurrences,
+            limited,
+        }
+    }
+
+    /// Returns the first `limit` pairs of occurrences of this set and `other` which overlap,
+    /// for the conflicts which start before `horizon`.
+    ///
+    /// The ends of the occurrences are derived like in [`RRuleSet::occurrences`]. Occurrences
+    /// without a length conflict with the occurrences they start in, or that start at the
+    /// same time. The sets can have different start dates and timezones.
+    ///
+    /// When one of the sets has no occurrences for a while, the other set jumps ahead
+    /// to its next occurrence, skipping whole periods of its rrules instead of generating all
+    /// the occurrences in between. Like with [`RRuleSet::after`], rules with a `COUNT` still
+    /// have to go through all their occurrences.
+    ///
+    /// If no conflicts are returned and [`ConflictResult::reason`] is
+    /// [`TerminationReason::Finished`], the sets don't overlap before `horizon`.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, TerminationReason, Tz};
+    ///
+    /// let review: RRuleSet = "DTSTART:20230102T100000Z\nDURATION:PT2H\nRRULE:FREQ=YEARLY"
+    ///     .parse()
+    ///     .unwrap();
+    /// let heartbeat: RRuleSet = "DTSTART:20230101T000000Z\nDURATION:PT10S\nRRULE:FREQ=MINUTELY;BYMINUTE=30"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let horizon = Tz::UTC.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+    /// let result = review.conflicts(&heartbeat, horizon, 3);
+    /// assert_eq!(result.conflicts.len(), 3);
+    /// assert_eq!(result.conflicts[0].start().to_rfc3339(), "2023-01-02T10:30:00+00:00");
+    /// assert_eq!(result.conflicts[2].start().to_rfc3339(), "2024-01-02T10:30:00+00:00");
+    ///
+    /// let lunch: RRuleSet = "DTSTART:20230101T120000Z\nDURATION:PT1H\nRRULE:FREQ=DAILY"
+    ///     .parse()
+    ///     .unwrap();
+    /// let result = review.conflicts(&lunch, horizon, 10);
+    /// assert!(result.conflicts.is_empty());
+    /// assert_eq!(result.reason, TerminationReason::Finished);
+    /// ```
+    #[must_use]
+    pub fn conflicts(&self, other: &Self, horizon: DateTime<Tz>, limit: u16) -> ConflictResult {
+        crate::iter::find_conflicts(self, other, &horizon, limit)
+    }
+
+    /// Splits the set at the recurrence `dt`, for "this and following" edits.
+    ///
+    /// The first set keeps the recurrences before `dt`, and the second set starts at `dt`
+    /// with the recurrences from `dt` on, in the same timezone. Rrules with a `COUNT` get the
+    /// number of their recurrences in each part as `COUNT`, other rrules of the first set
+    /// get their last recurrence before `dt` as `UNTIL`. Rrules without recurrences in a part
+    /// are left out of it. The rdates and exdates are split at `dt` as well. Exrules with a
+    /// `COUNT` are counted from `DTSTART`, so the second set gets their dates from `dt` on
+    /// as exdates instead.
+    ///
+    /// The rules of the second set count their `INTERVAL` from `dt`. Rules which don't
+    /// recur in step with `dt`, like a rule with `INTERVAL=2` which skips the period of `dt`,
+    /// are replaced by their dates from `dt` on, as rdates or exdates of the second set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::InvalidSplitDate`] if `dt` isn't a recurrence of the set
+    /// after its `DTSTART`, [`ValidationError::MisalignedSplitDate`] if a rule which
+    /// doesn't recur in step with `dt` has neither `COUNT` nor `UNTIL`, and
+    /// [`RRuleError::IterError`] if the recurrences of a rule can't be found within the
+    /// validation limits.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20230102T090000\nRRULE:FREQ=WEEKLY;COUNT=10\nEXDATE;TZID=Europe/Berlin:20230109T090000,20230220T090000"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let dt = Tz::Europe__Berlin.with_ymd_and_hms(2023, 1, 23, 9, 0, 0).unwrap();
+    /// let (before, after) = rrule_set.split_at(dt).unwrap();
+    ///
+    /// assert_eq!(before.get_rrule()[0].get_count(), Some(3));
+    /// assert_eq!(before.get_exdate().len(), 1);
+    /// assert_eq!(before.clone().all(10).dates.len(), 2);
+    ///
+    /// assert_eq!(after.get_dt_start(), &dt);
+    /// assert_eq!(after.get_rrule()[0].get_count(), Some(7));
+    /// assert_eq!(after.get_exdate().len(), 1);
+    /// assert_eq!(after.all(10).dates.len(), 6);
+    /// ```
+    pub fn split_at(&self, dt: DateTime<Tz>) -> Result<(Self, Self), RRuleError> {
+        let tz = self.dt_start.timezone();
+        let dt = dt.with_timezone(&tz);
+        if dt <= self.dt_start || !self.contains(&dt) {
+            return Err(ValidationError::InvalidSplitDate(dt.to_rfc3339()).into());
+        }
+
+        let (first_rdates, mut second_rdates): (Vec<_>, Vec<_>) =
+            self.rdate.iter().partition(|rdate| **rdate < dt);
+        let (first_periods, second_periods) = self
+            .rdate_period
+            .iter()
+            .partition(|period| *period.get_start() < dt);
+        let (first_exdates, mut second_exdates): (Vec<_>, Vec<_>) =
+            self.exdate.iter().partition(|exdate| **exdate < dt);
+
+        // Rules which don't have the same periods when they start at `dt` would generate
+        // other dates in the second set, so their dates are kept instead.
+        let start_period = DateTimeIter::from(&self.dt_start);
+        let is_in_phase = |rrule: &RRule| start_period.is_in_phase(rrule, &dt.naive_local());
+
+        let mut first_rrules = vec![];
+        let mut second_rrules = vec![];
+        for rrule in &self.rrule {
+            let (first, second) = self.split_rrule(rrule, &dt)?;
+            first_rrules.extend(first);
+            if is_in_phase(rrule) {
+                second_rrules.extend(second);
+            } else {
+                second_rdates.extend(self.dates_from(rrule, &dt)?);
+            }
+        }
+
+        let mut second_exrules = vec![];
+        for exrule in &self.exrule {
+            // The `COUNT` is counted from `DTSTART`, so the dates are kept instead too.
+            if exrule.count.is_none() && is_in_phase(exrule) {
+                second_exrules.push(exrule.clone());
+            } else {
+                second_exdates.extend(self.dates_from(exrule, &dt)?);
+            }
+        }
+
+        let first = Self {
+            rrule: first_rrules,
+            rdate: first_rdates,
+            rdate_period: first_periods,
+            exdate: first_exdates,
+            ..self.clone()
+        };
+        let second = Self {
+            rrule: second_rrules,
+            rdate: second_rdates,
+            rdate_period: second_periods,
+            exrule: second_exrules,
+            exdate: second_exdates,
+            dt_start: dt,
+            dt_end: self.dt_end.map(|dt_end| dt + (dt_end - self.dt_start)),
+            ..self.clone()
+        };
+        Ok((first, second))
+    }
+
+    /// Returns a copy of the set with only `rrule`, bounded by `before`.
+    fn with_single_rrule(&self, rrule: &RRule, before: DateTime<Tz>) -> Self {
+        Self {
+            rrule: vec![rrule.clone()],
+            rdate: vec![],
+            rdate_period: vec![],
+            exrule: vec![],
+            exdate: vec![],
+            before: Some(before),
+            after: None,
+            limited: true,
+            ..self.clone()
+        }
+    }
+
+    /// Splits `rrule` into the rrules for the recurrences before `dt` and from `dt` on,
+    /// see [`RRuleSet::split_at`].
+    fn split_rrule(
+        &self,
+        rrule: &RRule,
+        dt: &DateTime<Tz>,
+    ) -> Result<(Option<RRule>, Option<RRule>), RRuleError> {
+        let rrule_set = self.with_single_rrule(rrule, *dt);
+
+        if let Some(count) = rrule.count {
+            let mut iter = (&rrule_set).into_iter();
+            let mut before = 0;
+            let mut reached = false;
+            for date in iter.by_ref() {
+                if date >= *dt {
+                    reached = true;
+                    break;
+                }
+                before += 1;
+            }
+            if !reached {
+                check_split_iteration(rrule, dt, iter.termination_reason())?;
+            }
+            let with_count = |count| {
+                (count > 0).then(|| RRule {
+                    count: Some(count),
+                    ..rrule.clone()
+                })
+            };
+            return Ok((with_count(before), with_count(count.saturating_sub(before))));
+        }
+
+        let mut iter = rrule_set.iter_rev()?;
+        let last = iter.find(|date| date < dt);
+        if last.is_none() {
+            check_split_iteration(rrule, dt, iter.termination_reason())?;
+        }
+        let until_tz = if dt.timezone().is_local() {
+            dt.timezone()
+        } else {
+            Tz::UTC
+        };
+        let first = last.map(|last| RRule {
+            until: Some(last.with_timezone(&until_tz)),
+            ..rrule.clone()
+        });
+        let second = rrule
+            .until
+            .map_or(true, |until| until >= *dt)
+            .then(|| rrule.clone());
+        Ok((first, second))
+    }
+
+    /// Returns the dates of `rrule` from `dt` on, for the second set of
+    /// [`RRuleSet::split_at`] when it can't keep `rrule` itself.
+    fn dates_from(
+        &self,
+        rrule: &RRule,
+        dt: &DateTime<Tz>,
+    ) -> Result<Vec<DateTime<Tz>>, RRuleError> {
+        if rrule.count.is_none() && rrule.until.is_none() {
+            return Err(ValidationError::MisalignedSplitDate {
+                rrule: rrule.to_string(),
+                dt: dt.to_rfc3339(),
+            }
+            .into());
+        }
+        let rrule_set = self.with_single_rrule(rrule, *dt);
+        let mut iter = (&rrule_set).into_iter();
+        let dates = iter.by_ref().filter(|date| date >= dt).collect();
+        check_split_iteration(rrule, dt, iter.termination_reason())?;
+        Ok(dates)
+    }
+
+    /// Returns the longest exact length an occurrence of the set can have.
+    pub(crate) fn max_duration(&self) -> chrono::Duration {
+        self.rdate_period
+            .iter()
+            .filter_map(|period| Some(period.get_end()? - *period.get_start()))
+            .fold(self.event_length().max_duration(), std::cmp::max)
+    }
+
+    /// Returns the length of the occurrences of the set.
+    pub(crate) fn event_length(&self) -> EventLength {
+        match (&self.dt_end, &self.duration) {
+            (_, Some(duration)) => EventLength::Nominal(*duration),
+            (Some(dt_end), None) if self.date_only => {
+                let days = (dt_end.date_naive() - self.dt_start.date_naive()).num_days();
+                EventLength::Nominal(ICalDuration {
+                    days: u32::try_from(days).unwrap_or_default(),
+                    ..Default::default()
+                })
+            }
+            (Some(dt_end), None) => EventLength::Exact(*dt_end - self.dt_start),
+            // An all-day event without an end lasts for one day.
+            (None, None) if self.date_only => EventLength::Nominal(ICalDuration {
+                days: 1,
+                ..Default::default()
+            }),
+            (None, None) => EventLength::Exact(chrono::Duration::zero()),
+        }
+    }
+
+    /// Moves the given dates to the start of their day in the timezone of the start datetime.
+    pub(crate) fn dates_at_start_of_day(&self, dates: &[DateTime<Tz>]) -> Vec<DateTime<Tz>> {
+        let tz = self.dt_start.timezone();
+        dates
+            .iter()
+            .map(|date| start_of_day(date.with_timezone(&tz).date_naive(), tz))
+            .collect()
+    }
+
+    /// Returns the dates of a `RDATE` or `EXDATE` line, as the set expects them.
+    fn content_line_dates(&self, line: DateContentLine) -> Vec<DateTime<Tz>> {
+        if line.date_only || self.date_only {
+            // `VALUE=DATE` dates don't have a timezone, so take the date in the UTC (or `TZID`)
+            // timezone they were parsed in, and move it to the timezone of the start.
+            line.dates
+                .iter()
+                .map(|date| start_of_day(date.date_naive(), self.dt_start.timezone()))
+                .collect()
+        } else {
+            line.dates
+        }
+    }
+
+    /// Checks that an rrule of a date-only set has no time parts, and moves its `UNTIL`,
+    /// which is a date, to the start of that day.
+    fn date_only_rrule(
+        &self,
+        mut rrule: RRule<Unvalidated>,
+    ) -> Result<RRule<Unvalidated>, RRuleError> {
+        if self.date_only {
+            validate_date_only_times(&rrule, &self.dt_start)?;
+            rrule.until = rrule.until.map(|until| {
+                start_of_day(until.date_naive(), self.dt_start.timezone()).with_timezone(&Tz::UTC)
+            });
+        }
+        Ok(rrule)
+    }
+
     /// Returns all the recurrences of the rrule.
     ///
     /// Limit must be set in order to prevent infinite loops.
@@ -212,21 +1120,26 @@ impl RRuleSet {
         collect_with_error(self.into_iter(), &self.after, &self.before, true, None).dates
     }
 
-    fn set_from_content_lines(self, content_lines: Vec<ContentLine>) -> Result<Self, RRuleError> {
+    pub(crate) fn set_from_content_lines(
+        self,
+        content_lines: Vec<ContentLine>,
+    ) -> Result<Self, RRuleError> {
         let dt_start = self.dt_start;
 
         content_lines.into_iter().try_fold(
             self,
             |rrule_set, content_line| match content_line {
-                ContentLine::RRule(rrule) => rrule
-                    .validate(dt_start)
+                ContentLine::RRule(rrule) => rrule_set
+                    .date_only_rrule(rrule)?
+                    .validate_with_limits(dt_start, &rrule_set.limits)
                     .map(|rrule| rrule_set.rrule(rrule)),
                 #[allow(unused_variables)]
                 ContentLine::ExRule(exrule) => {
                     #[cfg(feature = "exrule")]
                     {
-                        exrule
-                            .validate(dt_start)
+                        rrule_set
+                            .date_only_rrule(exrule)?
+                            .validate_with_limits(dt_start, &rrule_set.limits)
                             .map(|exrule| rrule_set.exrule(exrule))
                     }
                     #[cfg(not(feature = "exrule"))]
@@ -236,10 +1149,23 @@ impl RRuleSet {
                     }
                 }
                 ContentLine::ExDate(exdates) => {
+                    let exdates = rrule_set.content_line_dates(exdates);
                     Ok(exdates.into_iter().fold(rrule_set, Self::exdate))
                 }
-                ContentLine::RDate(rdates) => {
-                    Ok(rdates.into_iter().fold(rrule_set, Self::rdate))
+                ContentLine::DtEnd(dt_end) => {
+                    let dt_end = if dt_end.is_date() || rrule_set.date_only {
+                        start_of_day(dt_end.datetime.date_naive(), dt_start.timezone())
+                    } else {
+                        dt_end.datetime
+                    };
+                    rrule_set.dt_end(dt_end)
+                }
+                ContentLine::Duration(duration) => rrule_set.duration(duration),
+                ContentLine::RDate(mut rdates) => {
+                    let periods = std::mem::take(&mut rdates.periods);
+                    let rdates = rrule_set.content_line_dates(rdates);
+                    let rrule_set = rdates.into_iter().fold(rrule_set, Self::rdate);
+                    Ok(periods.into_iter().fold(rrule_set, Self::rdate_period))
                 }
             },
         )
@@ -254,21 +1180,21 @@ impl RRuleSet {
 
         if let Some(dtstart) = start {
             self.dt_start = dtstart.datetime;
+            if dtstart.is_date() {
+                self = self.date_only()?;
+            }
         }
 
         self.set_from_content_lines(content_lines)
     }
-}
 
-impl FromStr for RRuleSet {
-    type Err = RRuleError;
-
-    /// Creates an [`RRuleSet`] from a string if input is valid.
+    /// Creates an [`RRuleSet`] from a string like [`FromStr`], but validates the rrules
+    /// with `limits` instead of the default [`Limits`]. The set keeps the limits for iterating.
     ///
     /// # Errors
     ///
     /// Returns [`RRuleError`], if iCalendar string contains invalid parts.
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    pub fn from_str_with_limits(s: &str, limits: Limits) -> Result<Self, RRuleError> {
         let Grammar {
             start,
             content_lines,
@@ -276,20 +1202,83 @@ impl FromStr for RRuleSet {
 
         let start = start.ok_or(ParseError::MissingStartDate)?;
 
-        Self::new(start.datetime).set_from_content_lines(content_lines)
+        Self::from_start_date(&start)
+            .limits(limits)
+            .set_from_content_lines(content_lines)
+    }
+}
+
+/// Returns an error if the iteration over `rrule` for [`RRuleSet::split_at`] stopped
+/// because of the validation limits or the budget.
+fn check_split_iteration(
+    rrule: &RRule,
+    dt: &DateTime<Tz>,
+    reason: Option<TerminationReason>,
+) -> Result<(), RRuleError> {
+    match reason {
+        Some(reason) if reason != TerminationReason::Finished => {
+            Err(RRuleError::new_iter_err(format!(
+                "Unable to find the recurrences of `{rrule}` around `{}`: {reason:?}",
+                dt.to_rfc3339()
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl FromStr for RRuleSet {
+    type Err = RRuleError;
+
+    /// Creates an [`RRuleSet`] from a string if input is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`], if iCalendar string contains invalid parts.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_limits(s, Limits::default())
     }
 }
 
 impl Display for RRuleSet {
     /// Prints a valid set of iCalendar properties which can be used to create a new [`RRuleSet`] later.
     /// You may use the generated string to create a new iCalendar component, like VEVENT.
+    /// Lines longer than 75 octets are folded.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let start_datetime = format!("DTSTART{}", datetime_to_ical_format(&self.dt_start));
+        let tz = self.dt_start.timezone();
+        let date_only_tz = self.date_only.then_some(tz);
+        let (start_datetime, value) = if self.date_only {
+            (
+                format!("DTSTART{}", date_to_ical_format(&self.dt_start)),
+                "DATE",
+            )
+        } else {
+            (
+                format!("DTSTART{}", datetime_to_ical_format(&self.dt_start)),
+                "DATE-TIME",
+            )
+        };
+        let end = match (&self.dt_end, &self.duration) {
+            (Some(dt_end), _) if self.date_only => {
+                format!("\nDTEND{}", date_to_ical_format(dt_end))
+            }
+            (Some(dt_end), _) => format!("\nDTEND{}", datetime_to_ical_format(dt_end)),
+            (None, Some(duration)) => format!("\nDURATION:{duration}"),
+            (None, None) => String::new(),
+        };
+        let format_date = |dt: &DateTime<Tz>| {
+            if self.date_only {
+                dt.with_timezone(&tz).format("%Y%m%d").to_string()
+            } else {
+                let maybe_zulu = if dt.timezone().is_local() { "" } else { "Z" };
+
+                format!("{}{}", dt.format("%Y%m%dT%H%M%S"), maybe_zulu)
+            }
+        };
 
         let mut rrules = self
             .rrule
             .iter()
-            .map(|rrule| format!("RRULE:{rrule}"))
+            .map(|rrule| format!("RRULE:{}", rrule.to_ical_string(date_only_tz)))
             .collect::<Vec<_>>()
             .join("\n");
         if !rrules.is_empty() {
@@ -299,22 +1288,27 @@ impl Display for RRuleSet {
         let mut rdates = self
             .rdate
             .iter()
-            .map(|dt| {
-                let maybe_zulu = if dt.timezone().is_local() { "" } else { "Z" };
-
-                format!("{}{}", dt.format("%Y%m%dT%H%M%S"), maybe_zulu)
-            })
+            .map(format_date)
             .collect::<Vec<_>>()
             .join(",");
         if !rdates.is_empty() {
-            // TODO: check if original VALUE prop was DATE or PERIOD
-            rdates = format!("\nRDATE;VALUE=DATE-TIME:{rdates}");
+            rdates = format!("\nRDATE;VALUE={value}:{rdates}");
+        }
+
+        let mut rdate_periods = self
+            .rdate_period
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        if !rdate_periods.is_empty() {
+            rdate_periods = format!("\nRDATE;VALUE=PERIOD:{rdate_periods}");
         }
 
         let mut exrules = self
             .exrule
             .iter()
-            .map(|exrule| format!("EXRULE:{exrule}"))
+            .map(|exrule| format!("EXRULE:{}", exrule.to_ical_string(date_only_tz)))
             .collect::<Vec<_>>()
             .join("\n");
         if !exrules.is_empty() {
@@ -324,19 +1318,22 @@ impl Display for RRuleSet {
         let mut exdates = self
             .exdate
             .iter()
-            .map(|dt| {
-                let maybe_zulu = if dt.timezone().is_local() { "" } else { "Z" };
-
-                format!("{}{}", dt.format("%Y%m%dT%H%M%S"), maybe_zulu)
-            })
+            .map(format_date)
             .collect::<Vec<_>>()
             .join(",");
         if !exdates.is_empty() {
-            // TODO: check if original VALUE prop was DATE or PERIOD
-            exdates = format!("\nEXDATE;VALUE=DATE-TIME:{exdates}");
+            exdates = format!("\nEXDATE;VALUE={value}:{exdates}");
         }
 
-        write!(f, "{start_datetime}{rrules}{rdates}{exrules}{exdates}")
+        let content_lines =
+            format!("{start_datetime}{end}{rrules}{rdates}{rdate_periods}{exrules}{exdates}");
+        let folded_content_lines = content_lines
+            .lines()
+            .map(fold_content_line)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        write!(f, "{folded_content_lines}")
     }
 }
 
@@ -351,7 +1348,7 @@ mod tests {
 
     #[test]
     fn rruleset_string_roundtrip() {
-        let rruleset_str = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nRDATE;VALUE=DATE-TIME:19970101T000000Z,19970120T000000Z\nEXRULE:FREQ=YEARLY;COUNT=8;BYMONTH=6,7;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nEXDATE;VALUE=DATE-TIME:19970121T000000Z";
+        let rruleset_str = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nRDATE;VALUE=DATE-TIME:19970101T000000Z,19970120T000000Z\nEXRULE:FREQ=YEARLY;COUNT=8;BYMONTH=6,7;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=30;BY\n SECOND=0\nEXDATE;VALUE=DATE-TIME:19970121T000000Z";
         let rruleset = RRuleSet::from_str(rruleset_str).unwrap();
 
         // Check start date
@@ -398,7 +1395,7 @@ mod tests {
 
     #[test]
     fn respect_local_timezone_in_exdates_rdates() {
-        let rruleset_str = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nRDATE;VALUE=DATE-TIME:19970101T000000,19970120T000000\nEXRULE:FREQ=YEARLY;COUNT=8;BYMONTH=6,7;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nEXDATE;VALUE=DATE-TIME:19970121T000000";
+        let rruleset_str = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nRDATE;VALUE=DATE-TIME:19970101T000000,19970120T000000\nEXRULE:FREQ=YEARLY;COUNT=8;BYMONTH=6,7;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=30;BY\n SECOND=0\nEXDATE;VALUE=DATE-TIME:19970121T000000";
         let rruleset = RRuleSet::from_str(rruleset_str).unwrap();
 
         // Serialize to string again
@@ -407,10 +1404,26 @@ mod tests {
 
     #[test]
     fn respect_utc_timezone_in_exdates_rdates() {
-        let rruleset_str = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nRDATE;VALUE=DATE-TIME:19970101T000000Z,19970120T000000Z\nEXRULE:FREQ=YEARLY;COUNT=8;BYMONTH=6,7;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nEXDATE;VALUE=DATE-TIME:19970121T000000Z";
+        let rruleset_str = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nRDATE;VALUE=DATE-TIME:19970101T000000Z,19970120T000000Z\nEXRULE:FREQ=YEARLY;COUNT=8;BYMONTH=6,7;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=30;BY\n SECOND=0\nEXDATE;VALUE=DATE-TIME:19970121T000000Z";
         let rruleset = RRuleSet::from_str(rruleset_str).unwrap();
 
         // Serialize to string again
         assert_eq!(rruleset.to_string(), rruleset_str);
     }
+
+    #[test]
+    fn folds_long_lines_on_roundtrip() {
+        let rruleset = RRuleSet::from_str(
+            "DTSTART:20120201T093000Z\r\nRDATE:20120202T093000Z,20120203T093000Z,20120204T093000Z,2012\r\n 0205T093000Z,20120206T093000Z",
+        )
+        .unwrap();
+        assert_eq!(rruleset.rdate.len(), 5);
+
+        let rruleset_str = rruleset.to_string();
+        assert_eq!(
+            rruleset_str,
+            "DTSTART:20120201T093000Z\nRDATE;VALUE=DATE-TIME:20120202T093000Z,20120203T093000Z,20120204T093000Z,20\n 120205T093000Z,20120206T093000Z"
+        );
+        assert_eq!(RRuleSet::from_str(&rruleset_str).unwrap(), rruleset);
+    }
 }
@@ -1,5 +1,5 @@
-use crate::RRuleResult;
 use crate::{iter::rrule_iter::WasLimited, Tz};
+use crate::{RRuleResult, TerminationReason};
 use std::ops::{
     Bound::{Excluded, Unbounded},
     RangeBounds,
@@ -20,7 +20,8 @@ where
     T: Iterator<Item = chrono::DateTime<Tz>> + WasLimited,
 {
     let mut list = vec![];
-    let mut was_limited = false;
+    // The loop only ends without a `break` when the limit is reached.
+    let mut reason = TerminationReason::Limit;
     // This loop should always end because `.next()` has build in limits
     // Once a limit is tripped it will break in the `None` case.
     while limit.is_none() || matches!(limit, Some(limit) if usize::from(limit) > list.len()) {
@@ -30,19 +31,21 @@ where
             }
             if has_reached_the_end(&value, end, inclusive) {
                 // Date is after end date, so can stop iterating
+                reason = TerminationReason::Finished;
                 break;
             }
         } else {
-            was_limited = iterator.was_limited();
+            reason = iterator
+                .stopped_early()
+                .unwrap_or(TerminationReason::Finished);
             break;
         }
     }
 
-    was_limited = was_limited || matches!(limit, Some(limit) if usize::from(limit) == list.len());
-
     RRuleResult {
         dates: list,
-        limited: was_limited,
+        limited: reason.is_limited(),
+        reason,
     }
 }
 
@@ -0,0 +1,292 @@
+//! Conversion of [`RRule`] and [`RRuleSet`] from and to [xCal](https://datatracker.ietf.org/doc/html/rfc6321).
+use super::datetime::datetime_to_extended_format;
+use super::{PeriodEnd, RRule, RRuleSet, Tz, Unvalidated};
+use crate::parser::{
+    escape_xml, extended_to_basic_format, props_to_rrule, ContentLine, ParseError, PropertyName,
+    RRuleProperty, XmlElement,
+};
+use crate::RRuleError;
+use chrono::DateTime;
+use std::collections::HashMap;
+
+impl<S> RRule<S> {
+    /// Returns the rrule as an xCal `recur` element, like
+    /// `<recur><freq>WEEKLY</freq><byday>MO</byday><byday>FR</byday></recur>`.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRule, Unvalidated};
+    ///
+    /// let rrule: RRule<Unvalidated> = "FREQ=WEEKLY;COUNT=4;BYDAY=MO,FR".parse().unwrap();
+    /// assert_eq!(
+    ///     rrule.to_xcal(),
+    ///     "<recur><freq>WEEKLY</freq><count>4</count><byday>MO</byday><byday>FR</byday></recur>"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_xcal(&self) -> String {
+        recur_to_xcal(self, None)
+    }
+}
+
+impl RRule<Unvalidated> {
+    /// Parses an xCal `recur` element, like
+    /// `<recur><freq>WEEKLY</freq><byday>MO</byday><byday>FR</byday></recur>`.
+    /// The `recur` element can also be inside of other elements, like `rrule`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidXml`] if `xml` isn't valid XML,
+    /// [`ParseError::MissingProperty`] if there is no `recur` element,
+    /// and the same errors as an `RRULE` string for invalid rule parts.
+    pub fn from_xcal(xml: &str) -> Result<Self, ParseError> {
+        let root = XmlElement::parse(xml)?;
+        let recur = root
+            .find("recur")
+            .ok_or_else(|| ParseError::MissingProperty("recur".into()))?;
+        xcal_to_rrule(recur)
+    }
+}
+
+impl RRuleSet {
+    /// Returns the `DTSTART`, `DTEND` or `DURATION`, `RRULE`, `EXRULE`, `RDATE` and `EXDATE`
+    /// properties of the set as an xCal `properties` element.
+    ///
+    /// Dates are in the timezone of `DTSTART`, with a `tzid` parameter for a named timezone.
+    /// Periods are in UTC, like in [`crate::Period`]'s `Display`.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20240101T090000\nRRULE:FREQ=DAILY;COUNT=3"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert!(rrule_set.to_xcal().starts_with(
+    ///     "<properties><dtstart><parameters><tzid><text>Europe/Berlin</text></tzid></parameters>\
+    ///      <date-time>2024-01-01T09:00:00</date-time></dtstart>"
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn to_xcal(&self) -> String {
+        let tz = self.dt_start.timezone();
+        let date_only_tz = self.date_only.then_some(tz);
+        let parameters = match tz {
+            // Dates can't have a `TZID`.
+            Tz::Tz(tz) if tz != chrono_tz::UTC && !self.date_only => format!(
+                "<parameters><tzid><text>{}</text></tzid></parameters>",
+                escape_xml(tz.name())
+            ),
+            _ => String::new(),
+        };
+        let value_type = if self.date_only { "date" } else { "date-time" };
+        let date_property = |xml: &mut String, name: &str, dates: &[DateTime<Tz>]| {
+            xml.push_str(&format!("<{name}>{parameters}"));
+            for date in dates {
+                let date = if self.date_only {
+                    date.with_timezone(&tz).format("%Y-%m-%d").to_string()
+                } else {
+                    datetime_to_extended_format(date, tz)
+                };
+                xml.push_str(&format!("<{value_type}>{date}</{value_type}>"));
+            }
+            xml.push_str(&format!("</{name}>"));
+        };
+
+        let mut xml = "<properties>".to_string();
+        date_property(&mut xml, "dtstart", &[self.dt_start]);
+        if let Some(dt_end) = &self.dt_end {
+            date_property(&mut xml, "dtend", &[*dt_end]);
+        }
+        if let Some(duration) = &self.duration {
+            xml.push_str(&format!(
+                "<duration><duration>{duration}</duration></duration>"
+            ));
+        }
+        for rrule in &self.rrule {
+            xml.push_str(&format!(
+                "<rrule>{}</rrule>",
+                recur_to_xcal(rrule, date_only_tz)
+            ));
+        }
+        for exrule in &self.exrule {
+            xml.push_str(&format!(
+                "<exrule>{}</exrule>",
+                recur_to_xcal(exrule, date_only_tz)
+            ));
+        }
+        if !self.rdate.is_empty() {
+            date_property(&mut xml, "rdate", &self.rdate);
+        }
+        if !self.rdate_period.is_empty() {
+            xml.push_str("<rdate>");
+            for period in &self.rdate_period {
+                let (start, end) = period.to_extended_format();
+                let end_name = match period.get_period_end() {
+                    PeriodEnd::DateTime(_) => "end",
+                    PeriodEnd::Duration(_) => "duration",
+                };
+                xml.push_str(&format!(
+                    "<period><start>{start}</start><{end_name}>{end}</{end_name}></period>"
+                ));
+            }
+            xml.push_str("</rdate>");
+        }
+        if !self.exdate.is_empty() {
+            date_property(&mut xml, "exdate", &self.exdate);
+        }
+        xml.push_str("</properties>");
+        xml
+    }
+
+    /// Creates an [`RRuleSet`] from an xCal document, from the first `properties` element,
+    /// like the properties of a `vevent`. Properties other than `DTSTART`, `DTEND`,
+    /// `DURATION`, `RRULE`, `EXRULE`, `RDATE` and `EXDATE` are ignored.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set = RRuleSet::from_xcal(
+    ///     r#"<vevent xmlns="urn:ietf:params:xml:ns:icalendar-2.0"><properties>
+    ///         <summary><text>Stand-up</text></summary>
+    ///         <dtstart><date-time>2024-01-01T09:00:00Z</date-time></dtstart>
+    ///         <rrule><recur><freq>DAILY</freq><count>3</count></recur></rrule>
+    ///     </properties></vevent>"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(rrule_set.all(10).dates.len(), 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidXml`] if `xml` isn't valid XML,
+    /// [`ParseError::InvalidXCalProperty`] if a property isn't valid xCal,
+    /// and the same errors as an iCalendar string otherwise.
+    pub fn from_xcal(xml: &str) -> Result<Self, RRuleError> {
+        let root = XmlElement::parse(xml)?;
+        let properties = root
+            .find("properties")
+            .ok_or_else(|| ParseError::MissingProperty("properties".into()))?;
+
+        let mut lines = vec![];
+        let mut rules = vec![];
+        for property in &properties.children {
+            let Ok(property_name) = property.name.parse::<PropertyName>() else {
+                continue;
+            };
+            let invalid_property = || ParseError::InvalidXCalProperty(property.name.clone());
+            let values = property
+                .children
+                .iter()
+                .filter(|value| value.name != "parameters")
+                .collect::<Vec<_>>();
+            let Some(value_type) = values.first().map(|value| value.name.as_str()) else {
+                return Err(invalid_property().into());
+            };
+            if values.iter().any(|value| value.name != value_type) {
+                return Err(invalid_property().into());
+            }
+
+            match property_name {
+                PropertyName::RRule | PropertyName::ExRule => {
+                    if value_type != "recur" {
+                        return Err(unsupported_value_type(property_name, value_type).into());
+                    }
+                    for recur in values {
+                        let rrule = xcal_to_rrule(recur)?;
+                        rules.push(if property_name == PropertyName::RRule {
+                            ContentLine::RRule(rrule)
+                        } else {
+                            ContentLine::ExRule(rrule)
+                        });
+                    }
+                }
+                PropertyName::Duration => {
+                    let [duration] = &values[..] else {
+                        return Err(invalid_property().into());
+                    };
+                    if value_type != "duration" {
+                        return Err(unsupported_value_type(property_name, value_type).into());
+                    }
+                    lines.push((property_name, None, duration.trimmed_text().to_string()));
+                }
+                _ => {
+                    let mut parameters = format!("VALUE={}", value_type.to_uppercase());
+                    let tzid = property
+                        .child("parameters")
+                        .and_then(|parameters| parameters.child("tzid"))
+                        .and_then(|tzid| tzid.child("text"));
+                    if let Some(tzid) = tzid {
+                        parameters = format!("TZID={};{parameters}", tzid.trimmed_text());
+                    }
+                    let values = values
+                        .iter()
+                        .map(|value| xcal_to_ical_date(value).ok_or_else(invalid_property))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    lines.push((property_name, Some(parameters), values.join(",")));
+                }
+            }
+        }
+
+        Self::from_content_line_parts(&lines, rules)
+    }
+}
+
+fn unsupported_value_type(property_name: PropertyName, value_type: &str) -> ParseError {
+    ParseError::UnsupportedValueType {
+        property: property_name.to_string(),
+        value: value_type.to_uppercase(),
+    }
+}
+
+/// Returns the `recur` element of an rrule, see [`RRule::to_recur_parts`].
+/// Every value of a part is in its own element.
+fn recur_to_xcal<S>(rrule: &RRule<S>, date_only_tz: Option<Tz>) -> String {
+    let mut xml = "<recur>".to_string();
+    for (name, values) in rrule.to_recur_parts(date_only_tz) {
+        for value in values {
+            xml.push_str(&format!("<{name}>{}</{name}>", escape_xml(&value)));
+        }
+    }
+    xml.push_str("</recur>");
+    xml
+}
+
+/// Parses the rule parts of a `recur` element like the ones of an `RRULE` string.
+fn xcal_to_rrule(recur: &XmlElement) -> Result<RRule<Unvalidated>, ParseError> {
+    let mut props: HashMap<RRuleProperty, String> = HashMap::new();
+    for part in &recur.children {
+        let property = part.name.parse::<RRuleProperty>()?;
+        let value = if property == RRuleProperty::Until {
+            extended_to_basic_format(part.trimmed_text())
+        } else {
+            part.trimmed_text().to_string()
+        };
+        props
+            .entry(property)
+            .and_modify(|values| {
+                values.push(',');
+                values.push_str(&value);
+            })
+            .or_insert(value);
+    }
+    props_to_rrule(&props)
+}
+
+/// Converts an xCal `date`, `date-time` or `period` element to the iCalendar format.
+fn xcal_to_ical_date(value: &XmlElement) -> Option<String> {
+    if value.name != "period" {
+        return Some(extended_to_basic_format(value.trimmed_text()));
+    }
+    let start = extended_to_basic_format(value.child("start")?.trimmed_text());
+    let end = match (value.child("end"), value.child("duration")) {
+        (Some(end), None) => extended_to_basic_format(end.trimmed_text()),
+        (None, Some(duration)) => duration.trimmed_text().to_string(),
+        _ => return None,
+    };
+    Some(format!("{start}/{end}"))
+}
@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A token to stop the iteration of an [`crate::RRuleSet`] from another thread,
+/// see [`crate::RRuleSet::cancellation_token`].
+///
+/// Clones of the token share the same state, so cancelling one of them cancels all of them.
+///
+/// # Usage
+///
+/// ```
+/// use rrule::{CancellationToken, RRuleSet};
+///
+/// let token = CancellationToken::new();
+/// let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+/// let rrule_set = rrule_set.cancellation_token(token.clone());
+///
+/// token.cancel();
+/// let result = rrule_set.all(10);
+/// assert!(result.dates.is_empty());
+/// assert!(result.timed_out());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a token which isn't cancelled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the iterations which use this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Tokens are only equal to their clones.
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CancellationToken {}
+
+/// When an iteration has to stop, at a deadline or once a token is cancelled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Budget {
+    pub(crate) deadline: Option<Instant>,
+    pub(crate) token: Option<CancellationToken>,
+}
+
+impl Budget {
+    /// Returns `true` if the deadline has passed or the token has been cancelled.
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
@@ -0,0 +1,180 @@
+use super::counter_date::DateTimeIter;
+use super::rrule_iter::WasLimited;
+use super::{OccurrenceIter, TerminationReason};
+use crate::core::conflicts;
+use crate::{Conflict, ConflictResult, Occurrence, RRuleSet, Tz};
+use chrono::{DateTime, Duration, Utc};
+
+/// How many occurrences in a row of one set can't overlap the other set before the
+/// iteration jumps ahead to the next occurrence of the other set.
+const SKIP_AFTER: u32 = 8;
+
+/// The occurrences of one of the sets, in the order of their start.
+struct Schedule<'a> {
+    rrule_set: &'a RRuleSet,
+    iter: OccurrenceIter,
+    /// The next occurrence, which hasn't been compared yet.
+    next: Option<Occurrence>,
+    /// The compared occurrences which can still overlap the next ones of the other set.
+    active: Vec<Occurrence>,
+    /// The start of the last compared occurrence.
+    last_start: Option<DateTime<Tz>>,
+    /// The longest length of an occurrence of the set.
+    max_duration: Duration,
+    /// How many occurrences in a row couldn't overlap the other set.
+    passed: u32,
+    /// Whether restarting the iteration skips the periods in between, rather than
+    /// walking them again from the start.
+    can_skip: bool,
+}
+
+impl<'a> Schedule<'a> {
+    fn new(rrule_set: &'a RRuleSet) -> Self {
+        let mut iter = rrule_set.clone().limit().occurrences();
+        Self {
+            rrule_set,
+            next: iter.next(),
+            iter,
+            active: vec![],
+            last_start: None,
+            max_duration: rrule_set.max_duration(),
+            passed: 0,
+            can_skip: rrule_set
+                .rrule
+                .iter()
+                .chain(&rrule_set.exrule)
+                .all(|rrule| rrule.count.is_none() && DateTimeIter::can_skip(rrule)),
+        }
+    }
+
+    /// Takes the next occurrence, to compare it with the other set.
+    fn advance(&mut self) -> Option<Occurrence> {
+        let occurrence = self.next.take()?;
+        self.next = self.iter.next();
+        self.last_start = Some(occurrence.start);
+        Some(occurrence)
+    }
+
+    /// Jumps ahead to the occurrences which can still be in progress at `dt`.
+    ///
+    /// The iteration is restarted at the new position, which skips whole periods of the
+    /// rrules instead of generating all the occurrences in between. Nothing changes
+    /// for sets with rrules which have to be walked from the start anyway.
+    fn skip_to(&mut self, dt: &DateTime<Tz>) {
+        let earliest_start = dt
+            .checked_sub_signed(self.max_duration)
+            .unwrap_or_else(|| DateTime::<Utc>::MIN_UTC.with_timezone(&Tz::UTC));
+        if !self.can_skip || self.next.map_or(true, |next| next.start >= earliest_start) {
+            return;
+        }
+        let after = match self.rrule_set.after {
+            Some(after) if after > earliest_start => after,
+            _ => earliest_start,
+        };
+        self.iter = self.rrule_set.clone().after(after).limit().occurrences();
+        self.next = self.iter.next();
+        while self
+            .next
+            .is_some_and(|next| Some(next.start) <= self.last_start)
+        {
+            self.next = self.iter.next();
+        }
+    }
+
+    /// Forgets the compared occurrences which are over at `dt`.
+    fn retire(&mut self, dt: &DateTime<Tz>) {
+        self.active
+            .retain(|occurrence| occurrence.end > *dt || occurrence.start == *dt);
+    }
+
+    /// Returns `true` if the set can't have any more conflicts.
+    fn is_done(&self) -> bool {
+        self.next.is_none() && self.active.is_empty()
+    }
+}
+
+/// Finds the first `limit` conflicts between the occurrences of `first` and `second`
+/// which start before `horizon`.
+pub(crate) fn find_conflicts(
+    first: &RRuleSet,
+    second: &RRuleSet,
+    horizon: &DateTime<Tz>,
+    limit: u16,
+) -> ConflictResult {
+    let mut schedules = [Schedule::new(first), Schedule::new(second)];
+    let mut found = vec![];
+    let reason = loop {
+        if found.len() >= usize::from(limit) {
+            found.truncate(usize::from(limit));
+            break TerminationReason::Limit;
+        }
+        // The occurrences are compared in the order of their start, which gives the
+        // conflicts in the order of their start too.
+        let index = match (&schedules[0].next, &schedules[1].next) {
+            (Some(first), Some(second)) => usize::from(second.start < first.start),
+            (Some(_), None) => 0,
+            (None, Some(_)) => 1,
+            (None, None) => {
+                break schedules[0]
+                    .iter
+                    .stopped_early()
+                    .or_else(|| schedules[1].iter.stopped_early())
+                    .unwrap_or(TerminationReason::Finished);
+            }
+        };
+        let [first, second] = &mut schedules;
+        let (this, other) = if index == 0 {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        let Some(occurrence) = this.next else {
+            unreachable!("the schedule with the earliest next occurrence has one");
+        };
+        if occurrence.start >= *horizon {
+            break TerminationReason::Finished;
+        }
+        other.retire(&occurrence.start);
+        if other.is_done() {
+            break other
+                .iter
+                .stopped_early()
+                .unwrap_or(TerminationReason::Finished);
+        }
+        this.advance();
+
+        for active in &other.active {
+            if conflicts(&occurrence, active) {
+                let (first, second) = if index == 0 {
+                    (occurrence, *active)
+                } else {
+                    (*active, occurrence)
+                };
+                found.push(Conflict { first, second });
+            }
+        }
+        this.active.push(occurrence);
+
+        match other.next {
+            Some(next)
+                if other.active.is_empty()
+                    && occurrence.start < next.start
+                    && occurrence.end <= next.start =>
+            {
+                this.passed += 1;
+                if this.passed >= SKIP_AFTER {
+                    this.passed = 0;
+                    this.skip_to(&next.start);
+                }
+            }
+            _ => this.passed = 0,
+        }
+    };
+
+    ConflictResult {
+        conflicts: found,
+        limited: reason.is_limited(),
+        reason,
+    }
+}
@@ -1,11 +1,12 @@
 use std::collections::HashSet;
 
-use chrono::{Datelike, TimeZone, Timelike, Utc, Weekday};
+use chrono::{
+    Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday,
+};
 
-use crate::{Frequency, RRule, RRuleError, Tz};
+use crate::{Frequency, Limits, RRule, RRuleError, Tz};
 
 use super::{
-    checks,
     masks::MASKS,
     operation_errors::{checked_add_u32, checked_mul_u32},
     utils::is_leap_year,
@@ -15,7 +16,7 @@ const MINUTES_IN_A_DAY: u32 = 60 * 24;
 const SECONDS_IN_A_DAY: u32 = 60 * 60 * 24;
 
 /// A simple date time type used during iteration.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub(crate) struct DateTimeIter {
     pub year: i32,
     pub month: u32,
@@ -33,7 +34,14 @@ impl DateTimeIter {
     /// is higher than daily (e.g. hourly) where this function might return a date with the
     /// same day, but the iterator already knows that the current day can't
     /// be part of the result.
-    pub fn increment(&mut self, rrule: &RRule, increment_day: bool) -> Result<(), RRuleError> {
+    ///
+    /// Returns an error if the year leaves the year range of `limits`.
+    pub fn increment(
+        &mut self,
+        rrule: &RRule,
+        limits: &Limits,
+        increment_day: bool,
+    ) -> Result<(), RRuleError> {
         let RRule {
             interval,
             week_start,
@@ -43,23 +51,25 @@ impl DateTimeIter {
             ..
         } = rrule;
         match rrule.freq {
-            Frequency::Yearly => self.increment_yearly(*interval),
-            Frequency::Monthly => self.increment_monthly(*interval),
-            Frequency::Weekly => self.increment_weekly(*interval, *week_start),
-            Frequency::Daily => self.increment_daily(*interval),
-            Frequency::Hourly => self.increment_hourly(*interval, by_hour, increment_day),
+            Frequency::Yearly => self.increment_yearly(*interval)?,
+            Frequency::Monthly => self.increment_monthly(*interval)?,
+            Frequency::Weekly => self.increment_weekly(*interval, *week_start)?,
+            Frequency::Daily => self.increment_daily(*interval)?,
+            Frequency::Hourly => self.increment_hourly(*interval, by_hour, increment_day)?,
             Frequency::Minutely => {
-                self.increment_minutely(*interval, by_hour, by_minute, increment_day)
+                self.increment_minutely(*interval, by_hour, by_minute, increment_day)?;
             }
             Frequency::Secondly => {
-                self.increment_secondly(*interval, by_hour, by_minute, by_second, increment_day)
+                self.increment_secondly(*interval, by_hour, by_minute, by_second, increment_day)?;
             }
         }
+        // The year only grows, so it is enough to check it after incrementing.
+        limits.check_year(self.year)?;
+        Ok(())
     }
 
     fn increment_yearly(&mut self, interval: u16) -> Result<(), RRuleError> {
         self.year += i32::from(interval);
-        checks::check_year_range(self.year)?;
         self.fix_day()
     }
 
@@ -77,7 +87,6 @@ impl DateTimeIter {
                 year_div -= 1;
             }
             self.year += i32::from(year_div);
-            checks::check_year_range(self.year)?;
         }
         Ok(())
     }
@@ -303,6 +312,171 @@ impl DateTimeIter {
 
         Ok(())
     }
+
+    /// Returns `true` if [`DateTimeIter::increment`] always moves by a multiple of the
+    /// interval of the [`RRule`], so that its periods can be found with
+    /// [`DateTimeIter::skip_to`] and [`DateTimeIter::decrement`].
+    ///
+    /// Minutely rules with `BYHOUR`, and secondly rules with `BYHOUR` or `BYMINUTE`, move
+    /// on by whole hours or minutes until these match. This stays on the grid of the
+    /// interval only if the interval divides a minute or an hour and no days are filtered,
+    /// as filtered days are jumped over differently.
+    pub fn can_skip(rrule: &RRule) -> bool {
+        let filters_days = !rrule.by_month.is_empty()
+            || !rrule.by_week_no.is_empty()
+            || !rrule.by_year_day.is_empty()
+            || !rrule.by_month_day.is_empty()
+            || !rrule.by_n_month_day.is_empty()
+            || !rrule.by_weekday.is_empty()
+            || (cfg!(feature = "by-easter") && rrule.by_easter.is_some());
+        let steps_on_grid = 60u16.checked_rem(rrule.interval) == Some(0) && !filters_days;
+        match rrule.freq {
+            Frequency::Minutely => rrule.by_hour.is_empty() || steps_on_grid,
+            Frequency::Secondly => {
+                (rrule.by_hour.is_empty() && rrule.by_minute.is_empty()) || steps_on_grid
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if the [`RRule`] has the same periods when it starts at `target`
+    /// as when it starts at the datetime, so that restarting it at `target` keeps its
+    /// dates from `target` on, apart from its `COUNT`.
+    pub fn is_in_phase(&self, rrule: &RRule, target: &NaiveDateTime) -> bool {
+        Self::can_skip(rrule)
+            && self
+                .periods_until(rrule, target)
+                .is_ok_and(|periods| periods.checked_rem(i64::from(rrule.interval)) == Some(0))
+    }
+
+    /// Moves the datetime forward by a multiple of the interval, to the last period
+    /// of the [`RRule`] which starts at or before `target`. The periods in between
+    /// are skipped without visiting them.
+    ///
+    /// Nothing changes if `target` is in the current period or before it.
+    pub fn skip_to(
+        &mut self,
+        rrule: &RRule,
+        limits: &Limits,
+        target: &NaiveDateTime,
+    ) -> Result<(), RRuleError> {
+        let interval = i64::from(rrule.interval);
+        if interval == 0 {
+            return Ok(());
+        }
+
+        let periods = self.periods_until(rrule, target)? / interval;
+
+        if periods > 0 {
+            self.shift(rrule, limits, periods)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of periods of the frequency of the [`RRule`] from the period of
+    /// the datetime to the period of `target`, ignoring the interval.
+    pub fn periods_until(&self, rrule: &RRule, target: &NaiveDateTime) -> Result<i64, RRuleError> {
+        let periods = match rrule.freq {
+            Frequency::Yearly => i64::from(target.year()) - i64::from(self.year),
+            Frequency::Monthly => {
+                (i64::from(target.year()) - i64::from(self.year)) * 12 + i64::from(target.month())
+                    - i64::from(self.month)
+            }
+            Frequency::Weekly => {
+                let week_start = Self::week_start(self.date()?, rrule.week_start);
+                (Self::week_start(target.date(), rrule.week_start) - week_start).num_days() / 7
+            }
+            Frequency::Daily => (target.date() - self.date()?).num_days(),
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let unit = Self::period_seconds(rrule.freq);
+                // Periods start at the beginning of their hour, minute or second.
+                let start_of_period = |dt: NaiveDateTime| dt.and_utc().timestamp().div_euclid(unit);
+                start_of_period(*target) - start_of_period(self.naive()?)
+            }
+        };
+        Ok(periods)
+    }
+
+    /// Moves the datetime back to the previous period of the [`RRule`].
+    ///
+    /// Unlike [`DateTimeIter::increment`], this doesn't skip periods which can't
+    /// contain any dates because of `BYHOUR`, `BYMINUTE` or `BYSECOND`.
+    pub fn decrement(&mut self, rrule: &RRule, limits: &Limits) -> Result<(), RRuleError> {
+        self.shift(rrule, limits, -1)
+    }
+
+    /// Moves the datetime by `periods` times the interval of the [`RRule`].
+    fn shift(&mut self, rrule: &RRule, limits: &Limits, periods: i64) -> Result<(), RRuleError> {
+        let delta = periods * i64::from(rrule.interval);
+        match rrule.freq {
+            Frequency::Yearly => {
+                self.year = Self::checked_year(i64::from(self.year) + delta, limits)?;
+                self.fix_day()?;
+            }
+            Frequency::Monthly => {
+                let total = i64::from(self.year) * 12 + i64::from(self.month) - 1 + delta;
+                self.year = Self::checked_year(total.div_euclid(12), limits)?;
+                self.month =
+                    u32::try_from(total.rem_euclid(12) + 1).expect("range 1-12 is covered by u32");
+            }
+            Frequency::Weekly => {
+                let week_start = Self::week_start(self.date()?, rrule.week_start);
+                self.set_date(week_start + Duration::weeks(delta), limits)?;
+            }
+            Frequency::Daily => self.set_date(self.date()? + Duration::days(delta), limits)?,
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let unit = Self::period_seconds(rrule.freq);
+                let next = self.naive()? + Duration::seconds(delta * unit);
+                self.set_date(next.date(), limits)?;
+                self.hour = next.hour();
+                self.minute = next.minute();
+                self.second = next.second();
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the first day of the week of `date`.
+    fn week_start(date: NaiveDate, week_start: Weekday) -> NaiveDate {
+        let days =
+            (date.weekday().num_days_from_monday() + 7 - week_start.num_days_from_monday()) % 7;
+        date - Duration::days(i64::from(days))
+    }
+
+    /// Returns the number of seconds in a period of a frequency higher than daily.
+    fn period_seconds(freq: Frequency) -> i64 {
+        match freq {
+            Frequency::Hourly => 60 * 60,
+            Frequency::Minutely => 60,
+            _ => 1,
+        }
+    }
+
+    fn naive(&self) -> Result<NaiveDateTime, RRuleError> {
+        let time = NaiveTime::from_hms_opt(self.hour, self.minute, self.second)
+            .ok_or_else(|| RRuleError::new_iter_err("invalid counter time"))?;
+        Ok(self.date()?.and_time(time))
+    }
+
+    fn date(&self) -> Result<NaiveDate, RRuleError> {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+            .ok_or_else(|| RRuleError::new_iter_err("invalid counter date"))
+    }
+
+    fn set_date(&mut self, date: NaiveDate, limits: &Limits) -> Result<(), RRuleError> {
+        limits.check_year(date.year())?;
+        self.year = date.year();
+        self.month = date.month();
+        self.day = date.day();
+        Ok(())
+    }
+
+    fn checked_year(year: i64, limits: &Limits) -> Result<i32, RRuleError> {
+        let year = i32::try_from(year)
+            .map_err(|_| RRuleError::new_iter_err("please decrease `INTERVAL`"))?;
+        limits.check_year(year)?;
+        Ok(year)
+    }
 }
 
 impl From<&chrono::DateTime<Tz>> for DateTimeIter {
@@ -367,7 +541,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, false);
+            let res = counter_date.increment(&rrule, &Limits::default(), false);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -411,7 +585,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, false);
+            let res = counter_date.increment(&rrule, &Limits::default(), false);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -455,7 +629,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, false);
+            let res = counter_date.increment(&rrule, &Limits::default(), false);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -494,7 +668,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, false);
+            let res = counter_date.increment(&rrule, &Limits::default(), false);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -550,7 +724,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, false);
+            let res = counter_date.increment(&rrule, &Limits::default(), false);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -599,7 +773,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, true);
+            let res = counter_date.increment(&rrule, &Limits::default(), true);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -638,7 +812,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, false);
+            let res = counter_date.increment(&rrule, &Limits::default(), false);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -672,7 +846,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, true);
+            let res = counter_date.increment(&rrule, &Limits::default(), true);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -711,7 +885,7 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, false);
+            let res = counter_date.increment(&rrule, &Limits::default(), false);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
@@ -745,7 +919,80 @@ mod tests {
             .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
             .unwrap();
 
-            let res = counter_date.increment(&rrule, true);
+            let res = counter_date.increment(&rrule, &Limits::default(), true);
+            assert!(res.is_ok());
+            assert_eq!(counter_date, expected_output);
+        }
+    }
+
+    #[test]
+    fn skips_counter_date_to_period_of_target() {
+        let target = UTC
+            .with_ymd_and_hms(2000, 3, 15, 10, 30, 0)
+            .unwrap()
+            .naive_utc();
+        let tests = [
+            (
+                Frequency::Yearly,
+                3,
+                ymd_hms(1997, 1, 1, 9, 0, 0),
+                ymd_hms(2000, 1, 1, 9, 0, 0),
+            ),
+            (
+                Frequency::Yearly,
+                2,
+                ymd_hms(1997, 1, 1, 9, 0, 0),
+                ymd_hms(1999, 1, 1, 9, 0, 0),
+            ),
+            (
+                Frequency::Monthly,
+                5,
+                ymd_hms(1997, 1, 31, 9, 0, 0),
+                ymd_hms(1999, 12, 31, 9, 0, 0),
+            ),
+            // 2000-03-15 is a Wednesday, and its week starts on Monday the 13th.
+            (
+                Frequency::Weekly,
+                1,
+                ymd_hms(1997, 1, 2, 9, 0, 0),
+                ymd_hms(2000, 3, 13, 9, 0, 0),
+            ),
+            (
+                Frequency::Daily,
+                7,
+                ymd_hms(1997, 1, 1, 9, 0, 0),
+                ymd_hms(2000, 3, 15, 9, 0, 0),
+            ),
+            (
+                Frequency::Hourly,
+                5,
+                ymd_hms(2000, 3, 15, 0, 0, 0),
+                ymd_hms(2000, 3, 15, 10, 0, 0),
+            ),
+            (
+                Frequency::Minutely,
+                1,
+                ymd_hms(2000, 3, 15, 0, 0, 20),
+                ymd_hms(2000, 3, 15, 10, 30, 20),
+            ),
+            // The target is before the counter date.
+            (
+                Frequency::Daily,
+                1,
+                ymd_hms(2001, 1, 1, 9, 0, 0),
+                ymd_hms(2001, 1, 1, 9, 0, 0),
+            ),
+        ];
+        for (freq, interval, mut counter_date, expected_output) in tests {
+            let rrule = RRule {
+                interval,
+                freq,
+                ..Default::default()
+            }
+            .validate(UTC.with_ymd_and_hms(1997, 1, 1, 1, 1, 1).unwrap())
+            .unwrap();
+
+            let res = counter_date.skip_to(&rrule, &Limits::default(), &target);
             assert!(res.is_ok());
             assert_eq!(counter_date, expected_output);
         }
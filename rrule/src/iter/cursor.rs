@@ -0,0 +1,380 @@
+use super::counter_date::DateTimeIter;
+use super::rrule_iter::WasLimited;
+use super::{Budget, RRuleIter, TerminationReason};
+use crate::{Limits, ParseError, RRule, RRuleError, Tz};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Timelike};
+#[cfg(feature = "serde")]
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Version of the format of [`RRuleSetCursor`], which is the first part of its string.
+const CURSOR_VERSION: &str = "1";
+
+/// Hash of the rules and dates of a set, to check that a cursor is used with the set it
+/// was created for.
+///
+/// This is FNV-1a, which, unlike the hashers of the standard library, doesn't change
+/// between versions of Rust.
+pub(crate) struct Fingerprint(u64);
+
+impl Fingerprint {
+    pub(crate) fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    pub(crate) fn write_i64(&mut self, value: i64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub(crate) fn write_str(&mut self, value: &str) {
+        self.write_i64(value.len() as i64);
+        self.write(value.as_bytes());
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The state of an [`crate::RRuleSetIter`], to resume the iteration exactly where it stopped.
+///
+/// A cursor is created with [`crate::RRuleSetIter::cursor`] and the iteration is resumed with
+/// [`crate::RRuleSet::iter_from_cursor`], which has to be called on the same [`crate::RRuleSet`].
+/// The cursor keeps a hash of the rules and remaining dates of the set to check this.
+/// The remaining `COUNT` of each rule is part of the state.
+///
+/// The cursor is opaque. It can be converted to and from a string, and serialized with
+/// the `serde` feature, but the format of the string is not part of the public API.
+///
+/// # Usage
+///
+/// ```
+/// use rrule::{RRuleSet, RRuleSetCursor};
+///
+/// let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=5".parse().unwrap();
+///
+/// let mut iter = rrule_set.into_iter();
+/// let first_page = iter.by_ref().take(2).collect::<Vec<_>>();
+/// let cursor = iter.cursor().to_string();
+///
+/// // Later, for example in another request.
+/// let cursor: RRuleSetCursor = cursor.parse().unwrap();
+/// let second_page = rrule_set.iter_from_cursor(&cursor).unwrap().collect::<Vec<_>>();
+/// assert_eq!(first_page.len() + second_page.len(), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(DeserializeFromStr, SerializeDisplay))]
+pub struct RRuleSetCursor {
+    /// See [`Fingerprint`].
+    pub(crate) fingerprint: u64,
+    pub(crate) rrule_iters: Vec<RRuleIterState>,
+    pub(crate) exrule_iters: Vec<RRuleIterState>,
+    /// Timestamps of the dates generated by the exrules, with the index of their exrule.
+    pub(crate) exrule_dates: Vec<(i64, usize)>,
+    /// Number of rdates which haven't been returned yet.
+    pub(crate) rdates_remaining: usize,
+    pub(crate) was_limited: bool,
+}
+
+/// The state of an [`RRuleIter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RRuleIterState {
+    counter_date: DateTimeIter,
+    count: Option<u32>,
+    finished: bool,
+    was_limited: bool,
+    /// Timestamp of the date which was generated, but not yet returned by the set iterator.
+    queued: Option<i64>,
+    /// Timestamps of the dates in the buffer.
+    buffer: Vec<i64>,
+}
+
+impl RRuleIterState {
+    pub(crate) fn new(rrule_iter: &RRuleIter, queued: Option<&DateTime<Tz>>) -> Self {
+        Self {
+            counter_date: rrule_iter.counter_date.clone(),
+            count: rrule_iter.count,
+            finished: rrule_iter.finished,
+            was_limited: rrule_iter.was_limited(),
+            queued: queued.map(DateTime::timestamp),
+            buffer: rrule_iter.buffer.iter().map(DateTime::timestamp).collect(),
+        }
+    }
+
+    /// Creates the iterator of `rrule` in this state, together with its queued date.
+    pub(crate) fn restore(
+        &self,
+        rrule: &RRule,
+        dt_start: &DateTime<Tz>,
+        limited: bool,
+        limits: Limits,
+        budget: Budget,
+    ) -> Result<(RRuleIter, Option<DateTime<Tz>>), RRuleError> {
+        let tz = dt_start.timezone();
+        let datetime = |timestamp: i64| {
+            tz.timestamp_opt(timestamp, 0)
+                .single()
+                .ok_or_else(|| RRuleError::new_iter_err("Invalid timestamp in cursor."))
+        };
+
+        let mut rrule_iter = RRuleIter::new(rrule, dt_start, limited, limits, budget);
+        rrule_iter.set_period(self.counter_date.clone());
+        rrule_iter.count = self.count;
+        rrule_iter.finished = self.finished;
+        rrule_iter.stopped_early = self.was_limited.then_some(TerminationReason::MaxIterations);
+        rrule_iter.buffer = self
+            .buffer
+            .iter()
+            .map(|timestamp| datetime(*timestamp))
+            .collect::<Result<VecDeque<_>, _>>()?;
+        let queued = self.queued.map(datetime).transpose()?;
+
+        Ok((rrule_iter, queued))
+    }
+}
+
+/// Joins the values with `separator`.
+fn join<T: ToString>(values: &[T], separator: &str) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Splits a list joined with [`join`], where an empty string is an empty list.
+fn split(s: &str, separator: char) -> impl Iterator<Item = &str> {
+    s.split(separator).filter(|value| !value.is_empty())
+}
+
+fn optional_to_string<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".into(), |value| value.to_string())
+}
+
+/// Parses a value written with [`optional_to_string`].
+fn optional(value: &str) -> Option<&str> {
+    Some(value).filter(|value| *value != "-")
+}
+
+impl Display for RRuleIterState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let DateTimeIter {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        } = &self.counter_date;
+        write!(
+            f,
+            "{year}/{month}/{day}/{hour}/{minute}/{second}/{}/{}/{}/{}/{}",
+            optional_to_string(self.count),
+            u8::from(self.finished),
+            u8::from(self.was_limited),
+            optional_to_string(self.queued),
+            join(&self.buffer, ",")
+        )
+    }
+}
+
+impl FromStr for RRuleIterState {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseError::InvalidCursor(s.into());
+        let parts = s.split('/').collect::<Vec<_>>();
+        let [year, month, day, hour, minute, second, count, finished, was_limited, queued, buffer] =
+            parts[..]
+        else {
+            return Err(err());
+        };
+
+        let number = |value: &str| value.parse::<u32>().map_err(|_| err());
+        let flag = |value: &str| match value {
+            "0" => Ok(false),
+            "1" => Ok(true),
+            _ => Err(err()),
+        };
+
+        // The day can be past the end of the month, like the 31st for a monthly rule
+        // starting on one, so only the year and month make a date.
+        let date = NaiveDate::from_ymd_opt(year.parse().map_err(|_| err())?, number(month)?, 1)
+            .ok_or_else(err)?;
+        let day = number(day)?;
+        if !(1..=31).contains(&day) {
+            return Err(err());
+        }
+        let time = NaiveTime::from_hms_opt(number(hour)?, number(minute)?, number(second)?)
+            .ok_or_else(err)?;
+
+        Ok(Self {
+            counter_date: DateTimeIter {
+                year: date.year(),
+                month: date.month(),
+                day,
+                hour: time.hour(),
+                minute: time.minute(),
+                second: time.second(),
+            },
+            count: optional(count).map(number).transpose()?,
+            finished: flag(finished)?,
+            was_limited: flag(was_limited)?,
+            queued: optional(queued)
+                .map(|queued| queued.parse().map_err(|_| err()))
+                .transpose()?,
+            buffer: split(buffer, ',')
+                .map(|timestamp| timestamp.parse().map_err(|_| err()))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Display for RRuleSetCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{CURSOR_VERSION};{:x};{};{};{};{};{}",
+            self.fingerprint,
+            u8::from(self.was_limited),
+            self.rdates_remaining,
+            join(
+                &self
+                    .exrule_dates
+                    .iter()
+                    .map(|(timestamp, i)| format!("{timestamp}:{i}"))
+                    .collect::<Vec<_>>(),
+                ","
+            ),
+            join(&self.rrule_iters, "|"),
+            join(&self.exrule_iters, "|"),
+        )
+    }
+}
+
+impl FromStr for RRuleSetCursor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseError::InvalidCursor(s.into());
+        let parts = s.split(';').collect::<Vec<_>>();
+        let [CURSOR_VERSION, fingerprint, was_limited, rdates_remaining, exrule_dates, rrule_iters, exrule_iters] =
+            parts[..]
+        else {
+            return Err(err());
+        };
+
+        Ok(Self {
+            fingerprint: u64::from_str_radix(fingerprint, 16).map_err(|_| err())?,
+            rrule_iters: split(rrule_iters, '|')
+                .map(|state| state.parse().map_err(|_| err()))
+                .collect::<Result<_, _>>()?,
+            exrule_iters: split(exrule_iters, '|')
+                .map(|state| state.parse().map_err(|_| err()))
+                .collect::<Result<_, _>>()?,
+            exrule_dates: split(exrule_dates, ',')
+                .map(|date| {
+                    let (timestamp, i) = date.split_once(':').ok_or_else(err)?;
+                    Ok((
+                        timestamp.parse().map_err(|_| err())?,
+                        i.parse().map_err(|_| err())?,
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+            rdates_remaining: rdates_remaining.parse().map_err(|_| err())?,
+            was_limited: match was_limited {
+                "0" => false,
+                "1" => true,
+                _ => return Err(err()),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_string_roundtrip() {
+        let cursor = RRuleSetCursor {
+            fingerprint: 0xab12,
+            rrule_iters: vec![
+                RRuleIterState {
+                    counter_date: DateTimeIter {
+                        year: -20,
+                        month: 2,
+                        day: 31,
+                        hour: 0,
+                        minute: 1,
+                        second: 2,
+                    },
+                    count: Some(3),
+                    finished: false,
+                    was_limited: true,
+                    queued: Some(-100),
+                    buffer: vec![1, 2],
+                },
+                RRuleIterState {
+                    counter_date: DateTimeIter {
+                        year: 2020,
+                        month: 1,
+                        day: 1,
+                        hour: 9,
+                        minute: 0,
+                        second: 0,
+                    },
+                    count: None,
+                    finished: true,
+                    was_limited: false,
+                    queued: None,
+                    buffer: vec![],
+                },
+            ],
+            exrule_iters: vec![],
+            exrule_dates: vec![(5, 0)],
+            rdates_remaining: 2,
+            was_limited: false,
+        };
+
+        let s = cursor.to_string();
+        assert_eq!(
+            s,
+            "1;ab12;0;2;5:0;-20/2/31/0/1/2/3/0/1/-100/1,2|2020/1/1/9/0/0/-/1/0/-/;"
+        );
+        assert_eq!(RRuleSetCursor::from_str(&s), Ok(cursor));
+    }
+
+    #[test]
+    fn rejects_invalid_cursors() {
+        for input in [
+            "",
+            "1;0;0;;;",
+            "2;0;0;0;;;",
+            "1;x;0;0;;;",
+            "1;0;0;0;;2020/1/1;",
+            "1;0;0;0;5;;",
+            "1;0;2;0;;;",
+            "1;0;0;x;;;",
+            "1;0;0;0;;2012/13/2/9/30/0/-/0/0/-/;",
+            "1;0;0;0;;2012/2/45/9/30/0/-/0/0/-/;",
+            "1;0;0;0;;999999/2/2/9/30/0/-/0/0/-/;",
+            "1;0;0;0;;2012/2/2/24/30/0/-/0/0/-/;",
+            "1;0;0;0;;2012/2/2/9/60/0/-/0/0/-/;",
+            "1;0;0;0;;2012/2/2/9/30/60/-/0/0/-/;",
+        ] {
+            assert_eq!(
+                RRuleSetCursor::from_str(input),
+                Err(ParseError::InvalidCursor(input.into()))
+            );
+        }
+    }
+}
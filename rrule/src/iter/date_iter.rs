@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+
+use super::RRuleSetIter;
+use crate::Tz;
+
+#[derive(Debug, Clone)]
+/// Iterator over the dates of the recurrences in an [`crate::RRuleSet`].
+///
+/// Created with [`crate::RRuleSet::iter_dates`].
+pub struct RRuleSetDateIter {
+    iter: RRuleSetIter,
+    tz: Tz,
+}
+
+impl RRuleSetDateIter {
+    pub(crate) fn new(iter: RRuleSetIter, tz: Tz) -> Self {
+        Self { iter, tz }
+    }
+}
+
+impl Iterator for RRuleSetDateIter {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|date| date.with_timezone(&self.tz).date_naive())
+    }
+}
@@ -0,0 +1,187 @@
+use super::counter_date::DateTimeIter;
+use super::filters::filter_results;
+use super::rruleset_iter::{exdate_timestamps, sorted_rdates};
+use super::utils::days_since_unix_epoch;
+use super::{Budget, IterInfo, RRuleIter};
+use crate::{
+    ExclusionSource, Explanation, Limits, NWeekday, RRule, RRuleCheck, RRuleExplanation, RRulePart,
+    RRuleSet, Tz,
+};
+use chrono::{DateTime, NaiveTime, Timelike};
+
+/// Explains why `date` is or isn't a recurrence of `rrule_set`.
+pub(crate) fn explain(rrule_set: &RRuleSet, date: &DateTime<Tz>) -> Explanation {
+    let explain_rrules = |rrules: &[RRule]| {
+        rrules
+            .iter()
+            .map(|rrule| {
+                explain_rrule(
+                    rrule,
+                    &rrule_set.dt_start,
+                    date,
+                    rrule_set.limited,
+                    rrule_set.limits,
+                    &rrule_set.budget,
+                )
+            })
+            .collect()
+    };
+
+    Explanation {
+        date: *date,
+        rrules: explain_rrules(&rrule_set.rrule),
+        rdate: sorted_rdates(rrule_set)
+            .into_iter()
+            .find(|(rdate, _, _)| rdate == date)
+            .map(|(_, _, source)| source),
+        exrules: explain_rrules(&rrule_set.exrule),
+        exdate: exdate_timestamps(rrule_set)
+            .get(&date.timestamp())
+            .map(|source| match source {
+                ExclusionSource::ExDate(i) | ExclusionSource::ExRule(i) => *i,
+            }),
+    }
+}
+
+/// Checks each part of `rrule` which is set against `date`.
+fn explain_rrule(
+    rrule: &RRule,
+    dt_start: &DateTime<Tz>,
+    date: &DateTime<Tz>,
+    limited: bool,
+    limits: Limits,
+    budget: &Budget,
+) -> RRuleExplanation {
+    let local = date.with_timezone(&dt_start.timezone());
+    let mut checks = vec![RRuleCheck {
+        part: RRulePart::DtStart,
+        passed: date >= dt_start,
+    }];
+    let mut check = |part, passed| checks.push(RRuleCheck { part, passed });
+
+    if let Some(until) = &rrule.until {
+        check(RRulePart::Until, date <= until);
+    }
+
+    // Rules whose periods don't follow the interval have to be walked from the start.
+    let can_skip = DateTimeIter::can_skip(rrule);
+    let is_walked_to = || {
+        let mut rrule_iter = RRuleIter::new(rrule, dt_start, limited, limits, budget.clone());
+        rrule_iter.count = None;
+        rrule_iter
+            .take_while(|rrule_date| rrule_date <= date)
+            .any(|rrule_date| rrule_date == *date)
+    };
+
+    let start_period = DateTimeIter::from(dt_start);
+    let periods = start_period.periods_until(rrule, &local.naive_local());
+    if rrule.interval != 1 {
+        let interval = i64::from(rrule.interval);
+        check(
+            RRulePart::Interval,
+            if can_skip {
+                matches!(periods, Ok(periods) if interval > 0 && periods.rem_euclid(interval) == 0)
+            } else {
+                is_walked_to()
+            },
+        );
+    }
+
+    // The masks are built for the year of the date, like for the period which contains it.
+    let mut ii = IterInfo::new(rrule, dt_start);
+    ii.rebuild(&DateTimeIter::from(&local));
+    let day = days_since_unix_epoch(&local.date_naive().and_time(NaiveTime::MIN).and_utc())
+        - ii.year_ordinal();
+    if let Ok(day) = usize::try_from(day) {
+        for (part, is_filtered) in filter_results(&ii, day) {
+            if is_part_set(rrule, part) {
+                check(part, !is_filtered);
+            }
+        }
+    }
+
+    if !rrule.by_hour.is_empty() {
+        check(
+            RRulePart::ByHour,
+            rrule
+                .by_hour
+                .iter()
+                .any(|hour| u32::from(*hour) == local.hour()),
+        );
+    }
+    if !rrule.by_minute.is_empty() {
+        check(
+            RRulePart::ByMinute,
+            rrule
+                .by_minute
+                .iter()
+                .any(|minute| u32::from(*minute) == local.minute()),
+        );
+    }
+    if !rrule.by_second.is_empty() {
+        check(
+            RRulePart::BySecond,
+            rrule
+                .by_second
+                .iter()
+                .any(|second| u32::from(*second) == local.second()),
+        );
+    }
+
+    let mut explanation = RRuleExplanation { checks };
+    if !explanation.matches() {
+        return explanation;
+    }
+
+    if !rrule.by_set_pos.is_empty() {
+        let mut period = start_period;
+        let passed = if can_skip {
+            period.skip_to(rrule, &limits, &local.naive_local()).is_ok() && {
+                let mut rrule_iter =
+                    RRuleIter::new(rrule, dt_start, limited, limits, budget.clone());
+                rrule_iter.count = None;
+                rrule_iter.set_period(period);
+                rrule_iter.generate_period();
+                rrule_iter.buffer.contains(date)
+            }
+        } else {
+            is_walked_to()
+        };
+        explanation.checks.push(RRuleCheck {
+            part: RRulePart::BySetPos,
+            passed,
+        });
+    }
+
+    if rrule.count.is_some() && explanation.matches() {
+        let passed = RRuleIter::new(rrule, dt_start, limited, limits, budget.clone())
+            .take_while(|rrule_date| rrule_date <= date)
+            .any(|rrule_date| rrule_date == *date);
+        explanation.checks.push(RRuleCheck {
+            part: RRulePart::Count,
+            passed,
+        });
+    }
+
+    explanation
+}
+
+/// Returns `true` if the part of the rule checked by a filter is set.
+fn is_part_set(rrule: &RRule, part: RRulePart) -> bool {
+    match part {
+        RRulePart::ByMonth => !rrule.by_month.is_empty(),
+        RRulePart::ByWeekNo => !rrule.by_week_no.is_empty(),
+        RRulePart::ByWeekday => rrule
+            .by_weekday
+            .iter()
+            .any(|weekday| matches!(weekday, NWeekday::Every(_))),
+        RRulePart::ByNthWeekday => rrule
+            .by_weekday
+            .iter()
+            .any(|weekday| matches!(weekday, NWeekday::Nth(_, _))),
+        RRulePart::ByEaster => cfg!(feature = "by-easter") && rrule.by_easter.is_some(),
+        RRulePart::ByMonthDay => !rrule.by_month_day.is_empty() || !rrule.by_n_month_day.is_empty(),
+        RRulePart::ByYearDay => !rrule.by_year_day.is_empty(),
+        _ => false,
+    }
+}
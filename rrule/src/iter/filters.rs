@@ -1,24 +1,35 @@
-use crate::{NWeekday, RRule};
+use crate::{NWeekday, RRule, RRulePart};
 
 use super::iterinfo::IterInfo;
 
 type RRuleFilter = &'static dyn Fn(&IterInfo, usize, &RRule) -> bool;
 
-const FILTERS: [RRuleFilter; 7] = [
-    &is_filtered_by_month,
-    &is_filtered_by_week_number,
-    &is_filtered_by_weekday,
-    &is_filtered_by_neg_weekday,
-    &is_filtered_by_easter,
-    &is_filtered_by_month_day,
-    &is_filtered_by_year_day,
+const FILTERS: [(RRulePart, RRuleFilter); 7] = [
+    (RRulePart::ByMonth, &is_filtered_by_month),
+    (RRulePart::ByWeekNo, &is_filtered_by_week_number),
+    (RRulePart::ByWeekday, &is_filtered_by_weekday),
+    (RRulePart::ByNthWeekday, &is_filtered_by_neg_weekday),
+    (RRulePart::ByEaster, &is_filtered_by_easter),
+    (RRulePart::ByMonthDay, &is_filtered_by_month_day),
+    (RRulePart::ByYearDay, &is_filtered_by_year_day),
 ];
 
 pub(crate) fn is_filtered(ii: &IterInfo, current_day: usize) -> bool {
     let rrule = ii.rrule();
     FILTERS
         .into_iter()
-        .any(|filter| filter(ii, current_day, rrule))
+        .any(|(_, filter)| filter(ii, current_day, rrule))
+}
+
+/// Returns each filter together with `true` if it filters out the day.
+pub(crate) fn filter_results(
+    ii: &IterInfo,
+    current_day: usize,
+) -> impl Iterator<Item = (RRulePart, bool)> + '_ {
+    let rrule = ii.rrule();
+    FILTERS
+        .into_iter()
+        .map(move |(part, filter)| (part, filter(ii, current_day, rrule)))
 }
 
 fn is_filtered_by_month(ii: &IterInfo, current_day: usize, rrule: &RRule) -> bool {
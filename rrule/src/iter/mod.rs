@@ -1,25 +1,42 @@
 #![allow(clippy::module_name_repetitions)]
 
-mod checks;
+mod budget;
+mod conflict;
 mod counter_date;
+mod cursor;
+mod date_iter;
 mod easter;
+mod explain;
 pub(crate) mod filters;
 pub(crate) mod iterinfo;
 mod masks;
 mod monthinfo;
+mod occurrence_iter;
 mod operation_errors;
 mod pos_list;
 pub(crate) mod rrule_iter;
+mod rrule_rev_iter;
 mod rruleset_iter;
+mod rruleset_rev_iter;
+mod set_operation;
+mod sourced_iter;
+mod termination;
 mod utils;
 mod yearinfo;
 
+pub(crate) use budget::Budget;
+pub use budget::CancellationToken;
+pub(crate) use conflict::find_conflicts;
+pub(crate) use counter_date::DateTimeIter;
+pub use cursor::RRuleSetCursor;
+pub use date_iter::RRuleSetDateIter;
+pub(crate) use explain::explain;
 use iterinfo::IterInfo;
+pub use occurrence_iter::OccurrenceIter;
 use pos_list::build_pos_list;
 pub(crate) use rrule_iter::RRuleIter;
 pub use rruleset_iter::RRuleSetIter;
-
-/// Prevent loops when searching for the next event in the iterator.
-/// If after X number of iterations it still has not found an event,
-/// we can assume it will not find an event.
-static MAX_ITER_LOOP: u32 = 100_000;
+pub use rruleset_rev_iter::RRuleSetRevIter;
+pub use set_operation::{SetOperationIter, SetOperations};
+pub use sourced_iter::SourcedIter;
+pub use termination::TerminationReason;
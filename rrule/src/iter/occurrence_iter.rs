@@ -0,0 +1,50 @@
+use super::{rrule_iter::WasLimited, RRuleSetIter, TerminationReason};
+use crate::core::EventLength;
+use crate::Occurrence;
+
+#[derive(Debug, Clone)]
+/// Iterator over the [`Occurrence`]s of an [`crate::RRuleSet`].
+///
+/// Created with [`crate::RRuleSet::occurrences`].
+pub struct OccurrenceIter {
+    iter: RRuleSetIter,
+    event_length: EventLength,
+    /// If the iteration stopped at an occurrence whose end can't be represented.
+    out_of_range: bool,
+}
+
+impl OccurrenceIter {
+    pub(crate) fn new(iter: RRuleSetIter, event_length: EventLength) -> Self {
+        Self {
+            iter,
+            event_length,
+            out_of_range: false,
+        }
+    }
+}
+
+impl Iterator for OccurrenceIter {
+    type Item = Occurrence;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.out_of_range {
+            return None;
+        }
+        let (start, end, source) = self.iter.next_sourced()?;
+        let Some(end) = end.or_else(|| self.event_length.end(&start)) else {
+            self.out_of_range = true;
+            return None;
+        };
+        Some(Occurrence { start, end, source })
+    }
+}
+
+impl WasLimited for OccurrenceIter {
+    fn stopped_early(&self) -> Option<TerminationReason> {
+        if self.out_of_range {
+            Some(TerminationReason::OutOfRange)
+        } else {
+            self.iter.stopped_early()
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use super::counter_date::DateTimeIter;
 use super::utils::add_time_to_date;
-use super::{build_pos_list, utils::date_from_ordinal, IterInfo, MAX_ITER_LOOP};
+use super::{build_pos_list, utils::date_from_ordinal, Budget, IterInfo, TerminationReason};
 use crate::core::{get_hour, get_minute, get_second};
-use crate::{Frequency, RRule, Tz};
-use chrono::NaiveTime;
+use crate::{Frequency, Limits, RRule, Tz};
+use chrono::{Duration, NaiveDateTime, NaiveTime, Offset};
 use std::collections::VecDeque;
 
 #[derive(Debug, Clone)]
@@ -23,12 +23,22 @@ pub(crate) struct RRuleIter {
     pub(crate) count: Option<u32>,
     /// If the iterator should be using iterator limits.
     pub(crate) limited: bool,
-    /// If the iterator has been stopped by the iterator limits.
-    pub(crate) was_limited: bool,
+    /// The year range and the number of iterations the iterator is limited to.
+    pub(crate) limits: Limits,
+    /// The deadline and cancellation token of the iteration.
+    pub(crate) budget: Budget,
+    /// Why the iterator has been stopped before generating all the dates, if it has.
+    pub(crate) stopped_early: Option<TerminationReason>,
 }
 
 impl RRuleIter {
-    pub(crate) fn new(rrule: &RRule, dt_start: &chrono::DateTime<Tz>, limited: bool) -> Self {
+    pub(crate) fn new(
+        rrule: &RRule,
+        dt_start: &chrono::DateTime<Tz>,
+        limited: bool,
+        limits: Limits,
+        budget: Budget,
+    ) -> Self {
         let ii = IterInfo::new(rrule, dt_start);
 
         let hour = get_hour(dt_start);
@@ -46,8 +56,85 @@ impl RRuleIter {
             finished: false,
             count,
             limited,
-            was_limited: false,
+            limits,
+            budget,
+            stopped_early: None,
+        }
+    }
+
+    /// Jumps to the period of the rule which contains `dt`, so that the periods before it
+    /// aren't generated. Dates in the same period before `dt` can still be returned.
+    ///
+    /// This has no effect when the rule has a `COUNT`, as all the dates since the start
+    /// have to be counted, when the periods of the rule can't be skipped (see
+    /// [`DateTimeIter::can_skip`]), or when dates have been generated already.
+    pub(crate) fn skip_to(&mut self, dt: &chrono::DateTime<Tz>) {
+        if self.count.is_some()
+            || !DateTimeIter::can_skip(self.ii.rrule())
+            || self.finished
+            || !self.buffer.is_empty()
+        {
+            return;
+        }
+        let mut counter_date = self.counter_date.clone();
+        if counter_date
+            .skip_to(self.ii.rrule(), &self.limits, &self.local_target(dt, false))
+            .is_err()
+        {
+            self.finished = true;
+            self.stopped_early = Some(TerminationReason::OutOfRange);
+            return;
+        }
+        self.set_period(counter_date);
+    }
+
+    /// Returns the local time in the timezone of the start date, from which the
+    /// periods containing dates around `dt` have to be looked at.
+    ///
+    /// Dates are generated in local time, so a change of the UTC offset, like at the
+    /// start or end of DST, can give a date after `dt` an earlier local time. The
+    /// local time of `dt` is moved by the change around it, forwards if `forwards`
+    /// is set and backwards otherwise.
+    pub(crate) fn local_target(&self, dt: &chrono::DateTime<Tz>, forwards: bool) -> NaiveDateTime {
+        let tz = self.dt_start.timezone();
+        // Around the limits of the supported dates, the day before or after is `dt` itself.
+        let offsets = [
+            dt.checked_sub_signed(Duration::days(1)).unwrap_or(*dt),
+            *dt,
+            dt.checked_add_signed(Duration::days(1)).unwrap_or(*dt),
+        ]
+        .map(|dt| dt.with_timezone(&tz).offset().fix().local_minus_utc());
+        let change = offsets.iter().max().unwrap_or(&0) - offsets.iter().min().unwrap_or(&0);
+        let change = if forwards { change } else { -change };
+
+        // The local time is shifted from UTC, and saturates at the limits of the supported dates.
+        let shift = Duration::seconds(i64::from(offsets[1] + change));
+        dt.naive_utc()
+            .checked_add_signed(shift)
+            .unwrap_or(if shift > Duration::zero() {
+                NaiveDateTime::MAX
+            } else {
+                NaiveDateTime::MIN
+            })
+    }
+
+    /// Sets the period which is generated next.
+    pub(crate) fn set_period(&mut self, counter_date: DateTimeIter) {
+        self.counter_date = counter_date;
+        let rrule = self.ii.rrule();
+        if matches!(
+            rrule.freq,
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly
+        ) {
+            let hour = u8::try_from(self.counter_date.hour).expect("range 0-23 is covered by u8");
+            let minute =
+                u8::try_from(self.counter_date.minute).expect("range 0-59 is covered by u8");
+            let second =
+                u8::try_from(self.counter_date.second).expect("range 0-59 is covered by u8");
+            // The time of the period might not match `BYHOUR`, `BYMINUTE` or `BYSECOND`.
+            self.timeset = self.ii.get_timeset(hour, minute, second);
         }
+        self.ii.rebuild(&self.counter_date);
     }
 
     /// Attempts to add a date to the result. Returns `true` if we should
@@ -100,65 +187,65 @@ impl RRuleIter {
         let mut loop_counter: u32 = 0;
         // Loop until there is at least 1 item in the buffer.
         while self.buffer.is_empty() {
+            if self.budget.is_exhausted() {
+                self.finished = true;
+                self.stopped_early = Some(TerminationReason::TimedOut);
+                log::warn!(
+                    "Stopped iterating, the deadline passed or the iteration was cancelled."
+                );
+                return true;
+            }
             // Prevent infinite loops
             if self.limited {
                 loop_counter += 1;
-                if loop_counter >= MAX_ITER_LOOP {
+                if loop_counter >= self.limits.max_iterations {
                     self.finished = true;
-                    self.was_limited = true;
+                    self.stopped_early = Some(TerminationReason::MaxIterations);
                     log::warn!(
                         "Reached max loop counter (`{}`). \
                     See 'validator limits' in docs for more info.",
-                        MAX_ITER_LOOP
+                        self.limits.max_iterations
                     );
                     return true;
                 }
             }
-            let rrule = self.ii.rrule();
+            if self.generate_period() {
+                return true;
+            }
+        }
 
-            let dayset = self.ii.get_dayset(
-                rrule.freq,
-                self.counter_date.year,
-                self.counter_date.month,
-                self.counter_date.day,
-            );
+        // Indicate that there might be more items on the next iteration.
+        false
+    }
 
-            let tz = self.dt_start.timezone();
+    /// Adds the dates of the current period to the buffer and moves to the next period.
+    /// Returns true if finished, no more items should/can be returned.
+    pub(crate) fn generate_period(&mut self) -> bool {
+        let rrule = self.ii.rrule();
 
-            if rrule.by_set_pos.is_empty() {
-                // Loop over `start..end`
-                for current_day in &dayset {
-                    let current_day = i64::try_from(*current_day).expect(
-                        "We control the dayset, and we know that it will always fit within an i64",
-                    );
-                    let year_ordinal = self.ii.year_ordinal();
-                    // Ordinal conversion uses UTC: if we apply local-TZ here, then
-                    // just below we'll end up double-applying.
-                    let date = date_from_ordinal(year_ordinal + current_day);
-                    for time in &self.timeset {
-                        let Some(dt) = add_time_to_date(tz, date, *time) else {
-                            continue;
-                        };
-                        if Self::try_add_datetime(
-                            dt,
-                            rrule,
-                            &mut self.count,
-                            &mut self.buffer,
-                            &self.dt_start,
-                        ) {
-                            return true;
-                        }
-                    }
-                }
-            } else {
-                let pos_list = build_pos_list(
-                    &rrule.by_set_pos,
-                    &dayset,
-                    &self.timeset,
-                    self.ii.year_ordinal(),
-                    self.dt_start.timezone(),
+        let dayset = self.ii.get_dayset(
+            rrule.freq,
+            self.counter_date.year,
+            self.counter_date.month,
+            self.counter_date.day,
+        );
+
+        let tz = self.dt_start.timezone();
+
+        if rrule.by_set_pos.is_empty() {
+            // Loop over `start..end`
+            for current_day in &dayset {
+                let current_day = i64::try_from(*current_day).expect(
+                    "We control the dayset, and we know that it will always fit within an i64",
                 );
-                for dt in pos_list {
+                let year_ordinal = self.ii.year_ordinal();
+                // Ordinal conversion uses UTC: if we apply local-TZ here, then
+                // just below we'll end up double-applying.
+                let date = date_from_ordinal(year_ordinal + current_day);
+                for time in &self.timeset {
+                    let Some(dt) = add_time_to_date(tz, date, *time) else {
+                        continue;
+                    };
                     if Self::try_add_datetime(
                         dt,
                         rrule,
@@ -170,30 +257,52 @@ impl RRuleIter {
                     }
                 }
             }
-
-            let increment_day = dayset.is_empty();
-            if self.counter_date.increment(rrule, increment_day).is_err() {
-                self.finished = true;
-                return true;
+        } else {
+            let pos_list = build_pos_list(
+                &rrule.by_set_pos,
+                &dayset,
+                &self.timeset,
+                self.ii.year_ordinal(),
+                self.dt_start.timezone(),
+            );
+            for dt in pos_list {
+                if Self::try_add_datetime(
+                    dt,
+                    rrule,
+                    &mut self.count,
+                    &mut self.buffer,
+                    &self.dt_start,
+                ) {
+                    return true;
+                }
             }
+        }
 
-            if matches!(
-                rrule.freq,
-                Frequency::Hourly | Frequency::Minutely | Frequency::Secondly
-            ) {
-                let hour =
-                    u8::try_from(self.counter_date.hour).expect("range 0-23 is covered by u8");
-                let minute =
-                    u8::try_from(self.counter_date.minute).expect("range 0-59 is covered by u8");
-                let second =
-                    u8::try_from(self.counter_date.second).expect("range 0-59 is covered by u8");
-                self.timeset = self.ii.get_timeset_unchecked(hour, minute, second);
-            }
+        let increment_day = dayset.is_empty();
+        if self
+            .counter_date
+            .increment(rrule, &self.limits, increment_day)
+            .is_err()
+        {
+            self.finished = true;
+            self.stopped_early = Some(TerminationReason::OutOfRange);
+            return true;
+        }
 
-            self.ii.rebuild(&self.counter_date);
+        if matches!(
+            rrule.freq,
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly
+        ) {
+            let hour = u8::try_from(self.counter_date.hour).expect("range 0-23 is covered by u8");
+            let minute =
+                u8::try_from(self.counter_date.minute).expect("range 0-59 is covered by u8");
+            let second =
+                u8::try_from(self.counter_date.second).expect("range 0-59 is covered by u8");
+            self.timeset = self.ii.get_timeset_unchecked(hour, minute, second);
         }
 
-        // Indicate that there might be more items on the next iteration.
+        self.ii.rebuild(&self.counter_date);
+
         false
     }
 }
@@ -220,11 +329,16 @@ impl Iterator for RRuleIter {
 }
 
 pub(crate) trait WasLimited {
-    fn was_limited(&self) -> bool;
+    /// Returns why the iteration stopped before generating all the dates, if it did.
+    fn stopped_early(&self) -> Option<TerminationReason>;
+
+    fn was_limited(&self) -> bool {
+        self.stopped_early() == Some(TerminationReason::MaxIterations)
+    }
 }
 
 impl WasLimited for RRuleIter {
-    fn was_limited(&self) -> bool {
-        self.was_limited
+    fn stopped_early(&self) -> Option<TerminationReason> {
+        self.stopped_early
     }
 }
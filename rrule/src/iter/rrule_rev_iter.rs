@@ -0,0 +1,154 @@
+use super::counter_date::DateTimeIter;
+use super::rrule_iter::WasLimited;
+use super::{Budget, RRuleIter, TerminationReason};
+use crate::{Limits, RRule, RRuleError, Tz};
+use chrono::DateTime;
+
+/// Iterator over the dates of an [`RRule`] in descending order.
+///
+/// The periods of the rule are walked backwards, starting with the period of the
+/// upper bound. Rules with a `COUNT` have to be counted from the start, and rules
+/// whose periods can't be skipped don't follow the interval, so their dates are
+/// generated forwards first.
+#[derive(Debug, Clone)]
+pub(crate) struct RRuleRevIter {
+    /// Iterator used to generate the dates of a single period.
+    period_iter: RRuleIter,
+    /// The period which is generated next.
+    period: DateTimeIter,
+    /// The period of the start date, which is the last one to generate.
+    first_period: DateTimeIter,
+    /// All the returned dates are at or before this date.
+    upper_bound: Option<DateTime<Tz>>,
+    /// Dates which have been generated but not yet returned, in ascending order.
+    buffer: Vec<DateTime<Tz>>,
+    finished: bool,
+    limited: bool,
+    limits: Limits,
+    stopped_early: Option<TerminationReason>,
+}
+
+impl RRuleRevIter {
+    /// Creates an iterator which returns the dates of the rule at or before `upper_bound`,
+    /// latest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rule has no `UNTIL` or `COUNT` and there is no `upper_bound`,
+    /// as it would be unclear where to start.
+    pub(crate) fn new(
+        rrule: &RRule,
+        dt_start: &DateTime<Tz>,
+        upper_bound: Option<DateTime<Tz>>,
+        limited: bool,
+        limits: Limits,
+        budget: Budget,
+    ) -> Result<Self, RRuleError> {
+        let upper_bound = match (rrule.until, upper_bound) {
+            (Some(until), Some(upper_bound)) => Some(until.min(upper_bound)),
+            (until, upper_bound) => until.or(upper_bound),
+        };
+        if upper_bound.is_none() && rrule.count.is_none() {
+            return Err(RRuleError::new_iter_err(
+                "Reverse iteration requires `UNTIL`, `COUNT` or a `before` date.",
+            ));
+        }
+
+        let mut period_iter = RRuleIter::new(rrule, dt_start, limited, limits, budget);
+        let first_period = period_iter.counter_date.clone();
+        let mut iter = Self {
+            period: first_period.clone(),
+            first_period,
+            upper_bound,
+            buffer: vec![],
+            finished: false,
+            limited,
+            limits,
+            stopped_early: None,
+            period_iter: period_iter.clone(),
+        };
+
+        if rrule.count.is_some() || !DateTimeIter::can_skip(rrule) {
+            // The dates have to be counted or walked from the start.
+            iter.buffer = period_iter
+                .by_ref()
+                .take_while(|dt| upper_bound.map_or(true, |upper_bound| *dt <= upper_bound))
+                .collect();
+            iter.stopped_early = period_iter.stopped_early();
+            iter.finished = true;
+        } else if let Some(upper_bound) = &upper_bound {
+            let target = period_iter.local_target(upper_bound, true);
+            if iter.period.skip_to(rrule, &limits, &target).is_err() {
+                iter.finished = true;
+                iter.stopped_early = Some(TerminationReason::OutOfRange);
+            }
+        }
+
+        Ok(iter)
+    }
+
+    /// Generates the dates of the current period and moves to the previous period.
+    fn generate_period(&mut self) {
+        self.period_iter.set_period(self.period.clone());
+        self.period_iter.generate_period();
+        let upper_bound = self.upper_bound;
+        self.buffer.extend(
+            self.period_iter
+                .buffer
+                .drain(..)
+                .filter(|dt| upper_bound.map_or(true, |upper_bound| *dt <= upper_bound)),
+        );
+
+        if self.period <= self.first_period {
+            self.finished = true;
+        } else if self
+            .period
+            .decrement(self.period_iter.ii.rrule(), &self.limits)
+            .is_err()
+        {
+            self.finished = true;
+            self.stopped_early = Some(TerminationReason::OutOfRange);
+        }
+    }
+}
+
+impl Iterator for RRuleRevIter {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut loop_counter: u32 = 0;
+        while self.buffer.is_empty() && !self.finished {
+            if self.period_iter.budget.is_exhausted() {
+                self.finished = true;
+                self.stopped_early = Some(TerminationReason::TimedOut);
+                log::warn!(
+                    "Stopped iterating, the deadline passed or the iteration was cancelled."
+                );
+                return None;
+            }
+            // Prevent infinite loops
+            if self.limited {
+                loop_counter += 1;
+                if loop_counter >= self.limits.max_iterations {
+                    self.finished = true;
+                    self.stopped_early = Some(TerminationReason::MaxIterations);
+                    log::warn!(
+                        "Reached max loop counter (`{}`). \
+                    See 'validator limits' in docs for more info.",
+                        self.limits.max_iterations
+                    );
+                    return None;
+                }
+            }
+            self.generate_period();
+        }
+
+        self.buffer.pop()
+    }
+}
+
+impl WasLimited for RRuleRevIter {
+    fn stopped_early(&self) -> Option<TerminationReason> {
+        self.stopped_early
+    }
+}
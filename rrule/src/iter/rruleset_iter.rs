@@ -1,144 +1,280 @@
 use chrono::DateTime;
 
+use super::cursor::{Fingerprint, RRuleIterState, RRuleSetCursor};
 use super::rrule_iter::WasLimited;
-use super::{rrule_iter::RRuleIter, MAX_ITER_LOOP};
-use crate::RRuleSet;
+use super::{rrule_iter::RRuleIter, Budget, TerminationReason};
+use crate::{ExclusionSource, Limits, OccurrenceSource, RRule, RRuleSet};
 use crate::{RRuleError, Tz};
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 use std::{collections::HashMap, iter::Iterator};
 
+/// A generated date, with the end of its period if it has one, and where it comes from.
+pub(super) type SourcedDate = (DateTime<Tz>, Option<DateTime<Tz>>, OccurrenceSource);
+
 #[derive(Debug, Clone)]
 /// Iterator over all the dates in an [`RRuleSet`].
 pub struct RRuleSetIter {
     queue: HashMap<usize, DateTime<Tz>>,
     limited: bool,
+    limits: Limits,
     rrule_iters: Vec<RRuleIter>,
     exrules: Vec<RRuleIter>,
-    exdates: BTreeSet<i64>,
+    /// Timestamps of the excluded dates, with what excludes them.
+    exdates: BTreeMap<i64, ExclusionSource>,
     /// Sorted additional dates in descending order
-    rdates: Vec<DateTime<Tz>>,
-    was_limited: bool,
+    rdates: Vec<SourcedDate>,
+    /// Dates before this are skipped, see [`RRuleSet::after`].
+    after: Option<DateTime<Tz>>,
+    budget: Budget,
+    /// Why the iterator stopped, once it has.
+    termination: Option<TerminationReason>,
 }
 
 impl RRuleSetIter {
-    fn generate_date(
-        dates: &mut Vec<DateTime<Tz>>,
-        exrules: &mut [RRuleIter],
-        exdates: &mut BTreeSet<i64>,
-        limited: bool,
-    ) -> (Option<DateTime<Tz>>, bool) {
-        if dates.is_empty() {
-            return (None, false);
+    /// Returns what removes `date` from the set, if anything.
+    fn exclusion(&mut self, date: &DateTime<Tz>) -> Option<ExclusionSource> {
+        for (i, exrule) in self.exrules.iter_mut().enumerate() {
+            for exdate in exrule {
+                self.exdates
+                    .entry(exdate.timestamp())
+                    .or_insert(ExclusionSource::ExRule(i));
+                if exdate > *date {
+                    break;
+                }
+            }
         }
 
-        let mut date = dates.remove(dates.len() - 1);
-        let mut loop_counter: u32 = 0;
-        while Self::is_date_excluded(&date, exrules, exdates) {
-            if dates.is_empty() {
-                return (None, false);
+        self.exdates.get(&date.timestamp()).copied()
+    }
+}
+
+impl RRuleSetIter {
+    /// Returns the current state of the iterator, so that the iteration can be resumed
+    /// later with [`RRuleSet::iter_from_cursor`].
+    #[must_use]
+    pub fn cursor(&self) -> RRuleSetCursor {
+        RRuleSetCursor {
+            fingerprint: self.fingerprint(),
+            rrule_iters: self
+                .rrule_iters
+                .iter()
+                .enumerate()
+                .map(|(i, rrule_iter)| RRuleIterState::new(rrule_iter, self.queue.get(&i)))
+                .collect(),
+            exrule_iters: self
+                .exrules
+                .iter()
+                .map(|exrule_iter| RRuleIterState::new(exrule_iter, None))
+                .collect(),
+            exrule_dates: self
+                .exdates
+                .iter()
+                .filter_map(|(timestamp, source)| match source {
+                    ExclusionSource::ExRule(i) => Some((*timestamp, *i)),
+                    ExclusionSource::ExDate(_) => None,
+                })
+                .collect(),
+            rdates_remaining: self.rdates.len(),
+            was_limited: self.was_limited(),
+        }
+    }
+
+    /// Returns why the iteration stopped, or `None` if it hasn't stopped yet.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRuleSet, TerminationReason};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=2".parse().unwrap();
+    /// let mut iter = rrule_set.into_iter();
+    /// assert_eq!(iter.next().unwrap().to_rfc3339(), "2012-02-01T09:30:00+00:00");
+    /// assert_eq!(iter.termination_reason(), None);
+    /// assert_eq!(iter.by_ref().count(), 1);
+    /// assert_eq!(iter.termination_reason(), Some(TerminationReason::Finished));
+    /// ```
+    #[must_use]
+    pub fn termination_reason(&self) -> Option<TerminationReason> {
+        self.termination
+    }
+
+    /// Returns the [`Fingerprint`] of the rules and the remaining dates of the set.
+    fn fingerprint(&self) -> u64 {
+        let mut fingerprint = Fingerprint::new();
+        for rrule_iters in [&self.rrule_iters, &self.exrules] {
+            fingerprint.write_i64(rrule_iters.len() as i64);
+            for rrule_iter in rrule_iters {
+                fingerprint.write_str(&rrule_iter.ii.rrule().to_string());
+                fingerprint.write_str(rrule_iter.dt_start.timezone().name());
+                fingerprint.write_i64(rrule_iter.dt_start.timestamp());
             }
-            // Prevent infinite loops
-            if limited {
-                loop_counter += 1;
-                if loop_counter >= MAX_ITER_LOOP {
-                    log::warn!(
-                        "Reached max loop counter (`{}`). \
-                See 'validator limits' in docs for more info.",
-                        MAX_ITER_LOOP
-                    );
-                    return (None, true);
-                }
+        }
+        for (timestamp, source) in &self.exdates {
+            if let ExclusionSource::ExDate(_) = source {
+                fingerprint.write_i64(*timestamp);
             }
-            date = dates.remove(dates.len() - 1);
         }
-
-        (Some(date), false)
+        fingerprint.write_i64(self.rdates.len() as i64);
+        for (date, end, _) in &self.rdates {
+            fingerprint.write_i64(date.timestamp());
+            fingerprint.write_i64(end.map_or(i64::MIN, |end| end.timestamp()));
+        }
+        fingerprint.write_i64(self.after.map_or(i64::MIN, |after| after.timestamp()));
+        fingerprint.finish()
     }
 
-    fn generate(
-        rrule_iter: &mut RRuleIter,
-        exrules: &mut [RRuleIter],
-        exdates: &mut BTreeSet<i64>,
-        limited: bool,
-    ) -> (Option<DateTime<Tz>>, bool) {
-        let mut date = match rrule_iter.next() {
-            Some(d) => d,
-            None => return (None, false),
+    /// Creates an iterator over `rrule_set` in the state of `cursor`.
+    pub(crate) fn from_cursor(
+        rrule_set: &RRuleSet,
+        cursor: &RRuleSetCursor,
+    ) -> Result<Self, RRuleError> {
+        let mut rdates = sorted_rdates(rrule_set);
+        let different_set =
+            || RRuleError::new_iter_err("The cursor was created for a different set.");
+        if rrule_set.rrule.len() != cursor.rrule_iters.len()
+            || rrule_set.exrule.len() != cursor.exrule_iters.len()
+            || rdates.len() < cursor.rdates_remaining
+            || cursor
+                .exrule_dates
+                .iter()
+                .any(|(_, i)| *i >= rrule_set.exrule.len())
+        {
+            return Err(different_set());
+        }
+        rdates.truncate(cursor.rdates_remaining);
+
+        let limited = rrule_set.limited;
+        let limits = rrule_set.limits;
+        let budget = &rrule_set.budget;
+        let mut queue = HashMap::new();
+        let mut rrule_iters = vec![];
+        for (i, (rrule, state)) in rrule_set.rrule.iter().zip(&cursor.rrule_iters).enumerate() {
+            let (rrule_iter, queued) =
+                state.restore(rrule, &rrule_set.dt_start, limited, limits, budget.clone())?;
+            rrule_iters.push(rrule_iter);
+            if let Some(queued) = queued {
+                queue.insert(i, queued);
+            }
+        }
+        let exrules = rrule_set
+            .exrule
+            .iter()
+            .zip(&cursor.exrule_iters)
+            .map(|(exrule, state)| {
+                state
+                    .restore(exrule, &rrule_set.dt_start, limited, limits, budget.clone())
+                    .map(|(exrule_iter, _)| exrule_iter)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut exdates = exdate_timestamps(rrule_set);
+        for (timestamp, i) in &cursor.exrule_dates {
+            exdates
+                .entry(*timestamp)
+                .or_insert(ExclusionSource::ExRule(*i));
+        }
+        let iter = Self {
+            queue,
+            limited,
+            limits,
+            rrule_iters,
+            exrules,
+            exdates,
+            rdates,
+            after: rrule_set.after,
+            budget: budget.clone(),
+            termination: cursor
+                .was_limited
+                .then_some(TerminationReason::MaxIterations),
         };
+        if iter.fingerprint() != cursor.fingerprint {
+            return Err(different_set());
+        }
+        Ok(iter)
+    }
+
+    /// Returns the next occurrence, together with its end if it comes from an
+    /// `RDATE` with a period (`RDATE;VALUE=PERIOD`).
+    ///
+    /// This advances the iterator just like [`Iterator::next`].
+    pub fn next_with_end(&mut self) -> Option<(DateTime<Tz>, Option<DateTime<Tz>>)> {
+        self.next_sourced().map(|(date, end, _source)| (date, end))
+    }
+
+    /// Returns the next date, together with the end of its period and where it comes from.
+    pub(crate) fn next_sourced(&mut self) -> Option<SourcedDate> {
+        self.next_checked(false).map(|(date, _excluded_by)| date)
+    }
+
+    /// Returns the next date, together with what excludes it from the set.
+    /// Excluded dates are skipped unless `with_exclusions` is set.
+    pub(crate) fn next_checked(
+        &mut self,
+        with_exclusions: bool,
+    ) -> Option<(SourcedDate, Option<ExclusionSource>)> {
+        if self.termination.is_some() {
+            return None;
+        }
+
         let mut loop_counter: u32 = 0;
-        while Self::is_date_excluded(&date, exrules, exdates) {
+        loop {
+            let date = self.generate_next();
+            // The rrules stop early when the budget is exhausted, so the dates can't be trusted.
+            if self.is_out_of_budget() {
+                return None;
+            }
+            let Some(date) = date else {
+                let rrules_stopped_early =
+                    self.rrule_iters.iter().find_map(WasLimited::stopped_early);
+                self.termination =
+                    Some(rrules_stopped_early.unwrap_or(TerminationReason::Finished));
+                return None;
+            };
+            if matches!(self.after, Some(after) if date.0 < after) {
+                continue;
+            }
+            let excluded_by = self.exclusion(&date.0);
+            if self.is_out_of_budget() {
+                return None;
+            }
+            if excluded_by.is_none() || with_exclusions {
+                return Some((date, excluded_by));
+            }
+
             // Prevent infinite loops
-            if limited {
+            if self.limited {
                 loop_counter += 1;
-                if loop_counter >= MAX_ITER_LOOP {
+                if loop_counter >= self.limits.max_iterations {
+                    self.termination = Some(TerminationReason::MaxIterations);
                     log::warn!(
                         "Reached max loop counter (`{}`). \
                     See 'validator limits' in docs for more info.",
-                        MAX_ITER_LOOP
+                        self.limits.max_iterations
                     );
-                    return (None, true);
+                    return None;
                 }
             }
-
-            date = match rrule_iter.next() {
-                Some(d) => d,
-                None => return (None, false),
-            };
         }
-
-        (Some(date), false)
     }
 
-    fn is_date_excluded(
-        date: &DateTime<Tz>,
-        exrules: &mut [RRuleIter],
-        exdates: &mut BTreeSet<i64>,
-    ) -> bool {
-        for exrule in exrules {
-            for exdate in exrule {
-                exdates.insert(exdate.timestamp());
-                if exdate > *date {
-                    break;
-                }
-            }
+    /// Returns `true` if the deadline has passed or the iteration was cancelled,
+    /// and marks the iterator as timed out.
+    fn is_out_of_budget(&mut self) -> bool {
+        if !self.budget.is_exhausted() {
+            return false;
         }
-
-        exdates.contains(&date.timestamp())
+        self.termination = Some(TerminationReason::TimedOut);
+        log::warn!("Stopped iterating, the deadline passed or the iteration was cancelled.");
+        true
     }
-}
 
-impl Iterator for RRuleSetIter {
-    type Item = DateTime<Tz>;
-
-    fn next(&mut self) -> Option<Self::Item> {
+    fn generate_next(&mut self) -> Option<SourcedDate> {
         let mut next_date: Option<(usize, DateTime<Tz>)> = None;
 
-        // If there already was an error, return the error again.
-        if self.was_limited {
-            return None;
-        }
-
         for (i, rrule_iter) in self.rrule_iters.iter_mut().enumerate() {
             let rrule_queue = self.queue.remove(&i);
-            let next_rrule_date = if let Some(d) = rrule_queue {
-                Some(d)
-            } else {
-                // should be method on self
-                let (date, was_limited) = Self::generate(
-                    rrule_iter,
-                    &mut self.exrules,
-                    &mut self.exdates,
-                    self.limited,
-                );
-
-                if was_limited {
-                    self.was_limited = true;
-                    return None;
-                }
-
-                date
-            };
+            let next_rrule_date = rrule_queue.or_else(|| rrule_iter.next());
 
             if let Some(next_rrule_date) = next_rrule_date {
                 match next_date {
@@ -159,22 +295,11 @@ impl Iterator for RRuleSetIter {
             }
         }
 
-        let (generated_date, was_limited) = Self::generate_date(
-            &mut self.rdates,
-            &mut self.exrules,
-            &mut self.exdates,
-            self.limited,
-        );
-        if was_limited {
-            self.was_limited = true;
-            return None;
-        }
-
-        match generated_date {
+        match self.rdates.pop() {
             Some(first_rdate) => {
                 let next_date = match next_date {
                     Some(next_date) => {
-                        if next_date.1 >= first_rdate {
+                        if next_date.1 >= first_rdate.0 {
                             // Add previous date to its rrule queue
                             self.queue.insert(next_date.0, next_date.1);
 
@@ -183,54 +308,113 @@ impl Iterator for RRuleSetIter {
                             // add rdate back
                             self.rdates.push(first_rdate);
 
-                            next_date.1
+                            (next_date.1, None, OccurrenceSource::RRule(next_date.0))
                         }
                     }
                     None => first_rdate,
                 };
                 Some(next_date)
             }
-            None => next_date.map(|d| d.1),
+            None => next_date.map(|d| (d.1, None, OccurrenceSource::RRule(d.0))),
         }
     }
 }
 
+impl Iterator for RRuleSetIter {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_end().map(|(date, _end)| date)
+    }
+}
+
+/// Returns all the rdates and the rdates with a period of the set, sorted in decreasing order.
+pub(super) fn sorted_rdates(rrule_set: &RRuleSet) -> Vec<SourcedDate> {
+    // Dates of a date-only set are always at the start of their day.
+    let rdates = if rrule_set.date_only {
+        rrule_set.dates_at_start_of_day(&rrule_set.rdate)
+    } else {
+        rrule_set.rdate.clone()
+    };
+
+    let mut rdates_sorted = rdates
+        .into_iter()
+        .enumerate()
+        .map(|(i, rdate)| (rdate, None, OccurrenceSource::RDate(i)))
+        .chain(
+            rrule_set
+                .rdate_period
+                .iter()
+                .enumerate()
+                .map(|(i, period)| {
+                    (
+                        *period.get_start(),
+                        period.get_end(),
+                        OccurrenceSource::RDatePeriod(i),
+                    )
+                }),
+        )
+        .collect::<Vec<_>>();
+    rdates_sorted.sort_by(|d1, d2| {
+        d2.0.partial_cmp(&d1.0)
+            .expect("Could not order dates correctly")
+    });
+    rdates_sorted
+}
+
+/// Returns the timestamps of all the exdates of the set, with the exdate they come from.
+pub(super) fn exdate_timestamps(rrule_set: &RRuleSet) -> BTreeMap<i64, ExclusionSource> {
+    // Dates of a date-only set are always at the start of their day.
+    let exdates = if rrule_set.date_only {
+        rrule_set.dates_at_start_of_day(&rrule_set.exdate)
+    } else {
+        rrule_set.exdate.clone()
+    };
+    let mut timestamps = BTreeMap::new();
+    for (i, exdate) in exdates.iter().enumerate() {
+        timestamps
+            .entry(exdate.timestamp())
+            .or_insert(ExclusionSource::ExDate(i));
+    }
+    timestamps
+}
+
 impl IntoIterator for &RRuleSet {
     type Item = DateTime<Tz>;
 
     type IntoIter = RRuleSetIter;
 
     fn into_iter(self) -> Self::IntoIter {
-        // Sort in decreasing order
-        let mut rdates_sorted = self.rdate.clone();
-        rdates_sorted
-            .sort_by(|d1, d2| d2.partial_cmp(d1).expect("Could not order dates correctly"));
-
         let limited = self.limited;
+        let limits = self.limits;
+        let exdates = exdate_timestamps(self);
+        let rrule_iter = |rrule: &RRule| {
+            let mut iter = rrule.iter_with_ctx(self.dt_start, limited, limits, self.budget.clone());
+            if let Some(after) = &self.after {
+                iter.skip_to(after);
+            }
+            iter
+        };
 
         RRuleSetIter {
             queue: HashMap::new(),
             limited,
-            rrule_iters: self
-                .rrule
-                .iter()
-                .map(|rrule| rrule.iter_with_ctx(self.dt_start, limited))
-                .collect(),
-            rdates: rdates_sorted,
-            exrules: self
-                .exrule
-                .iter()
-                .map(|exrule| exrule.iter_with_ctx(self.dt_start, limited))
-                .collect(),
-            exdates: self.exdate.iter().map(DateTime::timestamp).collect(),
-            was_limited: false,
+            limits,
+            rrule_iters: self.rrule.iter().map(rrule_iter).collect(),
+            rdates: sorted_rdates(self),
+            exrules: self.exrule.iter().map(rrule_iter).collect(),
+            exdates,
+            after: self.after,
+            budget: self.budget.clone(),
+            termination: None,
         }
     }
 }
 
 impl WasLimited for RRuleSetIter {
-    fn was_limited(&self) -> bool {
-        self.was_limited
+    fn stopped_early(&self) -> Option<TerminationReason> {
+        self.termination
+            .filter(|reason| *reason != TerminationReason::Finished)
     }
 }
 
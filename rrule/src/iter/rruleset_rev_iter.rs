@@ -0,0 +1,239 @@
+use chrono::DateTime;
+
+use super::rrule_iter::WasLimited;
+use super::rrule_rev_iter::RRuleRevIter;
+use super::rruleset_iter::{exdate_timestamps, sorted_rdates, SourcedDate};
+use super::{Budget, TerminationReason};
+use crate::{ExclusionSource, Limits, OccurrenceSource, RRule, RRuleError, RRuleSet, Tz};
+use std::collections::{BTreeMap, HashMap};
+
+/// Iterator over all the dates in an [`RRuleSet`] in descending order.
+///
+/// See [`RRuleSet::iter_rev`].
+#[derive(Debug, Clone)]
+pub struct RRuleSetRevIter {
+    queue: HashMap<usize, DateTime<Tz>>,
+    limited: bool,
+    limits: Limits,
+    dt_start: DateTime<Tz>,
+    rrule_iters: Vec<RRuleRevIter>,
+    /// The exrules, with their iterator once it has been needed.
+    exrules: Vec<(RRule, Option<RRuleRevIter>)>,
+    /// Timestamps of the excluded dates, with what excludes them.
+    exdates: BTreeMap<i64, ExclusionSource>,
+    /// Sorted additional dates in ascending order
+    rdates: Vec<SourcedDate>,
+    /// Iteration stops at dates before this, see [`RRuleSet::after`].
+    after: Option<DateTime<Tz>>,
+    budget: Budget,
+    /// Why the iterator stopped, once it has.
+    termination: Option<TerminationReason>,
+}
+
+impl RRuleSetRevIter {
+    pub(crate) fn new(rrule_set: &RRuleSet) -> Result<Self, RRuleError> {
+        let limited = rrule_set.limited;
+        let limits = rrule_set.limits;
+        let before = rrule_set.before;
+
+        let rrule_iters = rrule_set
+            .rrule
+            .iter()
+            .map(|rrule| {
+                RRuleRevIter::new(
+                    rrule,
+                    &rrule_set.dt_start,
+                    before,
+                    limited,
+                    limits,
+                    rrule_set.budget.clone(),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut rdates = sorted_rdates(rrule_set);
+        rdates.reverse();
+        if let Some(before) = &before {
+            rdates.retain(|(rdate, _, _)| rdate <= before);
+        }
+
+        Ok(Self {
+            queue: HashMap::new(),
+            limited,
+            limits,
+            dt_start: rrule_set.dt_start,
+            rrule_iters,
+            exrules: rrule_set
+                .exrule
+                .iter()
+                .map(|exrule| (exrule.clone(), None))
+                .collect(),
+            exdates: exdate_timestamps(rrule_set),
+            rdates,
+            after: rrule_set.after,
+            budget: rrule_set.budget.clone(),
+            termination: None,
+        })
+    }
+
+    /// Returns why the iteration stopped, or `None` if it hasn't stopped yet.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRuleSet, TerminationReason};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=2".parse().unwrap();
+    /// let mut iter = rrule_set.iter_rev().unwrap();
+    /// assert_eq!(iter.by_ref().count(), 2);
+    /// assert_eq!(iter.termination_reason(), Some(TerminationReason::Finished));
+    /// ```
+    #[must_use]
+    pub fn termination_reason(&self) -> Option<TerminationReason> {
+        self.termination
+    }
+
+    /// Returns the previous date, together with the end of its period and where it comes from.
+    pub(crate) fn next_sourced(&mut self) -> Option<SourcedDate> {
+        if self.termination.is_some() {
+            return None;
+        }
+
+        let mut loop_counter: u32 = 0;
+        loop {
+            let date = self.generate_previous();
+            // The rrules stop early when the budget is exhausted, so the dates can't be trusted.
+            if self.is_out_of_budget() {
+                return None;
+            }
+            let Some(date) = date else {
+                let rrules_stopped_early =
+                    self.rrule_iters.iter().find_map(WasLimited::stopped_early);
+                self.termination
+                    .get_or_insert(rrules_stopped_early.unwrap_or(TerminationReason::Finished));
+                return None;
+            };
+            if matches!(self.after, Some(after) if date.0 < after) {
+                self.termination = Some(TerminationReason::Finished);
+                return None;
+            }
+            let excluded = self.is_date_excluded(&date.0);
+            if self.is_out_of_budget() {
+                return None;
+            }
+            if !excluded {
+                return Some(date);
+            }
+
+            // Prevent infinite loops
+            if self.limited {
+                loop_counter += 1;
+                if loop_counter >= self.limits.max_iterations {
+                    self.termination = Some(TerminationReason::MaxIterations);
+                    log::warn!(
+                        "Reached max loop counter (`{}`). \
+                    See 'validator limits' in docs for more info.",
+                        self.limits.max_iterations
+                    );
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the deadline has passed or the iteration was cancelled,
+    /// and marks the iterator as timed out.
+    fn is_out_of_budget(&mut self) -> bool {
+        if !self.budget.is_exhausted() {
+            return false;
+        }
+        self.termination = Some(TerminationReason::TimedOut);
+        log::warn!("Stopped iterating, the deadline passed or the iteration was cancelled.");
+        true
+    }
+
+    /// Returns the latest date of all the rrules and rdates which hasn't been returned yet.
+    fn generate_previous(&mut self) -> Option<SourcedDate> {
+        let mut previous_date: Option<(usize, DateTime<Tz>)> = None;
+
+        for (i, rrule_iter) in self.rrule_iters.iter_mut().enumerate() {
+            let Some(date) = self.queue.remove(&i).or_else(|| rrule_iter.next()) else {
+                if rrule_iter.was_limited() {
+                    self.termination = Some(TerminationReason::MaxIterations);
+                    return None;
+                }
+                continue;
+            };
+
+            match previous_date {
+                Some((idx, previous)) if previous >= date => {
+                    // Store for next iterations
+                    self.queue.insert(i, date);
+                    previous_date = Some((idx, previous));
+                }
+                Some((idx, previous)) => {
+                    // Add previous date to its rrule queue
+                    self.queue.insert(idx, previous);
+                    previous_date = Some((i, date));
+                }
+                None => previous_date = Some((i, date)),
+            }
+        }
+
+        match (previous_date, self.rdates.last()) {
+            (Some((idx, date)), Some(rdate)) if date > rdate.0 => {
+                Some((date, None, OccurrenceSource::RRule(idx)))
+            }
+            (Some((idx, date)), Some(_)) => {
+                // Add previous date to its rrule queue
+                self.queue.insert(idx, date);
+                self.rdates.pop()
+            }
+            (Some((idx, date)), None) => Some((date, None, OccurrenceSource::RRule(idx))),
+            (None, _) => self.rdates.pop(),
+        }
+    }
+
+    fn is_date_excluded(&mut self, date: &DateTime<Tz>) -> bool {
+        for (i, (exrule, exrule_iter)) in self.exrules.iter_mut().enumerate() {
+            // The iterator starts at the first date which is checked, as there is
+            // no need to look at the exrule after it.
+            let exrule_iter = exrule_iter.get_or_insert_with(|| {
+                RRuleRevIter::new(
+                    exrule,
+                    &self.dt_start,
+                    Some(*date),
+                    self.limited,
+                    self.limits,
+                    self.budget.clone(),
+                )
+                .expect("an upper bound is always given")
+            });
+            for exdate in exrule_iter {
+                self.exdates
+                    .entry(exdate.timestamp())
+                    .or_insert(ExclusionSource::ExRule(i));
+                if exdate < *date {
+                    break;
+                }
+            }
+        }
+
+        self.exdates.contains_key(&date.timestamp())
+    }
+}
+
+impl Iterator for RRuleSetRevIter {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_sourced().map(|(date, _end, _source)| date)
+    }
+}
+
+impl WasLimited for RRuleSetRevIter {
+    fn stopped_early(&self) -> Option<TerminationReason> {
+        self.termination
+            .filter(|reason| *reason != TerminationReason::Finished)
+    }
+}
@@ -0,0 +1,170 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+
+use chrono::DateTime;
+
+use crate::Tz;
+
+/// Lazy set operations over sorted iterators of dates, like the iterators of [`crate::RRuleSet`]s.
+///
+/// The sets can have different start dates and timezones, as dates are compared by the instant
+/// they represent. The results are sorted and deduplicated, and can be combined further.
+/// When a date is in both iterators, the one of `self` is returned.
+///
+/// An intersection of two infinite sets which never meet, like "every Monday" and
+/// "every Tuesday", never returns. Use [`crate::RRuleSet::before`] to bound such sets.
+///
+/// # Usage
+///
+/// ```
+/// use rrule::{RRuleSet, SetOperations};
+///
+/// let standups: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;COUNT=10"
+///     .parse()
+///     .unwrap();
+/// let holidays: RRuleSet = "DTSTART:20240101T090000Z\nRDATE:20240101T090000Z,20240105T090000Z"
+///     .parse()
+///     .unwrap();
+/// let on_call: RRuleSet = "DTSTART;TZID=Europe/Berlin:20240101T100000\nRRULE:FREQ=WEEKLY;BYDAY=MO,FR"
+///     .parse()
+///     .unwrap();
+///
+/// let dates = standups
+///     .into_iter()
+///     .difference(&holidays)
+///     .intersection(&on_call)
+///     .map(|date| date.to_rfc3339())
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     dates,
+///     vec!["2024-01-08T09:00:00+00:00", "2024-01-12T09:00:00+00:00"]
+/// );
+/// ```
+pub trait SetOperations: Iterator<Item = DateTime<Tz>> + Sized {
+    /// Returns the dates which are in `self`, in `other` or in both.
+    fn union<I>(self, other: I) -> SetOperationIter<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = DateTime<Tz>>,
+    {
+        SetOperationIter::new(self, other.into_iter(), Operation::Union)
+    }
+
+    /// Returns the dates which are in both `self` and `other`.
+    fn intersection<I>(self, other: I) -> SetOperationIter<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = DateTime<Tz>>,
+    {
+        SetOperationIter::new(self, other.into_iter(), Operation::Intersection)
+    }
+
+    /// Returns the dates which are in `self`, but not in `other`.
+    fn difference<I>(self, other: I) -> SetOperationIter<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = DateTime<Tz>>,
+    {
+        SetOperationIter::new(self, other.into_iter(), Operation::Difference)
+    }
+
+    /// Returns the dates which are in either `self` or `other`, but not in both.
+    fn symmetric_difference<I>(self, other: I) -> SetOperationIter<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = DateTime<Tz>>,
+    {
+        SetOperationIter::new(self, other.into_iter(), Operation::SymmetricDifference)
+    }
+}
+
+impl<T: Iterator<Item = DateTime<Tz>>> SetOperations for T {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+impl Operation {
+    /// Returns `true` if a date which is in the left and/or right iterator is in the result.
+    fn keeps(self, in_left: bool, in_right: bool) -> bool {
+        match self {
+            Self::Union => true,
+            Self::Intersection => in_left && in_right,
+            Self::Difference => in_left && !in_right,
+            Self::SymmetricDifference => in_left != in_right,
+        }
+    }
+}
+
+/// Iterator over the result of a set operation, see [`SetOperations`].
+#[derive(Debug, Clone)]
+pub struct SetOperationIter<A: Iterator<Item = DateTime<Tz>>, B: Iterator<Item = DateTime<Tz>>> {
+    left: Peekable<A>,
+    right: Peekable<B>,
+    operation: Operation,
+}
+
+impl<A, B> SetOperationIter<A, B>
+where
+    A: Iterator<Item = DateTime<Tz>>,
+    B: Iterator<Item = DateTime<Tz>>,
+{
+    fn new(left: A, right: B, operation: Operation) -> Self {
+        Self {
+            left: left.peekable(),
+            right: right.peekable(),
+            operation,
+        }
+    }
+}
+
+/// Takes the next date, and skips the dates which are equal to it.
+fn next_distinct(iter: &mut Peekable<impl Iterator<Item = DateTime<Tz>>>) -> Option<DateTime<Tz>> {
+    let date = iter.next()?;
+    while iter.next_if_eq(&date).is_some() {}
+    Some(date)
+}
+
+impl<A, B> Iterator for SetOperationIter<A, B>
+where
+    A: Iterator<Item = DateTime<Tz>>,
+    B: Iterator<Item = DateTime<Tz>>,
+{
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ordering = match (self.left.peek(), self.right.peek()) {
+                (Some(left), Some(right)) => left.cmp(right),
+                (Some(_), None) => {
+                    if self.operation == Operation::Intersection {
+                        return None;
+                    }
+                    Ordering::Less
+                }
+                (None, Some(_)) => {
+                    if matches!(
+                        self.operation,
+                        Operation::Intersection | Operation::Difference
+                    ) {
+                        return None;
+                    }
+                    Ordering::Greater
+                }
+                (None, None) => return None,
+            };
+
+            let (date, in_left, in_right) = match ordering {
+                Ordering::Less => (next_distinct(&mut self.left)?, true, false),
+                Ordering::Greater => (next_distinct(&mut self.right)?, false, true),
+                Ordering::Equal => {
+                    next_distinct(&mut self.right);
+                    (next_distinct(&mut self.left)?, true, true)
+                }
+            };
+            if self.operation.keeps(in_left, in_right) {
+                return Some(date);
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::{rrule_iter::WasLimited, RRuleSetIter, TerminationReason};
+use crate::SourcedDateTime;
+
+#[derive(Debug, Clone)]
+/// Iterator over the dates of an [`crate::RRuleSet`] together with where they come from.
+///
+/// Created with [`crate::RRuleSet::iter_sourced`].
+pub struct SourcedIter {
+    iter: RRuleSetIter,
+    with_exclusions: bool,
+}
+
+impl SourcedIter {
+    pub(crate) fn new(iter: RRuleSetIter) -> Self {
+        Self {
+            iter,
+            with_exclusions: false,
+        }
+    }
+
+    /// Also return the dates which are removed by an `EXRULE` or `EXDATE`,
+    /// with [`SourcedDateTime::excluded_by`] set.
+    #[must_use]
+    pub fn with_exclusions(mut self) -> Self {
+        self.with_exclusions = true;
+        self
+    }
+}
+
+impl Iterator for SourcedIter {
+    type Item = SourcedDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_checked(self.with_exclusions)
+            .map(|((date, _end, source), excluded_by)| SourcedDateTime {
+                date,
+                source,
+                excluded_by,
+            })
+    }
+}
+
+impl WasLimited for SourcedIter {
+    fn stopped_early(&self) -> Option<TerminationReason> {
+        self.iter.stopped_early()
+    }
+}
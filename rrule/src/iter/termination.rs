@@ -0,0 +1,35 @@
+/// Why an iteration over an [`crate::RRuleSet`] stopped.
+///
+/// # Usage
+///
+/// ```
+/// use rrule::{RRuleSet, TerminationReason};
+///
+/// let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3".parse().unwrap();
+/// assert_eq!(rrule_set.clone().all(2).reason, TerminationReason::Limit);
+/// assert_eq!(rrule_set.all(10).reason, TerminationReason::Finished);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerminationReason {
+    /// All the dates were generated, or the end of the range was reached.
+    Finished,
+    /// The number of dates asked for was reached, like the `limit` of [`crate::RRuleSet::all`].
+    Limit,
+    /// No date was found within the max iterations of the validation limits,
+    /// see [`crate::Limits::max_iterations`].
+    MaxIterations,
+    /// The next date is outside of the year range of the validation limits,
+    /// see [`crate::Limits::year_range`], or can't be represented.
+    OutOfRange,
+    /// The deadline passed or the iteration was cancelled,
+    /// see [`crate::RRuleSet::deadline`] and [`crate::RRuleSet::cancellation_token`].
+    TimedOut,
+}
+
+impl TerminationReason {
+    /// Returns `true` if the iteration stopped at the number of dates asked for or at the
+    /// iteration limit, so there can be more dates than were returned.
+    pub(crate) fn is_limited(self) -> bool {
+        matches!(self, Self::Limit | Self::MaxIterations)
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops;
 
-use crate::core::{duration_from_midnight, Tz};
+use crate::core::{duration_from_midnight, start_of_day, Tz};
 use chrono::{NaiveDate, NaiveTime, Utc};
 
 const DAY_SECS: i64 = 24 * 60 * 60;
@@ -85,9 +85,8 @@ pub(crate) fn add_time_to_date(
     }
     // If the day is a daylight saving time, the above code might not work, and we
     // can try to get a valid datetime by adding the `time` as a duration instead.
-    let dt = date.and_hms_opt(0, 0, 0)?.and_local_timezone(tz).single()?;
     let day_duration = duration_from_midnight(time);
-    dt.checked_add_signed(day_duration)
+    start_of_day(date, tz).checked_add_signed(day_duration)
 }
 
 #[cfg(test)]
@@ -87,6 +87,14 @@
 //!
 //! Note: All the generated recurrence will be in the same time zone as the `dt_start` property.
 //!
+//! # Combining sets
+//! The iterators of several [`RRuleSet`]s can be combined with the lazy set operations of
+//! [`SetOperations`], like "standups minus holidays" or "days both people are on call".
+//!
+//! # Parsing iCalendar files
+//! [`Calendar`] parses the `VEVENT`, `VTODO` and `VJOURNAL` components of an iCalendar object (e.g. a `.ics` file)
+//! into one [`RRuleSet`] per component. Properties like `UID` and `SUMMARY` are available as raw [`Property`]s.
+//!
 
 #![forbid(unsafe_code)]
 #![deny(clippy::all)]
@@ -97,11 +105,33 @@ mod core;
 mod error;
 mod iter;
 mod parser;
+#[cfg(feature = "serde")]
+pub mod structured;
 mod tests;
+mod text;
 mod validator;
 
+pub use crate::core::{Calendar, Component, ComponentKind, Property};
+pub use crate::core::{
+    Conflict, ConflictResult, ExclusionSource, Explanation, FreeBusy, FreeBusyQuery, ICalDuration,
+    Occurrence, OccurrenceResult, OccurrenceSource, Period, PeriodEnd, RRuleCheck,
+    RRuleExplanation, RRulePart, SourcedDateTime, WorkingHours,
+};
 pub use crate::core::{Frequency, NWeekday, RRule, RRuleResult, RRuleSet, Tz};
 pub use crate::core::{Unvalidated, Validated};
 pub use chrono::Weekday;
 pub use error::{ParseError, RRuleError, ValidationError};
-pub use iter::RRuleSetIter;
+pub use iter::{
+    CancellationToken, OccurrenceIter, RRuleSetCursor, RRuleSetDateIter, RRuleSetIter,
+    RRuleSetRevIter, SetOperationIter, SetOperations, SourcedIter, TerminationReason,
+};
+#[cfg(feature = "locale-nl")]
+pub use text::Dutch;
+#[cfg(feature = "locale-fr")]
+pub use text::French;
+#[cfg(feature = "locale-de")]
+pub use text::German;
+#[cfg(feature = "locale-es")]
+pub use text::Spanish;
+pub use text::{English, Locale};
+pub use validator::Limits;
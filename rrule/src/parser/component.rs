@@ -0,0 +1,269 @@
+//! Parsing of iCalendar components, like `VEVENT` and `VTODO`, which can hold
+//! recurrence properties.
+use std::str::FromStr;
+
+use super::{
+    content_line::{ContentLineCaptures, PropertyName},
+    regex::unfold_content_lines,
+    utils::find_unquoted,
+    Grammar, ParseError,
+};
+use crate::core::{ComponentKind, Property};
+
+/// The recurrence grammar and the remaining properties of a single component.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ComponentGrammar {
+    pub kind: ComponentKind,
+    pub grammar: Grammar,
+    pub properties: Vec<Property>,
+}
+
+/// A component that is currently being parsed.
+struct OpenComponent<'a> {
+    kind: ComponentKind,
+    /// Number of components that were open when this component was opened.
+    depth: usize,
+    content_lines_parts: Vec<ContentLineCaptures<'a>>,
+    properties: Vec<Property>,
+}
+
+impl<'a> OpenComponent<'a> {
+    fn push(&mut self, name: &'a str, parameters: Option<&'a str>, value: &'a str) {
+        match PropertyName::from_str(name) {
+            Ok(property_name) => self.content_lines_parts.push(ContentLineCaptures {
+                property_name,
+                parameters,
+                value,
+            }),
+            Err(_) => {
+                self.properties
+                    .push(Property::new(name, parameters.unwrap_or_default(), value))
+            }
+        }
+    }
+
+    fn finish(self) -> Result<ComponentGrammar, ParseError> {
+        Ok(ComponentGrammar {
+            kind: self.kind,
+            grammar: Grammar::try_from(self.content_lines_parts)?,
+            properties: self.properties,
+        })
+    }
+}
+
+/// Splits a content line into its name, parameters and value.
+///
+/// For example `DTSTART;TZID=Europe/Berlin:20120201T093000` is split into
+/// `DTSTART`, `Some("TZID=Europe/Berlin")` and `20120201T093000`.
+pub(crate) fn split_content_line(line: &str) -> Result<(&str, Option<&str>, &str), ParseError> {
+    let colon_idx =
+        find_unquoted(line, ':').ok_or_else(|| ParseError::InvalidContentLine(line.into()))?;
+    let (head, value) = (&line[..colon_idx], &line[colon_idx + 1..]);
+    let (name, parameters) = match head.split_once(';') {
+        Some((name, parameters)) => (name, Some(parameters)),
+        None => (head, None),
+    };
+
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(ParseError::InvalidContentLine(line.into()));
+    }
+
+    Ok((name, parameters, value))
+}
+
+/// Walks all the `BEGIN`/`END` blocks of an iCalendar object and collects the
+/// components that can hold recurrence properties (`VEVENT`, `VTODO` and `VJOURNAL`).
+///
+/// Properties which are not related to the recurrence are kept as raw properties, and
+/// components that can't hold recurrence properties (e.g. `VTIMEZONE`, or a `VALARM`
+/// nested in a `VEVENT`) are skipped.
+pub(crate) fn parse_components(s: &str) -> Result<Vec<ComponentGrammar>, ParseError> {
+    let s = unfold_content_lines(s);
+    let mut open_components: Vec<String> = vec![];
+    let mut current: Option<OpenComponent> = None;
+    let mut components = vec![];
+
+    for line in s.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (name, parameters, value) = split_content_line(line)?;
+        match &name.to_uppercase()[..] {
+            "BEGIN" => {
+                let component_name = value.trim().to_uppercase();
+                if current.is_none() {
+                    if let Some(kind) = ComponentKind::from_name(&component_name) {
+                        current = Some(OpenComponent {
+                            kind,
+                            depth: open_components.len(),
+                            content_lines_parts: vec![],
+                            properties: vec![],
+                        });
+                    }
+                }
+                open_components.push(component_name);
+            }
+            "END" => {
+                let component_name = value.trim().to_uppercase();
+                match open_components.pop() {
+                    Some(expected) if expected == component_name => {}
+                    Some(expected) => {
+                        return Err(ParseError::MismatchedComponentEnd {
+                            expected,
+                            found: component_name,
+                        })
+                    }
+                    None => return Err(ParseError::UnexpectedComponentEnd(component_name)),
+                }
+
+                if matches!(&current, Some(component) if component.depth == open_components.len()) {
+                    if let Some(component) = current.take() {
+                        components.push(component.finish()?);
+                    }
+                }
+            }
+            _ => {
+                // Only the properties of the component itself are of interest,
+                // not the ones of its sub-components.
+                if let Some(component) = current
+                    .as_mut()
+                    .filter(|component| component.depth + 1 == open_components.len())
+                {
+                    component.push(name, parameters, value);
+                }
+            }
+        }
+    }
+
+    if let Some(component_name) = open_components.pop() {
+        return Err(ParseError::UnclosedComponent(component_name));
+    }
+
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{parser::ContentLine, Frequency, RRule, Tz};
+
+    #[test]
+    fn splits_content_lines() {
+        let tests = [
+            ("UID:1234", ("UID", None, "1234")),
+            (
+                "DTSTART;TZID=Europe/Berlin:20120201T093000",
+                ("DTSTART", Some("TZID=Europe/Berlin"), "20120201T093000"),
+            ),
+            (
+                "X-WR-CALNAME:Team: Standups",
+                ("X-WR-CALNAME", None, "Team: Standups"),
+            ),
+            (
+                "ATTENDEE;CN=\"Doe: John\":mailto:john@example.com",
+                (
+                    "ATTENDEE",
+                    Some("CN=\"Doe: John\""),
+                    "mailto:john@example.com",
+                ),
+            ),
+        ];
+        for (input, expected_output) in tests {
+            assert_eq!(split_content_line(input), Ok(expected_output));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_content_lines() {
+        for input in ["no colon here", ":value", "IN VALID:value"] {
+            assert_eq!(
+                split_content_line(input),
+                Err(ParseError::InvalidContentLine(input.into()))
+            );
+        }
+    }
+
+    #[test]
+    fn parses_components_in_calendar() {
+        let input = "BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Example//EN
+BEGIN:VTIMEZONE
+TZID:Europe/Berlin
+BEGIN:STANDARD
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU
+END:STANDARD
+END:VTIMEZONE
+BEGIN:VEVENT
+UID:event-1
+SUMMARY:Standup
+DTSTART:20120201T093000Z
+RRULE:FREQ=DAILY;COUNT=3
+BEGIN:VALARM
+ACTION:DISPLAY
+TRIGGER:-PT15M
+END:VALARM
+END:VEVENT
+BEGIN:VTODO
+UID:todo-1
+END:VTODO
+END:VCALENDAR";
+
+        let components = parse_components(input).unwrap();
+        assert_eq!(components.len(), 2);
+
+        let event = &components[0];
+        assert_eq!(event.kind, ComponentKind::Event);
+        assert_eq!(
+            event.grammar.start.as_ref().map(|start| start.datetime),
+            Some(Tz::UTC.with_ymd_and_hms(2012, 2, 1, 9, 30, 0).unwrap())
+        );
+        assert_eq!(
+            event.grammar.content_lines,
+            vec![ContentLine::RRule(RRule {
+                freq: Frequency::Daily,
+                count: Some(3),
+                ..Default::default()
+            })]
+        );
+        assert_eq!(
+            event.properties,
+            vec![
+                Property::new("UID", "", "event-1"),
+                Property::new("SUMMARY", "", "Standup"),
+            ]
+        );
+
+        let todo = &components[1];
+        assert_eq!(todo.kind, ComponentKind::Todo);
+        assert_eq!(todo.grammar.start, None);
+        assert_eq!(todo.properties, vec![Property::new("UID", "", "todo-1")]);
+    }
+
+    #[test]
+    fn rejects_unbalanced_components() {
+        let tests = [
+            (
+                "BEGIN:VCALENDAR\nBEGIN:VEVENT\nEND:VCALENDAR",
+                ParseError::MismatchedComponentEnd {
+                    expected: "VEVENT".into(),
+                    found: "VCALENDAR".into(),
+                },
+            ),
+            (
+                "BEGIN:VEVENT\nUID:1",
+                ParseError::UnclosedComponent("VEVENT".into()),
+            ),
+            (
+                "END:VEVENT",
+                ParseError::UnexpectedComponentEnd("VEVENT".into()),
+            ),
+        ];
+        for (input, expected_output) in tests {
+            assert_eq!(parse_components(input), Err(expected_output));
+        }
+    }
+}
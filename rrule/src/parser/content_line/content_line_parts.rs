@@ -1,4 +1,4 @@
-use crate::parser::{regex::get_property_name, ParseError};
+use crate::parser::{regex::get_property_name, utils::find_unquoted, ParseError};
 
 use super::PropertyName;
 
@@ -22,21 +22,20 @@ impl<'a> ContentLineCaptures<'a> {
                 value: line,
             }),
             property_name => {
+                // A colon in a quoted parameter value doesn't separate the value.
+                let colon_idx = find_unquoted(line, ':');
                 let mut parameters = None;
                 if line.starts_with(&format!("{};", property_name)) {
-                    let only_colon_idx = line.find(':');
-                    if let Some(only_colon_idx) = only_colon_idx {
-                        parameters =
-                            Some(&line[property_name.to_string().len() + 1..only_colon_idx]);
+                    if let Some(colon_idx) = colon_idx {
+                        parameters = Some(&line[property_name.to_string().len() + 1..colon_idx]);
                     }
                 }
 
                 Ok(Self {
                     property_name,
                     parameters,
-                    value: line
-                        .split_once(':')
-                        .map(|(_name, val)| val)
+                    value: colon_idx
+                        .map(|colon_idx| &line[colon_idx + 1..])
                         .unwrap_or_default(),
                 })
             }
@@ -108,6 +107,14 @@ mod tests {
                     value: "FREQ=DAILY;COUNT=10",
                 },
             ),
+            (
+                "DTSTART;TZID=\"Europe/Berlin\";X-NOTE=\"a:b\":20120201T093000",
+                ContentLineCaptures {
+                    property_name: PropertyName::DtStart,
+                    parameters: Some("TZID=\"Europe/Berlin\";X-NOTE=\"a:b\""),
+                    value: "20120201T093000",
+                },
+            ),
         ];
         for (input, expected_output) in tests {
             let output = ContentLineCaptures::new(input);
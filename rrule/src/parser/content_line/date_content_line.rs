@@ -4,13 +4,13 @@ use log::warn;
 
 use crate::{
     parser::{
-        datetime::{datestring_to_date, parse_timezone},
+        datetime::{datestring_to_date, parse_period, parse_timezone},
         ParseError,
     },
-    Tz,
+    Period, Tz,
 };
 
-use super::{content_line_parts::ContentLineCaptures, parameters::parse_parameters};
+use super::{content_line_parts::ContentLineCaptures, parameters::parse_parameters, PropertyName};
 
 #[derive(Debug, Hash, PartialEq, Eq)]
 pub enum DateParameter {
@@ -31,7 +31,18 @@ impl FromStr for DateParameter {
     }
 }
 
-impl TryFrom<ContentLineCaptures<'_>> for Vec<chrono::DateTime<Tz>> {
+/// The dates of an `RDATE` or `EXDATE` property.
+#[derive(Debug, PartialEq)]
+pub(crate) struct DateContentLine {
+    pub dates: Vec<chrono::DateTime<Tz>>,
+    /// `true` if the dates were given with `VALUE=DATE`, in which case they are
+    /// midnight in their timezone (UTC if no `TZID` is given).
+    pub date_only: bool,
+    /// The periods of an `RDATE` with `VALUE=PERIOD`.
+    pub periods: Vec<Period>,
+}
+
+impl TryFrom<ContentLineCaptures<'_>> for DateContentLine {
     type Error = ParseError;
 
     fn try_from(value: ContentLineCaptures) -> Result<Self, Self::Error> {
@@ -41,49 +52,63 @@ impl TryFrom<ContentLineCaptures<'_>> for Vec<chrono::DateTime<Tz>> {
             .transpose()?
             .unwrap_or_default();
 
-        match parameters
-            .get(&DateParameter::Value)
-            .map(|val| val.to_ascii_lowercase())
-            .as_deref()
-        {
-            Some("date") => {
-                warn!(
-                    "Parameter `DATE` is not supported for property name: `{}`. The dates will be interpreter with the `DATE-TIME` parameter instead.",
-                    value.property_name
-                );
-            }
-            Some("period") => {
-                warn!(
-                    "Parameter `PERIOD` is not supported for property name: `{}`. The dates will be interpreter with the `DATE-TIME` parameter instead.",
-                    value.property_name
-                );
-            }
-            Some("date-time") => {}
+        let value_in_parameter = parameters.get(&DateParameter::Value);
+        let value_in_parameter_lowercase = value_in_parameter.map(|val| val.to_ascii_lowercase());
+        let is_period = value_in_parameter_lowercase.as_deref() == Some("period");
+        if is_period && value.property_name != PropertyName::RDate {
+            return Err(ParseError::UnsupportedValueType {
+                property: value.property_name.to_string(),
+                value: "PERIOD".into(),
+            });
+        }
+        let date_only = match value_in_parameter_lowercase.as_deref() {
+            Some("date") => true,
+            Some("date-time" | "period") | None => false,
             Some(param) => {
                 warn!(
                     "Encountered unexpected parameter `{param}` for property name: `{}`",
                     value.property_name
                 );
+                false
             }
-            None => {}
-        }
+        };
 
-        let timezone = parameters
+        let mut timezone = parameters
             .get(&DateParameter::Timezone)
             .map(|tz| parse_timezone(tz))
             .transpose()?;
+        if date_only && timezone.is_none() {
+            // Dates don't have a timezone, so they shouldn't depend on the local timezone.
+            timezone = Some(Tz::UTC);
+        }
         let property = format!("{}", value.property_name);
 
         let mut dates = vec![];
+        let mut periods = vec![];
         for val in value.value.split(',') {
             if val.is_empty() {
                 continue;
             }
+            if is_period {
+                periods.push(parse_period(val, timezone, &property)?);
+                continue;
+            }
+            if date_only && val.len() > 8 {
+                return Err(ParseError::ParameterValueMismatch {
+                    parameter: "VALUE".into(),
+                    parameter_value: value_in_parameter.cloned().unwrap_or_default(),
+                    found_value: "DATE-TIME".into(),
+                });
+            }
             let datetime = datestring_to_date(val, timezone, &property)?;
             dates.push(datetime);
         }
 
-        Ok(dates)
+        Ok(Self {
+            dates,
+            date_only,
+            periods,
+        })
     }
 }
 
@@ -107,6 +132,7 @@ mod tests {
                     value: "19970714T123000Z",
                 },
                 vec![UTC.with_ymd_and_hms(1997, 7, 14, 12, 30, 0).unwrap()],
+                false,
             ),
             (
                 ContentLineCaptures {
@@ -115,6 +141,7 @@ mod tests {
                     value: "19970714T123000",
                 },
                 vec![Tz::LOCAL.with_ymd_and_hms(1997, 7, 14, 12, 30, 0).unwrap()],
+                false,
             ),
             (
                 ContentLineCaptures {
@@ -128,12 +155,88 @@ mod tests {
                     UTC.with_ymd_and_hms(1997, 2, 17, 0, 0, 0).unwrap(),
                     UTC.with_ymd_and_hms(1997, 4, 21, 0, 0, 0).unwrap(),
                 ],
+                true,
+            ),
+            (
+                ContentLineCaptures {
+                    property_name: PropertyName::ExDate,
+                    parameters: Some("VALUE=DATE"),
+                    value: "19970101",
+                },
+                vec![UTC.with_ymd_and_hms(1997, 1, 1, 0, 0, 0).unwrap()],
+                true,
             ),
         ];
 
-        for (input, expected_output) in tests {
-            let output = TryFrom::try_from(input);
-            assert_eq!(output, Ok(expected_output));
+        for (input, dates, date_only) in tests {
+            let output = DateContentLine::try_from(input);
+            assert_eq!(
+                output,
+                Ok(DateContentLine {
+                    dates,
+                    date_only,
+                    periods: vec![]
+                })
+            );
         }
     }
+
+    #[test]
+    fn rejects_date_time_with_date_value() {
+        let content = ContentLineCaptures {
+            property_name: PropertyName::RDate,
+            parameters: Some("VALUE=DATE"),
+            value: "19970101,19970120T090000Z",
+        };
+        assert_eq!(
+            DateContentLine::try_from(content),
+            Err(ParseError::ParameterValueMismatch {
+                parameter: "VALUE".into(),
+                parameter_value: "DATE".into(),
+                found_value: "DATE-TIME".into()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_period_content_line() {
+        let content = ContentLineCaptures {
+            property_name: PropertyName::RDate,
+            parameters: Some("VALUE=PERIOD"),
+            value: "19960403T020000Z/19960403T040000Z,19960404T010000Z/PT3H",
+        };
+        assert_eq!(
+            DateContentLine::try_from(content),
+            Ok(DateContentLine {
+                dates: vec![],
+                date_only: false,
+                periods: vec![
+                    Period::new(
+                        UTC.with_ymd_and_hms(1996, 4, 3, 2, 0, 0).unwrap(),
+                        UTC.with_ymd_and_hms(1996, 4, 3, 4, 0, 0).unwrap()
+                    ),
+                    Period::with_duration(
+                        UTC.with_ymd_and_hms(1996, 4, 4, 1, 0, 0).unwrap(),
+                        "PT3H".parse().unwrap()
+                    ),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_period_in_exdate() {
+        let content = ContentLineCaptures {
+            property_name: PropertyName::ExDate,
+            parameters: Some("VALUE=PERIOD"),
+            value: "19960403T020000Z/19960403T040000Z",
+        };
+        assert_eq!(
+            DateContentLine::try_from(content),
+            Err(ParseError::UnsupportedValueType {
+                property: "EXDATE".into(),
+                value: "PERIOD".into()
+            })
+        );
+    }
 }
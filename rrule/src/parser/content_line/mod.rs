@@ -7,11 +7,14 @@ mod start_date_content_line;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::ICalDuration;
 use crate::RRule;
-use crate::Tz;
 use crate::Unvalidated;
 
 pub(crate) use content_line_parts::ContentLineCaptures;
+pub(crate) use date_content_line::DateContentLine;
+#[cfg(any(feature = "serde", feature = "jcal", feature = "xcal"))]
+pub(crate) use rule_content_line::{props_to_rrule, RRuleProperty};
 pub(crate) use start_date_content_line::StartDateContentLine;
 
 use super::ParseError;
@@ -20,8 +23,10 @@ use super::ParseError;
 pub(crate) enum ContentLine {
     RRule(RRule<Unvalidated>),
     ExRule(RRule<Unvalidated>),
-    ExDate(Vec<chrono::DateTime<Tz>>),
-    RDate(Vec<chrono::DateTime<Tz>>),
+    ExDate(DateContentLine),
+    RDate(DateContentLine),
+    DtEnd(StartDateContentLine),
+    Duration(ICalDuration),
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -31,6 +36,8 @@ pub(crate) enum PropertyName {
     ExDate,
     RDate,
     DtStart,
+    DtEnd,
+    Duration,
 }
 
 impl Display for PropertyName {
@@ -41,6 +48,8 @@ impl Display for PropertyName {
             Self::ExDate => write!(f, "EXDATE"),
             Self::RDate => write!(f, "RDATE"),
             Self::DtStart => write!(f, "DTSTART"),
+            Self::DtEnd => write!(f, "DTEND"),
+            Self::Duration => write!(f, "DURATION"),
         }
     }
 }
@@ -55,6 +64,8 @@ impl FromStr for PropertyName {
             "RDATE" => Self::RDate,
             "EXDATE" => Self::ExDate,
             "DTSTART" => Self::DtStart,
+            "DTEND" => Self::DtEnd,
+            "DURATION" => Self::Duration,
             _ => return Err(ParseError::UnrecognizedPropertyName(s.into())),
         };
         Ok(name)
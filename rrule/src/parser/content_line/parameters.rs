@@ -1,14 +1,18 @@
 use std::{collections::HashMap, hash::Hash, str::FromStr};
 
-use crate::parser::ParseError;
+use crate::parser::{
+    utils::{split_unquoted, unquote},
+    ParseError,
+};
 
 /// Parses a string of semicolon seperated key value pairs into a `HashMap` with
 /// predefined keys. It will return an error if duplicate keys are found.
+/// Values may be quoted, in which case the quotes are removed.
 pub(super) fn parse_parameters<K: FromStr<Err = ParseError> + Hash + Eq>(
     raw_parameters: &str,
 ) -> Result<HashMap<K, String>, ParseError> {
     let mut parameters = HashMap::new();
-    for raw_parameter in raw_parameters.split(';') {
+    for raw_parameter in split_unquoted(raw_parameters, ';') {
         if raw_parameter.is_empty() {
             continue;
         }
@@ -17,7 +21,10 @@ pub(super) fn parse_parameters<K: FromStr<Err = ParseError> + Hash + Eq>(
             .ok_or_else(|| ParseError::InvalidParameterFormat(raw_parameter.into()))?;
         let parameter = K::from_str(raw_parameter)?;
 
-        if parameters.insert(parameter, value.into()).is_some() {
+        if parameters
+            .insert(parameter, unquote(value).into())
+            .is_some()
+        {
             return Err(ParseError::DuplicateProperty(raw_parameter.into()));
         }
     }
@@ -45,6 +52,15 @@ mod tests {
                     .into_iter()
                     .collect::<HashMap<_, _>>(),
             ),
+            (
+                "TZID=\"Europe/London\";VALUE=DATE-TIME",
+                [
+                    (DateParameter::Timezone, "Europe/London".to_string()),
+                    (DateParameter::Value, "DATE-TIME".to_string()),
+                ]
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+            ),
         ];
 
         for (input, expected_output) in tests {
@@ -16,7 +16,7 @@ use crate::{
 use super::content_line_parts::ContentLineCaptures;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-pub enum RRuleProperty {
+pub(crate) enum RRuleProperty {
     Freq,
     Until,
     Count,
@@ -82,7 +82,7 @@ impl TryFrom<ContentLineCaptures<'_>> for RRule<Unvalidated> {
 
 /// Takes a map of [`RRuleProperty`] and returns an [`RRule`].
 #[allow(clippy::too_many_lines)]
-fn props_to_rrule(
+pub(crate) fn props_to_rrule(
     props: &HashMap<RRuleProperty, String>,
 ) -> Result<RRule<Unvalidated>, ParseError> {
     let freq = props
@@ -32,20 +32,23 @@ impl TryFrom<&ContentLineCaptures<'_>> for StartDateContentLine {
             .transpose()?
             .unwrap_or_default();
 
-        let mut timezone = parameters
-            .get(&DateParameter::Timezone)
-            .map(|tz| parse_timezone(tz))
-            .transpose()?;
-        if timezone.is_none() && content_line.value.to_uppercase().ends_with('Z') {
-            timezone = Some(UTC);
-        }
-
         let value_in_parameter = parameters.get(&DateParameter::Value);
         let value = if content_line.value.len() > 8 {
             "DATE-TIME"
         } else {
             "DATE"
         };
+
+        let mut timezone = parameters
+            .get(&DateParameter::Timezone)
+            .map(|tz| parse_timezone(tz))
+            .transpose()?;
+        // Dates don't have a timezone, so they shouldn't depend on the local timezone.
+        if timezone.is_none()
+            && (content_line.value.to_uppercase().ends_with('Z') || value == "DATE")
+        {
+            timezone = Some(UTC);
+        }
         if let Some(value_in_parameter) = value_in_parameter {
             if value_in_parameter != value {
                 return Err(ParseError::ParameterValueMismatch {
@@ -56,7 +59,8 @@ impl TryFrom<&ContentLineCaptures<'_>> for StartDateContentLine {
             }
         }
 
-        let datetime = datestring_to_date(content_line.value, timezone, "DTSTART")?;
+        let property = content_line.property_name.to_string();
+        let datetime = datestring_to_date(content_line.value, timezone, &property)?;
 
         Ok(Self {
             datetime,
@@ -66,6 +70,13 @@ impl TryFrom<&ContentLineCaptures<'_>> for StartDateContentLine {
     }
 }
 
+impl StartDateContentLine {
+    /// Returns `true` if the start is a date without a time, i.e. `VALUE=DATE`.
+    pub(crate) fn is_date(&self) -> bool {
+        self.value == "DATE"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::TimeZone;
@@ -101,6 +112,18 @@ mod tests {
                     value: "DATE",
                 },
             ),
+            (
+                ContentLineCaptures {
+                    property_name: PropertyName::DtStart,
+                    parameters: Some("VALUE=DATE"),
+                    value: "19970101",
+                },
+                StartDateContentLine {
+                    datetime: UTC.with_ymd_and_hms(1997, 1, 1, 0, 0, 0).unwrap(),
+                    timezone: Some(UTC),
+                    value: "DATE",
+                },
+            ),
             (
                 ContentLineCaptures {
                     property_name: PropertyName::DtStart,
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use super::{regex::ParsedDateString, ParseError};
-use crate::{core::Tz, NWeekday};
+use crate::{core::Tz, NWeekday, Period};
 use chrono::{NaiveDate, TimeZone, Weekday};
 
 /// Attempts to convert a `str` to a `chrono_tz::Tz`.
@@ -113,7 +113,47 @@ pub(crate) fn datestring_to_date(
     Ok(datetime)
 }
 
+/// Convert a period string like `19970101T180000Z/PT5H30M` or
+/// `19970101T180000Z/19970102T070000Z` and a timezone to a [`Period`].
+pub(crate) fn parse_period(
+    val: &str,
+    tz: Option<Tz>,
+    property: &str,
+) -> Result<Period, ParseError> {
+    let (start, end) = val
+        .split_once('/')
+        .ok_or_else(|| ParseError::InvalidPeriod(val.into()))?;
+    // The start of a period is always a date-time.
+    if start.len() <= 8 {
+        return Err(ParseError::InvalidPeriod(val.into()));
+    }
+    let start = datestring_to_date(start, tz, property)?;
+
+    let period = if end.starts_with(['P', '+', '-']) {
+        let duration = super::parse_duration(end)?;
+        if duration.negative {
+            return Err(ParseError::InvalidPeriod(val.into()));
+        }
+        Period::with_duration(start, duration)
+    } else {
+        Period::new(start, datestring_to_date(end, tz, property)?)
+    };
+
+    if period.get_end().map_or(true, |end| end < start) {
+        return Err(ParseError::InvalidPeriod(val.into()));
+    }
+
+    Ok(period)
+}
+
 /// Attempts to convert a `str` to a `Weekday`.
+/// Converts a date or date-time in the extended format of jCal and xCal, like
+/// `2024-01-01T09:00:00Z`, to the basic format of iCalendar, like `20240101T090000Z`.
+#[cfg(any(feature = "jcal", feature = "xcal"))]
+pub(crate) fn extended_to_basic_format(value: &str) -> String {
+    value.replace(['-', ':'], "")
+}
+
 pub(crate) fn str_to_weekday(d: &str) -> Result<Weekday, ParseError> {
     let day = match &d.to_uppercase()[..] {
         "MO" => Weekday::Mon,
@@ -258,4 +298,43 @@ mod tests {
             assert!(res.is_err());
         }
     }
+
+    #[test]
+    fn parses_valid_periods() {
+        let start = Tz::UTC.with_ymd_and_hms(1997, 1, 1, 18, 0, 0).unwrap();
+        let tests = [
+            (
+                "19970101T180000Z/19970102T070000Z",
+                Period::new(
+                    start,
+                    Tz::UTC.with_ymd_and_hms(1997, 1, 2, 7, 0, 0).unwrap(),
+                ),
+            ),
+            (
+                "19970101T180000Z/PT5H30M",
+                Period::with_duration(start, "PT5H30M".parse().unwrap()),
+            ),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(parse_period(input, None, "RDATE"), Ok(expected_output));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_periods() {
+        let tests = [
+            "19970101T180000Z",
+            "19970101/P1D",
+            "19970101T180000Z/-PT1H",
+            "19970101T180000Z/19970101T170000Z",
+            "19970101T180000Z/P1H",
+            "19970101T180000Z/P99999999D",
+            "19970101T180000Z/PT4294967295H",
+        ];
+
+        for input in tests {
+            assert!(parse_period(input, None, "RDATE").is_err());
+        }
+    }
 }
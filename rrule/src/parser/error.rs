@@ -63,7 +63,7 @@ pub enum ParseError {
     #[error("Property parameters aren't supported for RRULE / EXRULE, found parameters: `{0}`")]
     PropertyParametersNotSupported(String),
     #[error(
-        "`{0}` is not a valid property name, expected one of: `RRULE,EXRULE,DTSTART,RDATE,EXDATE`"
+        "`{0}` is not a valid property name, expected one of: `RRULE,EXRULE,DTSTART,DTEND,DURATION,RDATE,EXDATE`"
     )]
     UnrecognizedPropertyName(String),
     #[error(
@@ -74,6 +74,40 @@ pub enum ParseError {
         "The value of `DTSTART` was specified in local timezone, but `UNTIL` was specified with a zulu time when it had to be specified in local time as well"
     )]
     DtStartUntilMismatchTimezone,
+    #[error("`{0}` is not a valid content line. Expected a line in the format `NAME[;PARAMETERS]:VALUE`.")]
+    InvalidContentLine(String),
+    #[error("Found `END:{found}`, but the component that is currently open is `{expected}`.")]
+    MismatchedComponentEnd { expected: String, found: String },
+    #[error("Found `END:{0}` without a matching `BEGIN:{0}`.")]
+    UnexpectedComponentEnd(String),
+    #[error("The component `{0}` was opened with `BEGIN`, but never closed with `END`.")]
+    UnclosedComponent(String),
+    #[error("`{0}` is not a valid duration. Expected a duration like `P1D` or `PT1H30M`.")]
+    InvalidDuration(String),
+    #[error("`{0}` is not a valid period. Expected `start/end` or `start/duration` with a start before the end.")]
+    InvalidPeriod(String),
+    #[error("`VALUE={value}` is not supported for `{property}`.")]
+    UnsupportedValueType { property: String, value: String },
+    #[error("`DTEND` and `DURATION` can't both be specified.")]
+    DtEndWithDuration,
+    #[error("`DTEND` needs to be after `DTSTART`.")]
+    DtEndBeforeDtStart,
+    #[error("`DURATION` can't be negative.")]
+    NegativeDuration,
+    #[error("`{0}` is not a valid iterator cursor.")]
+    InvalidCursor(String),
+    #[error("`{token}` at position {position} is not a valid part of a recurrence.")]
+    InvalidRecurrenceText { token: String, position: usize },
+    #[error("`{0}` ends before the recurrence is complete. Expected a frequency like `every week`, followed by its parts.")]
+    IncompleteRecurrenceText(String),
+    #[error("`{0}` is not a valid jCal property. Expected an array like `[\"rdate\", {{}}, \"date-time\", \"2024-01-01T09:00:00Z\"]`.")]
+    InvalidJCalProperty(String),
+    #[error("`{0}` is not a valid jCal value.")]
+    InvalidJCalValue(String),
+    #[error("The xCal document is not valid XML: {0}.")]
+    InvalidXml(String),
+    #[error("`<{0}>` is not a valid xCal property.")]
+    InvalidXCalProperty(String),
     #[error("Property parameter `{parameter}` was set to have value `{parameter_value}`, but found `{found_value}` ")]
     ParameterValueMismatch {
         parameter: String,
@@ -1,20 +1,38 @@
 //! Module for parsing text inputs to a [`Grammar`] which can further be used
 //! to construct an [`crate::RRuleSet`].
+mod component;
 mod content_line;
 mod datetime;
 mod error;
+mod phrase;
 mod regex;
 mod utils;
+#[cfg(feature = "xcal")]
+mod xml;
 
 use std::str::FromStr;
 
-pub(crate) use content_line::{ContentLine, ContentLineCaptures};
+pub(crate) use component::{parse_components, ComponentGrammar};
+#[cfg(any(feature = "serde", feature = "jcal", feature = "xcal"))]
+pub(crate) use content_line::{props_to_rrule, RRuleProperty};
+pub(crate) use content_line::{
+    ContentLine, ContentLineCaptures, DateContentLine, PropertyName, StartDateContentLine,
+};
+#[cfg(any(feature = "jcal", feature = "xcal"))]
+pub(crate) use datetime::extended_to_basic_format;
+#[cfg(feature = "serde")]
+pub(crate) use datetime::parse_timezone;
 pub(crate) use datetime::str_to_weekday;
 pub use error::ParseError;
+pub(crate) use phrase::parse_phrase;
+pub(crate) use regex::parse_duration;
+pub(crate) use utils::{fold_content_line, split_unquoted, unescape_text, unquote};
+#[cfg(feature = "xcal")]
+pub(crate) use xml::{escape as escape_xml, XmlElement};
 
 use crate::RRule;
 
-use self::content_line::{PropertyName, StartDateContentLine};
+use self::regex::unfold_content_lines;
 
 /// Grammar represents a well-formatted rrule input.
 #[derive(Debug, PartialEq)]
@@ -23,15 +41,40 @@ pub(crate) struct Grammar {
     pub content_lines: Vec<ContentLine>,
 }
 
+impl Grammar {
+    /// Returns `true` if there is at least one `RRULE` or `RDATE` to generate occurrences from.
+    pub(crate) fn has_date_generation_rules(&self) -> bool {
+        self.content_lines
+            .iter()
+            .any(|line| matches!(line, ContentLine::RRule(_) | ContentLine::RDate(_)))
+    }
+}
+
 impl FromStr for Grammar {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = unfold_content_lines(s);
         let content_lines_parts = s
             .lines()
             .map(ContentLineCaptures::new)
             .collect::<Result<Vec<_>, _>>()?;
 
+        let grammar = Self::try_from(content_lines_parts)?;
+
+        // Need to be at least one `RDATE` or `RRULE`
+        if !grammar.has_date_generation_rules() {
+            return Err(ParseError::MissingDateGenerationRules);
+        }
+
+        Ok(grammar)
+    }
+}
+
+impl TryFrom<Vec<ContentLineCaptures<'_>>> for Grammar {
+    type Error = ParseError;
+
+    fn try_from(content_lines_parts: Vec<ContentLineCaptures<'_>>) -> Result<Self, Self::Error> {
         let start = content_lines_parts
             .iter()
             .find(|parts| matches!(parts.property_name, PropertyName::DtStart))
@@ -52,6 +95,8 @@ impl FromStr for Grammar {
                 }
                 PropertyName::RDate => ContentLine::RDate(TryFrom::try_from(parts)?),
                 PropertyName::ExDate => ContentLine::ExDate(TryFrom::try_from(parts)?),
+                PropertyName::DtEnd => ContentLine::DtEnd(StartDateContentLine::try_from(&parts)?),
+                PropertyName::Duration => ContentLine::Duration(parse_duration(parts.value)?),
                 PropertyName::DtStart => {
                     // Nothing to do
                     continue;
@@ -60,12 +105,22 @@ impl FromStr for Grammar {
             content_lines.push(line);
         }
 
-        // Need to be at least one `RDATE` or `RRULE`
-        if !content_lines
+        let ends = content_lines
             .iter()
-            .any(|line| matches!(line, ContentLine::RRule(_) | ContentLine::RDate(_)))
-        {
-            return Err(ParseError::MissingDateGenerationRules);
+            .filter(|line| matches!(line, ContentLine::DtEnd(_)))
+            .count();
+        let durations = content_lines
+            .iter()
+            .filter(|line| matches!(line, ContentLine::Duration(_)))
+            .count();
+        if ends > 0 && durations > 0 {
+            return Err(ParseError::DtEndWithDuration);
+        }
+        if ends > 1 {
+            return Err(ParseError::DuplicateProperty("DTEND".into()));
+        }
+        if durations > 1 {
+            return Err(ParseError::DuplicateProperty("DURATION".into()));
         }
 
         Ok(Self {
@@ -80,7 +135,11 @@ mod test {
     use chrono::{TimeZone, Weekday};
 
     use super::*;
-    use crate::{core::Tz, parser::content_line::ContentLine, Frequency, NWeekday, RRule};
+    use crate::{
+        core::Tz,
+        parser::content_line::{ContentLine, DateContentLine},
+        Frequency, NWeekday, RRule,
+    };
 
     const UTC: Tz = Tz::UTC;
     const BERLIN: Tz = Tz::Europe__Berlin;
@@ -120,10 +179,10 @@ mod test {
             count: Some(5),
             ..Default::default()
         }),
-        ContentLine::ExDate(vec![
+        ContentLine::ExDate(DateContentLine { dates: vec![
             BERLIN.with_ymd_and_hms(2012, 2, 2,13, 0, 0).unwrap(),
             BERLIN.with_ymd_and_hms(2012, 2, 3,13, 0, 0).unwrap(),
-        ])
+        ], date_only: false, periods: vec![] })
     ]
 }),
 ("DTSTART:20120201T120000Z\nRRULE:FREQ=DAILY;COUNT=5\nEXDATE;TZID=Europe/Berlin:20120202T130000,20120203T130000\nEXRULE:FREQ=WEEKLY;COUNT=10", Grammar {
@@ -134,10 +193,10 @@ mod test {
             count: Some(5),
             ..Default::default()
         }),
-        ContentLine::ExDate(vec![
+        ContentLine::ExDate(DateContentLine { dates: vec![
             BERLIN.with_ymd_and_hms(2012, 2, 2,13, 0, 0).unwrap(),
             BERLIN.with_ymd_and_hms(2012, 2, 3,13, 0, 0).unwrap(),
-        ]),
+        ], date_only: false, periods: vec![] }),
         ContentLine::ExRule(RRule {
             freq: Frequency::Weekly,
             count: Some(10),
@@ -0,0 +1,698 @@
+//! Parses recurrences described in English, like "every other Tuesday until March 3" or
+//! "the last Friday of each month".
+use super::ParseError;
+use crate::core::start_of_day;
+use crate::{Frequency, NWeekday, RRule, Tz, Unvalidated};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Timelike, Weekday};
+
+const WEEKDAYS: [Weekday; 5] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+];
+
+const WEEKEND: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
+
+/// Parses `text` to an rrule and the start date, if the text has one.
+///
+/// Dates without a year are the next one on or after `reference`, and all dates are in
+/// its timezone.
+pub(crate) fn parse_phrase(
+    text: &str,
+    reference: &DateTime<Tz>,
+) -> Result<(RRule<Unvalidated>, Option<DateTime<Tz>>), ParseError> {
+    PhraseParser::new(text, reference).parse()
+}
+
+/// A word of the text with its position, to point at it in errors.
+#[derive(Debug)]
+struct Token<'a> {
+    /// The word in lowercase without dots, so "A.M." is "am".
+    word: String,
+    text: &'a str,
+    position: usize,
+    /// Whether the word follows a comma, which separates the items of a list.
+    after_comma: bool,
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut start = None;
+    let mut after_comma = false;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        let is_separator = c.is_whitespace() || c == ',';
+        match start {
+            Some(position) if is_separator => {
+                let text = &text[position..i];
+                let word = text.to_lowercase().replace('.', "");
+                if !word.is_empty() {
+                    tokens.push(Token {
+                        word,
+                        text,
+                        position,
+                        after_comma,
+                    });
+                    after_comma = false;
+                }
+                start = None;
+            }
+            None if !is_separator => start = Some(i),
+            _ => {}
+        }
+        after_comma |= c == ',';
+    }
+    tokens
+}
+
+struct PhraseParser<'a> {
+    text: &'a str,
+    tokens: Vec<Token<'a>>,
+    next: usize,
+    reference: &'a DateTime<Tz>,
+    freq: Option<Frequency>,
+    rrule: RRule<Unvalidated>,
+    times: Vec<NaiveTime>,
+    start: Option<(NaiveDate, usize)>,
+}
+
+impl<'a> PhraseParser<'a> {
+    fn new(text: &'a str, reference: &'a DateTime<Tz>) -> Self {
+        Self {
+            text,
+            tokens: tokenize(text),
+            next: 0,
+            reference,
+            freq: None,
+            rrule: RRule::default(),
+            times: vec![],
+            start: None,
+        }
+    }
+
+    fn parse(mut self) -> Result<(RRule<Unvalidated>, Option<DateTime<Tz>>), ParseError> {
+        while let Some(word) = self.peek() {
+            match word {
+                "every" | "each" => {
+                    self.check_single_freq()?;
+                    self.next += 1;
+                    self.parse_every()?;
+                }
+                "daily" | "weekly" | "monthly" | "yearly" | "annually" | "hourly" => {
+                    let freq = match word {
+                        "daily" => Frequency::Daily,
+                        "weekly" => Frequency::Weekly,
+                        "monthly" => Frequency::Monthly,
+                        "hourly" => Frequency::Hourly,
+                        _ => Frequency::Yearly,
+                    };
+                    self.check_single_freq()?;
+                    self.freq = Some(freq);
+                    self.next += 1;
+                }
+                "on" => {
+                    self.next += 1;
+                    self.parse_days()?;
+                }
+                "the" => self.parse_days()?,
+                "in" => {
+                    self.next += 1;
+                    self.parse_months()?;
+                }
+                "of" => {
+                    self.next += 1;
+                    if !matches!(self.peek(), Some("every" | "each")) {
+                        self.parse_months()?;
+                    }
+                }
+                "at" => {
+                    self.next += 1;
+                    self.parse_times()?;
+                }
+                "until" | "till" | "through" => {
+                    self.next += 1;
+                    let position = self.next;
+                    let next_day = self
+                        .parse_date()?
+                        .succ_opt()
+                        .ok_or_else(|| self.error_at(position))?;
+                    let end_of_day =
+                        start_of_day(next_day, self.reference.timezone()) - Duration::seconds(1);
+                    self.rrule.until = Some(end_of_day.with_timezone(&Tz::UTC));
+                }
+                "for" => {
+                    self.next += 1;
+                    self.parse_count()?;
+                }
+                "starting" | "from" | "beginning" => {
+                    self.next += 1;
+                    self.eat("on");
+                    let position = self.next;
+                    self.start = Some((self.parse_date()?, position));
+                }
+                _ if parse_number(word).is_some() => self.parse_count()?,
+                _ => return Err(self.error()),
+            }
+        }
+
+        self.rrule.freq = self
+            .freq
+            .ok_or_else(|| ParseError::IncompleteRecurrenceText(self.text.into()))?;
+        let dt_start = self.start_date()?;
+        Ok((self.rrule, dt_start))
+    }
+
+    /// Parses the frequency after "every", like "other week", "3 days" or "Tuesday".
+    fn parse_every(&mut self) -> Result<(), ParseError> {
+        let interval_position = self.next;
+        if let Some(interval) = self.parse_interval() {
+            self.rrule.interval = u16::try_from(interval)
+                .ok()
+                .filter(|interval| *interval > 0)
+                .ok_or_else(|| self.error_at(interval_position))?;
+        }
+
+        let word = self.peek().ok_or_else(|| self.error())?;
+        if let Some(freq) = unit(word) {
+            self.freq = Some(freq);
+            self.next += 1;
+        } else if weekdays(word).is_some() {
+            self.freq = Some(Frequency::Weekly);
+            self.parse_weekdays()?;
+        } else {
+            return Err(self.error());
+        }
+        Ok(())
+    }
+
+    /// Parses the interval of "every", which is followed by a unit or a weekday.
+    fn parse_interval(&mut self) -> Option<u32> {
+        let word = self.peek()?;
+        let interval = match word {
+            "other" => 2,
+            _ => parse_number(word).or_else(|| {
+                // Like "every 3rd day", but "every second" is a frequency.
+                let next = self.peek_at(1)?;
+                let n = parse_ordinal(word)?;
+                (unit(next).is_some() || weekdays(next).is_some())
+                    .then_some(n)
+                    .and_then(|n| u32::try_from(n).ok())
+            })?,
+        };
+        self.next += 1;
+        Some(interval)
+    }
+
+    /// Parses a list of weekdays, like "Monday, Wednesday and Friday" or "weekdays".
+    fn parse_weekdays(&mut self) -> Result<(), ParseError> {
+        loop {
+            let days = self.peek().and_then(weekdays).ok_or_else(|| self.error())?;
+            self.next += 1;
+            self.rrule
+                .by_weekday
+                .extend(days.into_iter().map(NWeekday::Every));
+            if !self.next_in_list(|word| weekdays(word).is_some()) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Parses a list of days, like "the last Friday", "the 1st and 15th" or "Monday".
+    fn parse_days(&mut self) -> Result<(), ParseError> {
+        // A number in a list is the count, like in "on the 1st, 5 times".
+        let is_day = |word: &str| {
+            word == "the"
+                || weekdays(word).is_some()
+                || parse_ordinal(word).is_some()
+                || word.ends_with("-to-last")
+        };
+        loop {
+            self.eat("the");
+            let position = self.next;
+            let word = self.peek().ok_or_else(|| self.error())?;
+            if let Some(days) = weekdays(word) {
+                self.next += 1;
+                self.rrule
+                    .by_weekday
+                    .extend(days.into_iter().map(NWeekday::Every));
+            } else {
+                let n = self.parse_position()?;
+                let is_year_day = (0..4)
+                    .map(|offset| self.peek_at(offset))
+                    .eq(["day", "of", "the", "year"].map(Some));
+                if let Some(weekday) = self.peek().and_then(weekday) {
+                    let n = i16::try_from(n).map_err(|_| self.error_at(position))?;
+                    self.next += 1;
+                    self.rrule.by_weekday.push(NWeekday::Nth(n, weekday));
+                } else if is_year_day {
+                    let n = i16::try_from(n).map_err(|_| self.error_at(position))?;
+                    self.next += 4;
+                    self.rrule.by_year_day.push(n);
+                } else {
+                    self.eat("day");
+                    let n = i8::try_from(n).map_err(|_| self.error_at(position))?;
+                    self.rrule.by_month_day.push(n);
+                }
+            }
+            if !self.next_in_list(is_day) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Parses the position of a day, like "2nd", "last", "second to last" or "15".
+    fn parse_position(&mut self) -> Result<i32, ParseError> {
+        let word = self.peek().ok_or_else(|| self.error())?;
+        if let Some(n) = word.strip_suffix("-to-last").and_then(parse_ordinal) {
+            self.next += 1;
+            return Ok(-n);
+        }
+        let n = parse_ordinal(word)
+            .or_else(|| parse_number(word).and_then(|n| i32::try_from(n).ok()))
+            .ok_or_else(|| self.error())?;
+        self.next += 1;
+        if n > 0 && self.peek() == Some("to") && self.peek_at(1) == Some("last") {
+            self.next += 2;
+            return Ok(-n);
+        }
+        Ok(n)
+    }
+
+    /// Parses a list of months, like "January and July".
+    fn parse_months(&mut self) -> Result<(), ParseError> {
+        loop {
+            let number = self.peek().and_then(month).ok_or_else(|| self.error())?;
+            self.next += 1;
+            self.rrule.by_month.push(number);
+            if !self.next_in_list(|word| month(word).is_some()) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Parses a list of times, like "9am and 5:30pm", which needs to be a combination
+    /// of hours, minutes and seconds.
+    fn parse_times(&mut self) -> Result<(), ParseError> {
+        let at = self.next - 1;
+        loop {
+            let time = self.parse_time()?;
+            self.times.push(time);
+            if !self.next_in_list(|word| parse_time(word).is_some()) {
+                break;
+            }
+        }
+
+        let mut hours = self
+            .times
+            .iter()
+            .map(|time| time.hour())
+            .collect::<Vec<_>>();
+        let mut minutes = self
+            .times
+            .iter()
+            .map(|time| time.minute())
+            .collect::<Vec<_>>();
+        let mut seconds = self
+            .times
+            .iter()
+            .map(|time| time.second())
+            .collect::<Vec<_>>();
+        for values in [&mut hours, &mut minutes, &mut seconds] {
+            values.sort_unstable();
+            values.dedup();
+        }
+        let mut times = self.times.clone();
+        times.sort_unstable();
+        times.dedup();
+        if times.len() != hours.len() * minutes.len() * seconds.len() {
+            // Like "9am and 5:30pm", which would be every combination of 9, 17, 0 and 30.
+            return Err(self.error_at(at));
+        }
+
+        let to_u8 = |values: Vec<u32>| values.into_iter().filter_map(|v| u8::try_from(v).ok());
+        self.rrule.by_hour = to_u8(hours).collect();
+        self.rrule.by_minute = to_u8(minutes).collect();
+        self.rrule.by_second = to_u8(seconds).collect();
+        Ok(())
+    }
+
+    /// Parses a time, like "9am", "9:30 pm", "17:00" or "noon".
+    fn parse_time(&mut self) -> Result<NaiveTime, ParseError> {
+        let word = self.peek().ok_or_else(|| self.error())?;
+        let time = parse_time(word).ok_or_else(|| self.error())?;
+        let is_12_hour = ["am", "pm"].iter().any(|suffix| word.ends_with(suffix));
+        let position = self.next;
+        self.next += 1;
+        match self.peek() {
+            Some(suffix @ ("am" | "pm")) if !is_12_hour => {
+                let is_pm = suffix == "pm";
+                self.next += 1;
+                to_24_hour(time, is_pm).ok_or_else(|| self.error_at(position))
+            }
+            Some("o'clock") => {
+                self.next += 1;
+                Ok(time)
+            }
+            _ => Ok(time),
+        }
+    }
+
+    /// Parses a date, like "March 3", "3rd of March 2024" or "2024-03-03".
+    fn parse_date(&mut self) -> Result<NaiveDate, ParseError> {
+        let position = self.next;
+        let word = self.peek().ok_or_else(|| self.error())?;
+        if let Ok(date) = NaiveDate::parse_from_str(word, "%Y-%m-%d") {
+            self.next += 1;
+            return Ok(date);
+        }
+
+        let (month, day) = if let Some(month) = month(word) {
+            self.next += 1;
+            let day = self.parse_day_of_month()?;
+            (month, day)
+        } else {
+            let day = self.parse_day_of_month()?;
+            self.eat("of");
+            let month = self.peek().and_then(month).ok_or_else(|| self.error())?;
+            self.next += 1;
+            (month, day)
+        };
+
+        let year = self
+            .peek()
+            .filter(|word| word.len() == 4)
+            .and_then(|word| word.parse::<i32>().ok());
+        if let Some(year) = year {
+            self.next += 1;
+            return NaiveDate::from_ymd_opt(year, month.into(), day)
+                .ok_or_else(|| self.error_at(position));
+        }
+
+        // The next date from the reference, which can be a few years away for February 29.
+        let reference = self.reference.date_naive();
+        (reference.year()..=reference.year() + 8)
+            .filter_map(|year| NaiveDate::from_ymd_opt(year, month.into(), day))
+            .find(|date| *date >= reference)
+            .ok_or_else(|| self.error_at(position))
+    }
+
+    fn parse_day_of_month(&mut self) -> Result<u32, ParseError> {
+        let word = self.peek().ok_or_else(|| self.error())?;
+        let day = parse_number(word)
+            .or_else(|| parse_ordinal(word).and_then(|day| u32::try_from(day).ok()))
+            .ok_or_else(|| self.error())?;
+        self.next += 1;
+        Ok(day)
+    }
+
+    /// Parses the count, like "10 times" or "5 occurrences".
+    fn parse_count(&mut self) -> Result<(), ParseError> {
+        let position = self.next;
+        let count = self
+            .peek()
+            .and_then(parse_number)
+            .filter(|count| *count > 0)
+            .ok_or_else(|| self.error())?;
+        self.next += 1;
+        if !matches!(
+            self.peek(),
+            Some("time" | "times" | "occurrence" | "occurrences")
+        ) {
+            return Err(self.error());
+        }
+        self.next += 1;
+        if self.rrule.count.is_some() {
+            return Err(self.error_at(position));
+        }
+        self.rrule.count = Some(count);
+        Ok(())
+    }
+
+    /// Returns the start date at the first time, or at the time of the reference.
+    fn start_date(&self) -> Result<Option<DateTime<Tz>>, ParseError> {
+        let Some((date, position)) = self.start else {
+            return Ok(None);
+        };
+        let time = self
+            .times
+            .iter()
+            .min()
+            .copied()
+            .unwrap_or_else(|| self.reference.time());
+        self.reference
+            .timezone()
+            .from_local_datetime(&date.and_time(time))
+            .earliest()
+            .map(Some)
+            .ok_or_else(|| self.error_at(position))
+    }
+
+    /// Checks that the frequency wasn't given yet, like in "daily every week".
+    fn check_single_freq(&self) -> Result<(), ParseError> {
+        if self.freq.is_some() {
+            return Err(self.error());
+        }
+        Ok(())
+    }
+
+    /// Moves to the next item of a list, which is separated by commas or "and".
+    fn next_in_list(&mut self, is_item: impl Fn(&str) -> bool) -> bool {
+        if self.peek() == Some("and") && self.peek_at(1).is_some_and(&is_item) {
+            self.next += 1;
+            return true;
+        }
+        self.tokens
+            .get(self.next)
+            .is_some_and(|token| token.after_comma && is_item(&token.word))
+    }
+
+    fn eat(&mut self, word: &str) -> bool {
+        let is_next = self.peek() == Some(word);
+        if is_next {
+            self.next += 1;
+        }
+        is_next
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&str> {
+        self.tokens
+            .get(self.next + offset)
+            .map(|token| token.word.as_str())
+    }
+
+    /// Returns the error for the next token, or for the end of the text.
+    fn error(&self) -> ParseError {
+        self.error_at(self.next)
+    }
+
+    fn error_at(&self, index: usize) -> ParseError {
+        match self.tokens.get(index) {
+            Some(token) => ParseError::InvalidRecurrenceText {
+                token: token.text.into(),
+                position: token.position,
+            },
+            None => ParseError::IncompleteRecurrenceText(self.text.into()),
+        }
+    }
+}
+
+fn unit(word: &str) -> Option<Frequency> {
+    let freq = match word.strip_suffix('s').unwrap_or(word) {
+        "year" => Frequency::Yearly,
+        "month" => Frequency::Monthly,
+        "week" => Frequency::Weekly,
+        "day" => Frequency::Daily,
+        "hour" => Frequency::Hourly,
+        "minute" => Frequency::Minutely,
+        "second" => Frequency::Secondly,
+        _ => return None,
+    };
+    Some(freq)
+}
+
+/// Returns the weekday of a name, like "Tuesday", "tue" or "Tuesdays".
+fn weekday(word: &str) -> Option<Weekday> {
+    let weekday = match word.strip_suffix('s').unwrap_or(word) {
+        "monday" | "mon" => Weekday::Mon,
+        "tuesday" | "tue" | "tues" => Weekday::Tue,
+        "wednesday" | "wed" => Weekday::Wed,
+        "thursday" | "thu" | "thur" | "thurs" => Weekday::Thu,
+        "friday" | "fri" => Weekday::Fri,
+        "saturday" | "sat" => Weekday::Sat,
+        "sunday" | "sun" => Weekday::Sun,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+/// Returns the weekdays of a weekday name, or of "weekday" and "weekend".
+fn weekdays(word: &str) -> Option<Vec<Weekday>> {
+    match word.strip_suffix('s').unwrap_or(word) {
+        "weekday" => Some(WEEKDAYS.to_vec()),
+        "weekend" => Some(WEEKEND.to_vec()),
+        _ => weekday(word).map(|weekday| vec![weekday]),
+    }
+}
+
+/// Returns the month number of a name, like "March" or "mar".
+fn month(word: &str) -> Option<u8> {
+    let month = match word {
+        "january" | "jan" => 1,
+        "february" | "feb" => 2,
+        "march" | "mar" => 3,
+        "april" | "apr" => 4,
+        "may" => 5,
+        "june" | "jun" => 6,
+        "july" | "jul" => 7,
+        "august" | "aug" => 8,
+        "september" | "sep" | "sept" => 9,
+        "october" | "oct" => 10,
+        "november" | "nov" => 11,
+        "december" | "dec" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// Parses a number, like "3" or "three".
+fn parse_number(word: &str) -> Option<u32> {
+    const NUMBERS: [&str; 12] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+        "twelve",
+    ];
+    if word.bytes().all(|b| b.is_ascii_digit()) {
+        return word.parse().ok();
+    }
+    NUMBERS
+        .iter()
+        .position(|number| *number == word)
+        .and_then(|i| u32::try_from(i + 1).ok())
+}
+
+/// Parses an ordinal, like "3rd", "third" or "last".
+fn parse_ordinal(word: &str) -> Option<i32> {
+    const ORDINALS: [&str; 10] = [
+        "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+        "tenth",
+    ];
+    if word == "last" {
+        return Some(-1);
+    }
+    if let Some(i) = ORDINALS.iter().position(|ordinal| *ordinal == word) {
+        return i32::try_from(i + 1).ok();
+    }
+    let number = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok().filter(|n| *n > 0)
+}
+
+/// Parses a time, like "9", "9am", "9:30pm", "17:00:30", "noon" or "midnight".
+fn parse_time(word: &str) -> Option<NaiveTime> {
+    match word {
+        "noon" | "midday" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return NaiveTime::from_hms_opt(0, 0, 0),
+        _ => {}
+    }
+    let (time, is_pm) = match word.strip_suffix("am") {
+        Some(time) => (time, Some(false)),
+        None => match word.strip_suffix("pm") {
+            Some(time) => (time, Some(true)),
+            None => (word, None),
+        },
+    };
+    let mut parts = time.split(':').map(|part| {
+        (!part.is_empty() && part.len() <= 2 && part.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| part.parse::<u32>().ok())
+            .flatten()
+    });
+    let hour = parts.next()??;
+    let minute = parts.next().unwrap_or(Some(0))?;
+    let second = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let time = NaiveTime::from_hms_opt(hour, minute, second)?;
+    match is_pm {
+        Some(is_pm) => to_24_hour(time, is_pm),
+        None => Some(time),
+    }
+}
+
+/// Converts a time on a 12-hour clock, where 12am is midnight and 12pm is noon.
+fn to_24_hour(time: NaiveTime, is_pm: bool) -> Option<NaiveTime> {
+    let hour = match time.hour() {
+        0 | 13.. => return None,
+        12 => 0,
+        hour => hour,
+    };
+    time.with_hour(if is_pm { hour + 12 } else { hour })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenizes_words_with_their_position() {
+        let tokens = tokenize("Every  Mon, Fri at 9 A.M.")
+            .into_iter()
+            .map(|token| (token.word, token.position, token.after_comma))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                ("every".into(), 0, false),
+                ("mon".into(), 7, false),
+                ("fri".into(), 12, true),
+                ("at".into(), 16, false),
+                ("9".into(), 19, false),
+                ("am".into(), 21, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_times() {
+        let time = |h, m, s| NaiveTime::from_hms_opt(h, m, s);
+        let tests = [
+            ("9", time(9, 0, 0)),
+            ("9am", time(9, 0, 0)),
+            ("12am", time(0, 0, 0)),
+            ("12pm", time(12, 0, 0)),
+            ("5:30pm", time(17, 30, 0)),
+            ("17:00:30", time(17, 0, 30)),
+            ("noon", time(12, 0, 0)),
+            ("13pm", None),
+            ("9:3:0:0", None),
+            ("24:00", None),
+        ];
+        for (word, expected) in tests {
+            assert_eq!(parse_time(word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn parses_ordinals() {
+        let tests = [
+            ("1st", Some(1)),
+            ("22nd", Some(22)),
+            ("third", Some(3)),
+            ("last", Some(-1)),
+            ("0th", None),
+            ("th", None),
+            ("second", Some(2)),
+        ];
+        for (word, expected) in tests {
+            assert_eq!(parse_ordinal(word), expected, "{word}");
+        }
+    }
+}
@@ -1,10 +1,11 @@
 //! Utility functions around the regexes we use for parsing rrule strings.
 
-use std::{str::FromStr, sync::OnceLock};
+use std::{borrow::Cow, str::FromStr, sync::OnceLock};
 
 use regex::{Captures, Regex};
 
 use super::{content_line::PropertyName, ParseError};
+use crate::ICalDuration;
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct ParsedDateString {
@@ -86,6 +87,50 @@ impl ParsedDateString {
     }
 }
 
+/// Parses a duration with format `(+/-)P(nW)` or `(+/-)P(nD)(T(nH)(nM)(nS))`, like `PT1H30M`.
+pub(crate) fn parse_duration(val: &str) -> Result<ICalDuration, ParseError> {
+    static DURATION_RE: OnceLock<Regex> = OnceLock::new();
+
+    let captures = DURATION_RE
+        .get_or_init(|| {
+            Regex::new(
+                r"^([+-])?P(?:([0-9]+)W|([0-9]+)D(?:T([0-9]+H)?([0-9]+M)?([0-9]+S)?)?|T([0-9]+H)?([0-9]+M)?([0-9]+S)?)$",
+            )
+            .expect("DURATION_RE must compile")
+        })
+        .captures(val)
+        .ok_or_else(|| ParseError::InvalidDuration(val.into()))?;
+
+    let get_number = |idxs: &[usize]| -> Result<u32, ParseError> {
+        idxs.iter()
+            .find_map(|idx| captures.get(*idx))
+            .map(|part| {
+                part.as_str()
+                    .trim_end_matches(['W', 'D', 'H', 'M', 'S'])
+                    .parse()
+                    .map_err(|_| ParseError::InvalidDuration(val.into()))
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    };
+
+    // A `T` must be followed by at least one time part.
+    let has_time_designator = val.contains('T');
+    let has_time_part = (4..=9).any(|idx| captures.get(idx).is_some());
+    if has_time_designator && !has_time_part {
+        return Err(ParseError::InvalidDuration(val.into()));
+    }
+
+    Ok(ICalDuration {
+        negative: captures.get(1).is_some_and(|sign| sign.as_str() == "-"),
+        weeks: get_number(&[2])?,
+        days: get_number(&[3])?,
+        hours: get_number(&[4, 7])?,
+        minutes: get_number(&[5, 8])?,
+        seconds: get_number(&[6, 9])?,
+    })
+}
+
 /// Get the line property name, the `RRULE:`, `EXRULE:` etc part.
 pub(crate) fn get_property_name(val: &str) -> Result<Option<PropertyName>, ParseError> {
     static PARSE_PROPERTY_NAME_RE: OnceLock<Regex> = OnceLock::new();
@@ -100,9 +145,24 @@ pub(crate) fn get_property_name(val: &str) -> Result<Option<PropertyName>, Parse
         .transpose()
 }
 
+/// Unfolds the content lines as described in
+/// [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1), i.e. removes
+/// every line break which is directly followed by a single space or tab.
+pub(crate) fn unfold_content_lines(val: &str) -> Cow<'_, str> {
+    static UNFOLD_RE: OnceLock<Regex> = OnceLock::new();
+
+    UNFOLD_RE
+        .get_or_init(|| Regex::new(r"\r?\n[ \t]").expect("UNFOLD_RE regex must compile"))
+        .replace_all(val, "")
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::parser::{content_line::PropertyName, regex::get_property_name, ParseError};
+    use crate::parser::{
+        content_line::PropertyName,
+        regex::{get_property_name, parse_duration, unfold_content_lines},
+        ParseError,
+    };
 
     use super::{ParsedDateString, ParsedDateStringFlags, ParsedDateStringTime};
 
@@ -249,4 +309,55 @@ mod tests {
             assert_eq!(output, Err(expected_output));
         }
     }
+
+    #[test]
+    fn unfolds_content_lines() {
+        let tests = [
+            ("RRULE:FREQ=DAILY", "RRULE:FREQ=DAILY"),
+            ("RRULE:FREQ=DA\r\n ILY", "RRULE:FREQ=DAILY"),
+            ("RRULE:FREQ=DA\n\tILY", "RRULE:FREQ=DAILY"),
+            ("RRULE:FREQ=DA\n  ILY", "RRULE:FREQ=DA ILY"),
+            (
+                "DTSTART:20120201T093000Z\r\nRRULE:FREQ=DAILY",
+                "DTSTART:20120201T093000Z\r\nRRULE:FREQ=DAILY",
+            ),
+        ];
+        for (input, expected_output) in tests {
+            assert_eq!(unfold_content_lines(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        let tests = [
+            ("P15DT5H0M20S", (false, 0, 15, 5, 0, 20)),
+            ("P7W", (false, 7, 0, 0, 0, 0)),
+            ("-PT15M", (true, 0, 0, 0, 15, 0)),
+            ("+P1D", (false, 0, 1, 0, 0, 0)),
+            ("PT1H30M", (false, 0, 0, 1, 30, 0)),
+        ];
+        for (input, (negative, weeks, days, hours, minutes, seconds)) in tests {
+            assert_eq!(
+                parse_duration(input),
+                Ok(crate::ICalDuration {
+                    negative,
+                    weeks,
+                    days,
+                    hours,
+                    minutes,
+                    seconds
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        for input in ["", "P", "PT", "P1DT", "P1W2D", "1D", "P1H", "PT1D", "P-1D"] {
+            assert_eq!(
+                parse_duration(input),
+                Err(ParseError::InvalidDuration(input.into()))
+            );
+        }
+    }
 }
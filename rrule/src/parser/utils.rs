@@ -27,9 +27,85 @@ pub(crate) fn parse_str_to_vec<T: FromStr + Ord + PartialEq + Copy, F: Fn(T) ->
     Ok(parsed_vals)
 }
 
+/// Returns the index of the first `separator` which is not part of a quoted string,
+/// like the `:` in `DTSTART;X-NOTE="a:b":20120201T093000`.
+pub(crate) fn find_unquoted(val: &str, separator: char) -> Option<usize> {
+    let mut quoted = false;
+    for (idx, c) in val.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if c == separator && !quoted {
+            return Some(idx);
+        }
+    }
+    None
+}
+
+/// Splits `val` on every `separator` which is not part of a quoted string.
+pub(crate) fn split_unquoted(val: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut rest = val;
+    while let Some(idx) = find_unquoted(rest, separator) {
+        parts.push(&rest[..idx]);
+        rest = &rest[idx + separator.len_utf8()..];
+    }
+    parts.push(rest);
+    parts
+}
+
+/// Removes the surrounding double quotes of a quoted parameter value, like `"Europe/Berlin"`.
+pub(crate) fn unquote(val: &str) -> &str {
+    val.strip_prefix('"')
+        .and_then(|val| val.strip_suffix('"'))
+        .unwrap_or(val)
+}
+
+/// Reverts the escaping of a `TEXT` value as described in
+/// [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.11).
+/// So `\\`, `\;`, `\,` and `\n` become `\`, `;`, `,` and a newline.
+pub(crate) fn unescape_text(val: &str) -> String {
+    let mut unescaped = String::with_capacity(val.len());
+    let mut chars = val.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Maximum length of a content line in octets, excluding the line break.
+const MAX_CONTENT_LINE_OCTETS: usize = 75;
+
+/// Folds a content line into multiple lines of at most 75 octets as described in
+/// [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1).
+/// Every continuation line starts with a single space, and multi-octet
+/// characters are never split.
+pub(crate) fn fold_content_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_CONTENT_LINE_OCTETS * 2);
+    let mut line_octets = 0;
+    for c in line.chars() {
+        if line_octets + c.len_utf8() > MAX_CONTENT_LINE_OCTETS {
+            folded.push_str("\n ");
+            line_octets = 1;
+        }
+        folded.push(c);
+        line_octets += c.len_utf8();
+    }
+    folded
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_str_to_vec;
+    use super::{
+        find_unquoted, fold_content_line, parse_str_to_vec, split_unquoted, unescape_text, unquote,
+    };
 
     #[test]
     fn parses_str_to_vec() {
@@ -51,4 +127,68 @@ mod tests {
             assert_eq!(output, expected_output);
         }
     }
+
+    #[test]
+    fn finds_unquoted_separator() {
+        let tests = [
+            ("DTSTART:20120201T093000", Some(7)),
+            ("DTSTART;X-NOTE=\"a:b\":20120201T093000", Some(20)),
+            ("DTSTART;X-NOTE=\"a:b\"", None),
+        ];
+        for (input, expected_output) in tests {
+            assert_eq!(find_unquoted(input, ':'), expected_output);
+        }
+    }
+
+    #[test]
+    fn splits_on_unquoted_separator() {
+        assert_eq!(
+            split_unquoted("TZID=\"Europe/Berlin\";X-NOTE=\"a;b\";VALUE=DATE", ';'),
+            vec!["TZID=\"Europe/Berlin\"", "X-NOTE=\"a;b\"", "VALUE=DATE"]
+        );
+        assert_eq!(split_unquoted("", ';'), vec![""]);
+    }
+
+    #[test]
+    fn unquotes_parameter_values() {
+        assert_eq!(unquote("\"Europe/Berlin\""), "Europe/Berlin");
+        assert_eq!(unquote("Europe/Berlin"), "Europe/Berlin");
+        assert_eq!(unquote("\""), "\"");
+    }
+
+    #[test]
+    fn unescapes_text() {
+        let tests = [
+            ("Standup", "Standup"),
+            ("Room 1\\, 2nd floor", "Room 1, 2nd floor"),
+            ("a\\;b\\\\c", "a;b\\c"),
+            ("line 1\\nline 2\\Nline 3", "line 1\nline 2\nline 3"),
+            ("trailing\\", "trailing\\"),
+        ];
+        for (input, expected_output) in tests {
+            assert_eq!(unescape_text(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn folds_long_content_lines() {
+        let short = "RRULE:FREQ=DAILY;COUNT=10";
+        assert_eq!(fold_content_line(short), short);
+
+        let long = format!("RDATE:{}", "19970714T123000Z,".repeat(10));
+        let folded = fold_content_line(&long);
+        assert!(folded.lines().all(|line| line.len() <= 75));
+        assert!(folded.lines().skip(1).all(|line| line.starts_with(' ')));
+        assert_eq!(folded.lines().next().map(str::len), Some(75));
+        assert_eq!(folded.replace("\n ", ""), long);
+    }
+
+    #[test]
+    fn does_not_split_characters_when_folding() {
+        let long = format!("SUMMARY:{}", "ü".repeat(40));
+        let folded = fold_content_line(&long);
+        assert!(folded.lines().all(|line| line.len() <= 75));
+        assert_eq!(folded.lines().next().map(str::len), Some(74));
+        assert_eq!(folded.replace("\n ", ""), long);
+    }
 }
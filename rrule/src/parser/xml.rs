@@ -0,0 +1,288 @@
+//! A minimal XML reader and writer for xCal. It only keeps the elements and their text,
+//! which is all xCal needs: attributes, comments and processing instructions are skipped.
+use super::ParseError;
+
+/// How deeply elements can be nested. xCal needs a handful of levels, and the
+/// elements are parsed recursively.
+const MAX_DEPTH: usize = 64;
+
+/// An XML element, with its name without namespace prefix.
+#[derive(Debug, PartialEq, Eq, Default)]
+pub(crate) struct XmlElement {
+    pub name: String,
+    pub children: Vec<Self>,
+    /// The text of the element, without the text of its children.
+    pub text: String,
+}
+
+impl XmlElement {
+    /// Parses an XML document, and returns its root element.
+    pub(crate) fn parse(xml: &str) -> Result<Self, ParseError> {
+        let mut reader = Reader { xml, pos: 0 };
+        reader.skip_misc()?;
+        if reader.rest().starts_with("<!DOCTYPE") {
+            return Err(ParseError::InvalidXml(
+                "document type declarations aren't supported".into(),
+            ));
+        }
+        let root = reader.element(1)?;
+        reader.skip_misc()?;
+        if reader.pos < xml.len() {
+            return Err(reader.error("content after the root element"));
+        }
+        Ok(root)
+    }
+
+    /// Returns the first child element named `name`.
+    pub(crate) fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Returns this element or its first descendant named `name`, depth first.
+    pub(crate) fn find(&self, name: &str) -> Option<&Self> {
+        if self.name == name {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(name))
+    }
+
+    /// Returns the text of the element, without the whitespace around it.
+    pub(crate) fn trimmed_text(&self) -> &str {
+        self.text.trim()
+    }
+}
+
+/// Escapes the characters which can't be in the text of an element.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+struct Reader<'a> {
+    xml: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.pos..]
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError::InvalidXml(format!("{message} at position {}", self.pos))
+    }
+
+    /// Skips past `end`, which has to follow.
+    fn skip_past(&mut self, end: &str) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let idx = rest
+            .find(end)
+            .ok_or_else(|| self.error(&format!("missing `{end}`")))?;
+        self.pos += idx + end.len();
+        Ok(&rest[..idx])
+    }
+
+    /// Skips whitespace, comments and processing instructions, like the XML declaration.
+    fn skip_misc(&mut self) -> Result<(), ParseError> {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if trimmed.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("missing name"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Parses the element which starts at the current position, at `depth` levels
+    /// below the document.
+    fn element(&mut self, depth: usize) -> Result<XmlElement, ParseError> {
+        if !self.rest().starts_with('<') {
+            return Err(self.error("expected an element"));
+        }
+        if depth > MAX_DEPTH {
+            return Err(self.error(&format!(
+                "elements nested more than {MAX_DEPTH} levels deep"
+            )));
+        }
+        self.pos += 1;
+        let qualified_name = self.name()?;
+        let mut element = XmlElement {
+            name: local_name(qualified_name).into(),
+            ..Default::default()
+        };
+
+        // Attributes are skipped, as xCal doesn't use them.
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("/>") {
+                self.pos += 2;
+                return Ok(element);
+            }
+            if trimmed.starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+            self.name()?;
+            if !self.rest().trim_start().starts_with('=') {
+                return Err(self.error("missing attribute value"));
+            }
+            let rest = self.rest().trim_start()[1..].trim_start();
+            self.pos = self.xml.len() - rest.len();
+            let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+                return Err(self.error("unquoted attribute value"));
+            };
+            self.pos += 1;
+            self.skip_past(&quote.to_string())?;
+        }
+
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.pos += 2;
+                let end_name = self.name()?;
+                if end_name != qualified_name {
+                    return Err(self.error(&format!(
+                        "`</{end_name}>` doesn't close `<{qualified_name}>`"
+                    )));
+                }
+                self.skip_past(">")?;
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                element.text.push_str(self.skip_past("]]>")?);
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                element.children.push(self.element(depth + 1)?);
+            } else if rest.is_empty() {
+                return Err(self.error(&format!("`<{qualified_name}>` isn't closed")));
+            } else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                let text = unescape(&rest[..len]).ok_or_else(|| self.error("invalid entity"))?;
+                element.text.push_str(&text);
+                self.pos += len;
+            }
+        }
+    }
+}
+
+/// Returns the name without its namespace prefix, like `rrule` for `xcal:rrule`.
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Replaces the predefined entities and character references.
+fn unescape(text: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(idx) = rest.find('&') {
+        unescaped.push_str(&rest[..idx]);
+        let end = rest[idx..].find(';')? + idx;
+        let entity = &rest[idx + 1..end];
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = match entity.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => entity.strip_prefix('#')?.parse().ok()?,
+                };
+                char::from_u32(code)?
+            }
+        };
+        unescaped.push(c);
+        rest = &rest[end + 1..];
+    }
+    unescaped.push_str(rest);
+    Some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(name: &str, text: &str, children: Vec<XmlElement>) -> XmlElement {
+        XmlElement {
+            name: name.into(),
+            children,
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn parses_elements_and_text() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<!-- a comment -->
+<x:properties xmlns:x="urn:ietf:params:xml:ns:icalendar-2.0">
+  <x:summary><x:text>Fish &amp; chips &#x26; &#38;<![CDATA[ <more> ]]></x:text></x:summary>
+  <empty attr='a > b'/>
+</x:properties>"#;
+
+        let root = XmlElement::parse(xml).unwrap();
+        assert_eq!(root.name, "properties");
+        assert_eq!(
+            root.children,
+            vec![
+                element(
+                    "summary",
+                    "",
+                    vec![element("text", "Fish & chips & & <more> ", vec![])]
+                ),
+                element("empty", "", vec![]),
+            ]
+        );
+        assert_eq!(
+            root.find("text").unwrap().trimmed_text(),
+            "Fish & chips & & <more>"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_xml() {
+        let tests = [
+            "<a><b></a>",
+            "<a>",
+            "<a></a><b></b>",
+            "<a>&unknown;</a>",
+            "<a b=c></a>",
+            "<!DOCTYPE a><a></a>",
+            "text",
+        ];
+        for xml in tests {
+            assert!(
+                matches!(XmlElement::parse(xml), Err(ParseError::InvalidXml(_))),
+                "{xml}"
+            );
+        }
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!(escape("a < b && c > d"), "a &lt; b &amp;&amp; c &gt; d");
+    }
+}
@@ -0,0 +1,393 @@
+//! A structured serde representation of [`RRule`] and [`RRuleSet`], as an alternative
+//! to the iCalendar string they are serialized to by default.
+//!
+//! Use it with `#[serde(with = "rrule::structured")]` on a field of type [`RRuleSet`] or
+//! [`RRule<Unvalidated>`]. The fields are named like the rule parts, in camel case, and
+//! the parts which aren't set are left out:
+//!
+//! ```json
+//! {
+//!   "dtStart": "20230102T090000",
+//!   "tzid": "Europe/Berlin",
+//!   "rrule": [{ "freq": "WEEKLY", "interval": 2, "byWeekday": ["MO", "-1FR"] }],
+//!   "exdate": ["20230116T090000"]
+//! }
+//! ```
+//!
+//! Dates are written in the iCalendar format, in the timezone of `tzid`, or in UTC with a
+//! `Z` suffix. `UNTIL` is always in UTC, unless the set only has dates. The input is parsed
+//! and validated like the iCalendar string, with the same errors.
+//!
+//! # Usage
+//!
+//! ```
+//! # extern crate serde;
+//! use rrule::RRuleSet;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "rrule::structured")]
+//!     recurrence: RRuleSet,
+//! }
+//!
+//! let event = Event {
+//!     recurrence: "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY;COUNT=3".parse().unwrap(),
+//! };
+//! let json = serde_json::to_string(&event).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"recurrence":{"dtStart":"20230102T090000Z","rrule":[{"freq":"DAILY","count":3,"byHour":[9],"byMinute":[0],"bySecond":[0]}]}}"#
+//! );
+//! ```
+use crate::parser::{
+    parse_duration, parse_timezone, props_to_rrule, ContentLine, ContentLineCaptures,
+    DateContentLine, PropertyName, RRuleProperty, StartDateContentLine,
+};
+use crate::{NWeekday, ParseError, RRule, RRuleError, RRuleSet, Tz, Unvalidated};
+use chrono::{DateTime, Weekday};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+
+/// Serializes `value` to its structured representation.
+///
+/// # Errors
+///
+/// Returns the error of the serializer.
+pub fn serialize<T: Structured, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.serialize_structured(serializer)
+}
+
+/// Deserializes a value from its structured representation, and validates it.
+///
+/// # Errors
+///
+/// Returns the [`RRuleError`] of an invalid value as a custom error of the deserializer.
+pub fn deserialize<'de, T: Structured, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::deserialize_structured(deserializer)
+}
+
+/// The types which have a structured representation, which are [`RRuleSet`] and
+/// [`RRule<Unvalidated>`].
+pub trait Structured: Sized + private::Sealed {
+    #[doc(hidden)]
+    fn serialize_structured<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    #[doc(hidden)]
+    fn deserialize_structured<'de, D: Deserializer<'de>>(deserializer: D)
+        -> Result<Self, D::Error>;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::RRule<crate::Unvalidated> {}
+    impl Sealed for crate::RRuleSet {}
+}
+
+impl Structured for RRule<Unvalidated> {
+    fn serialize_structured<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StructuredRRule::new(self, None).serialize(serializer)
+    }
+
+    fn deserialize_structured<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        StructuredRRule::deserialize(deserializer)?
+            .to_rrule()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Structured for RRuleSet {
+    fn serialize_structured<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StructuredRRuleSet::new(self).serialize(serializer)
+    }
+
+    fn deserialize_structured<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        StructuredRRuleSet::deserialize(deserializer)?
+            .to_rrule_set()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StructuredRRule {
+    freq: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    interval: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    count: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    until: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    week_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_set_pos: Vec<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_month: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_month_day: Vec<i8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_year_day: Vec<i16>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_week_no: Vec<i8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_weekday: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_hour: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_minute: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    by_second: Vec<u8>,
+    #[cfg(feature = "by-easter")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    by_easter: Option<i16>,
+}
+
+impl StructuredRRule {
+    /// Creates the representation of `rrule`. If `date_only_tz` is set, the rrule belongs
+    /// to a set with `VALUE=DATE` dates, so `UNTIL` is a date in that timezone.
+    fn new<S>(rrule: &RRule<S>, date_only_tz: Option<Tz>) -> Self {
+        let until = rrule.until.map(|until| match date_only_tz {
+            Some(tz) => until.with_timezone(&tz).format("%Y%m%d").to_string(),
+            None => format_date_time(&until, until.timezone()),
+        });
+        let mut by_month_day = rrule.by_month_day.clone();
+        by_month_day.extend(&rrule.by_n_month_day);
+
+        Self {
+            freq: rrule.freq.to_string(),
+            interval: (rrule.interval != 1).then_some(rrule.interval),
+            count: rrule.count,
+            until,
+            week_start: (rrule.week_start != Weekday::Mon)
+                .then(|| NWeekday::Every(rrule.week_start).to_ical()),
+            by_set_pos: rrule.by_set_pos.clone(),
+            by_month: rrule.by_month.clone(),
+            by_month_day,
+            by_year_day: rrule.by_year_day.clone(),
+            by_week_no: rrule.by_week_no.clone(),
+            by_weekday: rrule
+                .by_weekday
+                .iter()
+                .map(|weekday| weekday.to_ical())
+                .collect(),
+            by_hour: if date_only_tz.is_some() {
+                vec![]
+            } else {
+                rrule.by_hour.clone()
+            },
+            by_minute: if date_only_tz.is_some() {
+                vec![]
+            } else {
+                rrule.by_minute.clone()
+            },
+            by_second: if date_only_tz.is_some() {
+                vec![]
+            } else {
+                rrule.by_second.clone()
+            },
+            #[cfg(feature = "by-easter")]
+            by_easter: rrule.by_easter,
+        }
+    }
+
+    /// Parses the rule parts like the ones of an `RRULE` string.
+    fn to_rrule(&self) -> Result<RRule<Unvalidated>, ParseError> {
+        fn join<T: ToString>(values: &[T]) -> Option<String> {
+            (!values.is_empty()).then(|| {
+                values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+        }
+
+        let props = [
+            (RRuleProperty::Freq, Some(self.freq.clone())),
+            (
+                RRuleProperty::Interval,
+                self.interval.map(|i| i.to_string()),
+            ),
+            (RRuleProperty::Count, self.count.map(|c| c.to_string())),
+            (RRuleProperty::Until, self.until.clone()),
+            (RRuleProperty::Wkst, self.week_start.clone()),
+            (RRuleProperty::BySetPos, join(&self.by_set_pos)),
+            (RRuleProperty::ByMonth, join(&self.by_month)),
+            (RRuleProperty::ByMonthDay, join(&self.by_month_day)),
+            (RRuleProperty::ByYearDay, join(&self.by_year_day)),
+            (RRuleProperty::ByWeekNo, join(&self.by_week_no)),
+            (RRuleProperty::ByDay, join(&self.by_weekday)),
+            (RRuleProperty::ByHour, join(&self.by_hour)),
+            (RRuleProperty::ByMinute, join(&self.by_minute)),
+            (RRuleProperty::BySecond, join(&self.by_second)),
+            #[cfg(feature = "by-easter")]
+            (
+                RRuleProperty::ByEaster,
+                self.by_easter.map(|e| e.to_string()),
+            ),
+        ];
+        let props = props
+            .into_iter()
+            .filter_map(|(prop, value)| Some((prop, value?)))
+            .collect::<HashMap<_, _>>();
+        props_to_rrule(&props)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StructuredRRuleSet {
+    dt_start: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tzid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dt_end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    duration: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rrule: Vec<StructuredRRule>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    exrule: Vec<StructuredRRule>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rdate: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rdate_period: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    exdate: Vec<String>,
+}
+
+impl StructuredRRuleSet {
+    fn new(rrule_set: &RRuleSet) -> Self {
+        let tz = rrule_set.dt_start.timezone();
+        let date_only_tz = rrule_set.date_only.then_some(tz);
+        let format_date = |date: &DateTime<Tz>| match date_only_tz {
+            Some(tz) => date.with_timezone(&tz).format("%Y%m%d").to_string(),
+            None => format_date_time(date, tz),
+        };
+        let rrules = |rrules: &[RRule]| {
+            rrules
+                .iter()
+                .map(|rrule| StructuredRRule::new(rrule, date_only_tz))
+                .collect()
+        };
+
+        Self {
+            dt_start: format_date(&rrule_set.dt_start),
+            tzid: match tz {
+                Tz::Tz(tz) if tz != chrono_tz::UTC => Some(tz.name().into()),
+                _ => None,
+            },
+            dt_end: rrule_set.dt_end.as_ref().map(format_date),
+            duration: rrule_set.duration.as_ref().map(ToString::to_string),
+            rrule: rrules(&rrule_set.rrule),
+            exrule: rrules(&rrule_set.exrule),
+            rdate: rrule_set.rdate.iter().map(format_date).collect(),
+            rdate_period: rrule_set
+                .rdate_period
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            exdate: rrule_set.exdate.iter().map(format_date).collect(),
+        }
+    }
+
+    /// Parses and validates the set like an iCalendar string with the same properties.
+    fn to_rrule_set(&self) -> Result<RRuleSet, RRuleError> {
+        let tzid = self
+            .tzid
+            .as_deref()
+            .map(parse_timezone)
+            .transpose()?
+            .map(|tz| format!("TZID={}", tz.name()));
+        let date_only = self.dt_start.len() <= 8;
+        let parameters = |value: Option<&str>| match (&tzid, value) {
+            (Some(tzid), Some(value)) => Some(format!("{tzid};VALUE={value}")),
+            (Some(tzid), None) => Some(tzid.clone()),
+            (None, value) => value.map(|value| format!("VALUE={value}")),
+        };
+        let date_value = date_only.then_some("DATE");
+
+        let start_parameters = parameters(None);
+        let start = StartDateContentLine::try_from(&ContentLineCaptures {
+            property_name: PropertyName::DtStart,
+            parameters: start_parameters.as_deref(),
+            value: &self.dt_start,
+        })?;
+
+        if self.dt_end.is_some() && self.duration.is_some() {
+            return Err(ParseError::DtEndWithDuration.into());
+        }
+        if self.rrule.is_empty() && self.rdate.is_empty() && self.rdate_period.is_empty() {
+            return Err(ParseError::MissingDateGenerationRules.into());
+        }
+
+        let mut content_lines = vec![];
+        if let Some(dt_end) = &self.dt_end {
+            content_lines.push(ContentLine::DtEnd(StartDateContentLine::try_from(
+                &ContentLineCaptures {
+                    property_name: PropertyName::DtEnd,
+                    parameters: start_parameters.as_deref(),
+                    value: dt_end,
+                },
+            )?));
+        }
+        if let Some(duration) = &self.duration {
+            content_lines.push(ContentLine::Duration(parse_duration(duration)?));
+        }
+        for rrule in &self.rrule {
+            content_lines.push(ContentLine::RRule(rrule.to_rrule()?));
+        }
+        for exrule in &self.exrule {
+            content_lines.push(ContentLine::ExRule(exrule.to_rrule()?));
+        }
+
+        let dates = [
+            (PropertyName::RDate, &self.rdate, date_value),
+            (PropertyName::RDate, &self.rdate_period, Some("PERIOD")),
+            (PropertyName::ExDate, &self.exdate, date_value),
+        ];
+        for (property_name, dates, value) in dates {
+            if dates.is_empty() {
+                continue;
+            }
+            let parameters = parameters(value);
+            let line = DateContentLine::try_from(ContentLineCaptures {
+                property_name,
+                parameters: parameters.as_deref(),
+                value: &dates.join(","),
+            })?;
+            content_lines.push(match property_name {
+                PropertyName::ExDate => ContentLine::ExDate(line),
+                _ => ContentLine::RDate(line),
+            });
+        }
+
+        RRuleSet::from_start_date(&start).set_from_content_lines(content_lines)
+    }
+}
+
+/// Formats a datetime in `tz`, or in UTC with a `Z` suffix.
+fn format_date_time(date: &DateTime<Tz>, tz: Tz) -> String {
+    match tz {
+        Tz::Tz(tz) if tz != chrono_tz::UTC => {
+            date.with_timezone(&tz).format("%Y%m%dT%H%M%S").to_string()
+        }
+        Tz::Local(_) => date.with_timezone(&tz).format("%Y%m%dT%H%M%S").to_string(),
+        Tz::Tz(_) => date
+            .with_timezone(&Tz::UTC)
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string(),
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::{Duration, Instant};
+
+use crate::tests::common::ymd_hms;
+use crate::{CancellationToken, RRuleSet};
+
+fn daily_rrule_set() -> RRuleSet {
+    "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY\nRDATE:20120201T100000Z"
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn cancelled_iteration_times_out() {
+    let token = CancellationToken::new();
+    let rrule_set = daily_rrule_set().cancellation_token(token.clone());
+    assert_eq!(rrule_set.get_cancellation_token(), Some(&token));
+
+    let result = rrule_set.clone().all(10);
+    assert_eq!(result.dates.len(), 10);
+    assert!(!result.timed_out());
+
+    token.cancel();
+    assert!(token.is_cancelled());
+    let result = rrule_set.all(10);
+    assert!(result.dates.is_empty());
+    assert!(result.timed_out());
+    assert!(!result.limited);
+}
+
+#[test]
+fn cancels_running_iteration() {
+    let token = CancellationToken::new();
+    let rrule_set = daily_rrule_set().cancellation_token(token.clone());
+
+    let mut iter = rrule_set.into_iter();
+    assert_eq!(iter.next(), Some(ymd_hms(2012, 2, 1, 9, 30, 0)));
+    assert_eq!(iter.next(), Some(ymd_hms(2012, 2, 1, 10, 0, 0)));
+    token.cancel();
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn cancels_reverse_iteration() {
+    let token = CancellationToken::new();
+    let rrule_set = daily_rrule_set()
+        .before(ymd_hms(2013, 1, 1, 0, 0, 0))
+        .cancellation_token(token.clone());
+
+    let mut iter = rrule_set.iter_rev().unwrap();
+    assert_eq!(iter.next(), Some(ymd_hms(2012, 12, 31, 9, 30, 0)));
+    token.cancel();
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn iteration_stops_at_deadline() {
+    let rrule_set = daily_rrule_set();
+
+    let deadline = Instant::now() + Duration::from_secs(60);
+    let result = rrule_set.clone().deadline(deadline).all(10);
+    assert_eq!(result.dates.len(), 10);
+    assert!(!result.timed_out());
+
+    let rrule_set = rrule_set.deadline(Instant::now());
+    assert!(rrule_set.get_deadline().is_some());
+    let result = rrule_set.clone().all(10);
+    assert!(result.dates.is_empty());
+    assert!(result.timed_out());
+    assert_eq!(rrule_set.occurrences().next(), None);
+}
+
+#[test]
+fn tokens_are_only_equal_to_their_clones() {
+    let token = CancellationToken::new();
+    assert_eq!(token, token.clone());
+    assert_ne!(token, CancellationToken::new());
+}
@@ -0,0 +1,115 @@
+use crate::tests::common::check_occurrences;
+use crate::{Calendar, ComponentKind, ParseError, RRuleError};
+use std::str::FromStr;
+
+#[test]
+fn parses_recurring_events_from_calendar() {
+    let calendar = Calendar::from_str(
+        "BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Example Corp//Calendar//EN
+BEGIN:VEVENT
+UID:weekly-sync
+SUMMARY:Weekly sync
+DTSTART;TZID=Europe/Berlin:20230102T100000
+RRULE:FREQ=WEEKLY;COUNT=3
+EXDATE;TZID=Europe/Berlin:20230109T100000
+LOCATION:Room 1
+END:VEVENT
+BEGIN:VEVENT
+UID:one-off
+DTSTART:20230105T120000Z
+END:VEVENT
+END:VCALENDAR",
+    )
+    .unwrap();
+
+    let components = calendar.components();
+    assert_eq!(components.len(), 2);
+
+    let weekly = &components[0];
+    assert_eq!(weekly.kind(), ComponentKind::Event);
+    assert_eq!(weekly.uid(), Some("weekly-sync"));
+    assert_eq!(weekly.summary(), Some("Weekly sync"));
+    assert_eq!(
+        weekly.property("location").map(|property| property.value()),
+        Some("Room 1")
+    );
+    check_occurrences(
+        &weekly.rrule_set().unwrap().clone().all(10).dates,
+        &["2023-01-02T10:00:00+01:00", "2023-01-16T10:00:00+01:00"],
+    );
+
+    // Events without a recurrence have a single occurrence at their start date.
+    let one_off = &components[1];
+    check_occurrences(
+        &one_off.rrule_set().unwrap().clone().all(10).dates,
+        &["2023-01-05T12:00:00+00:00"],
+    );
+}
+
+#[test]
+fn parses_components_without_calendar() {
+    let calendar = Calendar::from_str(
+        "BEGIN:VTODO\nUID:todo\nDTSTART:20230105T120000Z\nRRULE:FREQ=DAILY;COUNT=2\nEND:VTODO",
+    )
+    .unwrap();
+
+    let components = calendar.into_components();
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].kind(), ComponentKind::Todo);
+    check_occurrences(
+        &components[0].rrule_set().unwrap().clone().all(10).dates,
+        &["2023-01-05T12:00:00+00:00", "2023-01-06T12:00:00+00:00"],
+    );
+}
+
+#[test]
+fn keeps_components_without_start_date() {
+    let calendar = Calendar::from_str("BEGIN:VJOURNAL\nUID:notes\nEND:VJOURNAL").unwrap();
+
+    let journal = &calendar.components()[0];
+    assert_eq!(journal.kind(), ComponentKind::Journal);
+    assert_eq!(journal.rrule_set(), None);
+}
+
+#[test]
+fn rejects_recurrence_without_start_date() {
+    let res = Calendar::from_str("BEGIN:VEVENT\nRRULE:FREQ=DAILY\nEND:VEVENT");
+    assert_eq!(
+        res,
+        Err(RRuleError::ParserError(ParseError::MissingStartDate))
+    );
+}
+
+#[test]
+fn unfolds_and_unescapes_properties() {
+    let calendar = Calendar::from_str(
+        "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:folded\r
+SUMMARY:Planning\\, review\\; and retro\\nfor the whole t\r
+ eam\r
+ORGANIZER;CN=\"Doe: Jane\":mailto:jane@example.com\r
+DTSTART;TZID=\"Europe/Berlin\":20230102T100000\r
+RRULE:FREQ=WEEKLY;COUNT=2;BYDAY=MO,\r
+\tTU\r
+END:VEVENT\r
+END:VCALENDAR\r
+",
+    )
+    .unwrap();
+
+    let event = &calendar.components()[0];
+    assert_eq!(
+        event.summary(),
+        Some("Planning, review; and retro\nfor the whole team")
+    );
+    let organizer = event.property("ORGANIZER").unwrap();
+    assert_eq!(organizer.parameter("CN"), Some("Doe: Jane"));
+    assert_eq!(organizer.value(), "mailto:jane@example.com");
+    check_occurrences(
+        &event.rrule_set().unwrap().clone().all(10).dates,
+        &["2023-01-02T10:00:00+01:00", "2023-01-03T10:00:00+01:00"],
+    );
+}
@@ -18,6 +18,10 @@ pub fn ymd_hms(
         .unwrap()
 }
 
+pub fn parse(s: &str) -> RRuleSet {
+    s.parse().unwrap()
+}
+
 pub fn test_recurring_rrule(
     rrule: RRule<Unvalidated>,
     limited: bool,
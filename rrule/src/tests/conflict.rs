@@ -0,0 +1,192 @@
+use std::time::{Duration, Instant};
+
+use crate::core::conflicts;
+use crate::tests::common::{parse, ymd_hms};
+use crate::{Conflict, Limits, Occurrence, OccurrenceSource, RRuleSet, TerminationReason, Tz};
+use chrono::DateTime;
+
+/// Compares every occurrence of `first` with every occurrence of `second`.
+fn brute_force(first: &RRuleSet, second: &RRuleSet, horizon: DateTime<Tz>) -> Vec<Conflict> {
+    let occurrences = |rrule_set: &RRuleSet| {
+        rrule_set
+            .occurrences()
+            .take_while(|occurrence| occurrence.start < horizon)
+            .collect::<Vec<_>>()
+    };
+    let seconds = occurrences(second);
+    let mut found = vec![];
+    for first in occurrences(first) {
+        for second in &seconds {
+            if conflicts(&first, second) {
+                found.push(Conflict {
+                    first,
+                    second: *second,
+                });
+            }
+        }
+    }
+    found.sort_by_key(|conflict| {
+        (
+            conflict.start(),
+            conflict.first.start,
+            conflict.second.start,
+        )
+    });
+    found
+}
+
+fn assert_same_conflicts(first: &str, second: &str, horizon: DateTime<Tz>) {
+    let (first, second) = (parse(first), parse(second));
+    let expected = brute_force(&first, &second, horizon);
+    assert!(!expected.is_empty());
+
+    let result = first.conflicts(&second, horizon, u16::MAX);
+    assert_eq!(result.reason, TerminationReason::Finished);
+    assert!(!result.limited);
+    let mut conflicts = result.conflicts;
+    assert!(conflicts.windows(2).all(|w| w[0].start() <= w[1].start()));
+    conflicts.sort_by_key(|conflict| {
+        (
+            conflict.start(),
+            conflict.first.start,
+            conflict.second.start,
+        )
+    });
+    assert_eq!(conflicts, expected);
+}
+
+#[test]
+fn finds_the_same_conflicts_as_comparing_all_occurrences() {
+    let horizon = ymd_hms(2023, 3, 1, 0, 0, 0);
+    assert_same_conflicts(
+        "DTSTART:20230101T090000Z\nDURATION:PT1H\nRRULE:FREQ=DAILY;BYDAY=MO,WE,FR",
+        "DTSTART;TZID=Europe/Berlin:20230101T093000\nDURATION:PT2H\nRRULE:FREQ=WEEKLY;BYDAY=WE,SA\nEXDATE;TZID=Europe/Berlin:20230111T093000",
+        horizon,
+    );
+    assert_same_conflicts(
+        "DTSTART:20230101T000000Z\nRRULE:FREQ=HOURLY;INTERVAL=5",
+        "DTSTART:20230101T000000Z\nDURATION:PT30M\nRRULE:FREQ=HOURLY;INTERVAL=7\nRDATE;VALUE=PERIOD:20230110T000000Z/P3D",
+        horizon,
+    );
+    assert_same_conflicts(
+        "DTSTART:20230101T000000Z\nDURATION:PT10M\nRRULE:FREQ=MINUTELY;INTERVAL=15;BYHOUR=8,9",
+        "DTSTART:20230115T090000Z\nDURATION:PT1H\nRRULE:FREQ=MONTHLY;BYMONTHDAY=15",
+        horizon,
+    );
+    // The time filters move the minutely rule off the grid of its interval.
+    let sub_daily =
+        "DTSTART:20230101T000000Z\nDURATION:PT20M\nRRULE:FREQ=MINUTELY;INTERVAL=97;BYHOUR=9,10";
+    assert_same_conflicts(
+        sub_daily,
+        "DTSTART;VALUE=DATE:20230102\nRRULE:FREQ=WEEKLY;INTERVAL=3",
+        horizon,
+    );
+    assert_same_conflicts(
+        sub_daily,
+        "DTSTART:20230105T090000Z\nDURATION:PT2H\nRRULE:FREQ=MONTHLY;BYDAY=1TH\nRDATE;VALUE=PERIOD:20230120T080000Z/PT4H",
+        horizon,
+    );
+}
+
+#[test]
+fn returns_conflicts_in_order() {
+    let standup = parse("DTSTART:20230102T090000Z\nDURATION:PT15M\nRRULE:FREQ=DAILY");
+    let workshop =
+        parse("DTSTART:20230104T083000Z\nDTEND:20230104T093000Z\nRRULE:FREQ=WEEKLY;COUNT=3");
+
+    let result = standup.conflicts(&workshop, ymd_hms(2024, 1, 1, 0, 0, 0), 10);
+    assert_eq!(result.reason, TerminationReason::Finished);
+    assert_eq!(
+        result.conflicts,
+        [4, 11, 18]
+            .into_iter()
+            .map(|day| Conflict {
+                first: Occurrence {
+                    start: ymd_hms(2023, 1, day, 9, 0, 0),
+                    end: ymd_hms(2023, 1, day, 9, 15, 0),
+                    source: OccurrenceSource::RRule(0),
+                },
+                second: Occurrence {
+                    start: ymd_hms(2023, 1, day, 8, 30, 0),
+                    end: ymd_hms(2023, 1, day, 9, 30, 0),
+                    source: OccurrenceSource::RRule(0),
+                },
+            })
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(result.conflicts[0].start(), ymd_hms(2023, 1, 4, 9, 0, 0));
+    assert_eq!(result.conflicts[0].end(), ymd_hms(2023, 1, 4, 9, 15, 0));
+
+    let result = standup.conflicts(&workshop, ymd_hms(2024, 1, 1, 0, 0, 0), 2);
+    assert_eq!(result.conflicts.len(), 2);
+    assert_eq!(result.reason, TerminationReason::Limit);
+    assert!(result.limited);
+}
+
+#[test]
+fn matches_occurrences_without_length() {
+    let reminder = parse("DTSTART:20230101T090000Z\nRRULE:FREQ=DAILY;COUNT=3");
+    let other = parse("DTSTART:20230102T090000Z\nRDATE:20230102T090000Z,20230103T080000Z");
+    let meeting = parse("DTSTART:20230103T083000Z\nDURATION:PT1H\nRDATE:20230103T083000Z");
+
+    let result = reminder.conflicts(&other, ymd_hms(2024, 1, 1, 0, 0, 0), 10);
+    assert_eq!(result.conflicts.len(), 1);
+    assert_eq!(result.conflicts[0].start(), ymd_hms(2023, 1, 2, 9, 0, 0));
+    assert_eq!(result.conflicts[0].end(), ymd_hms(2023, 1, 2, 9, 0, 0));
+
+    let result = meeting.conflicts(&reminder, ymd_hms(2024, 1, 1, 0, 0, 0), 10);
+    assert_eq!(result.conflicts.len(), 1);
+    assert_eq!(
+        result.conflicts[0].second.start,
+        ymd_hms(2023, 1, 3, 9, 0, 0)
+    );
+}
+
+#[test]
+fn skips_ahead_between_sparse_occurrences() {
+    // Comparing every minute of two centuries would take far longer than the deadline.
+    let deadline = Instant::now() + Duration::from_secs(30);
+    let review = parse("DTSTART:20230102T100000Z\nDURATION:PT2H\nRRULE:FREQ=YEARLY");
+    let lunch_check =
+        parse("DTSTART:20230101T000000Z\nDURATION:PT30S\nRRULE:FREQ=MINUTELY;BYHOUR=12,13")
+            .deadline(deadline);
+
+    let horizon = ymd_hms(2200, 1, 1, 0, 0, 0);
+    let result = review.conflicts(&lunch_check, horizon, 10);
+    assert_eq!(result.reason, TerminationReason::Finished);
+    assert!(result.conflicts.is_empty());
+
+    let result = lunch_check.conflicts(&review, horizon, 10);
+    assert_eq!(result.reason, TerminationReason::Finished);
+    assert!(result.conflicts.is_empty());
+
+    let heartbeat =
+        parse("DTSTART:20230101T000000Z\nRRULE:FREQ=SECONDLY;BYSECOND=0").deadline(deadline);
+    let result = review.conflicts(&heartbeat, horizon, 500);
+    assert_eq!(result.conflicts.len(), 500);
+    assert_eq!(result.conflicts[120].start(), ymd_hms(2024, 1, 2, 10, 0, 0));
+}
+
+#[test]
+fn stops_when_a_set_is_over() {
+    let once = parse("DTSTART:20230101T090000Z\nDURATION:PT1H\nRDATE:20230101T090000Z");
+    let daily = parse("DTSTART:20230101T120000Z\nRRULE:FREQ=DAILY");
+
+    let result = daily.conflicts(&once, ymd_hms(9000, 1, 1, 0, 0, 0), 10);
+    assert!(result.conflicts.is_empty());
+    assert_eq!(result.reason, TerminationReason::Finished);
+    assert!(!result.limited);
+}
+
+#[test]
+fn reports_when_no_proof_is_possible() {
+    // There is no 29th of February between 2096 and 2104.
+    let leap_day = parse("DTSTART:20970101T090000Z\nRRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29")
+        .limits(Limits::default().max_iterations(5));
+    let daily = parse("DTSTART:20970101T120000Z\nRRULE:FREQ=DAILY");
+
+    let result = daily.conflicts(&leap_day, ymd_hms(2200, 1, 1, 0, 0, 0), 10);
+    assert!(result.conflicts.is_empty());
+    assert_eq!(result.reason, TerminationReason::MaxIterations);
+    assert!(result.limited);
+}
@@ -0,0 +1,112 @@
+use crate::{RRuleSet, RRuleSetCursor};
+
+#[test]
+fn resumes_iteration_from_cursor() {
+    let rrule_sets = [
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=7",
+        "DTSTART;TZID=Europe/Berlin:20120301T023000\nRRULE:FREQ=WEEKLY;BYDAY=SU,MO;UNTIL=20120501T000000Z",
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=HOURLY;INTERVAL=5;BYHOUR=4,9,14;COUNT=12",
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=10\nRRULE:FREQ=DAILY;INTERVAL=3;COUNT=5\n\
+        EXRULE:FREQ=DAILY;INTERVAL=4\nRDATE:20120201T100000Z,20120203T093000Z\nEXDATE:20120205T093000Z",
+    ];
+
+    for rrule_set in rrule_sets {
+        let rrule_set: RRuleSet = rrule_set.parse().unwrap();
+        let expected = rrule_set.clone().all(u16::MAX).dates;
+
+        for page_size in 1..5 {
+            let mut dates = vec![];
+            let mut cursor: Option<RRuleSetCursor> = None;
+            loop {
+                let mut iter = match &cursor {
+                    Some(cursor) => {
+                        // The cursor goes through a string, like when it is sent to a client.
+                        let cursor = cursor.to_string().parse().unwrap();
+                        rrule_set.iter_from_cursor(&cursor).unwrap()
+                    }
+                    None => rrule_set.into_iter(),
+                };
+                let page = iter.by_ref().take(page_size).collect::<Vec<_>>();
+                if page.is_empty() {
+                    break;
+                }
+                dates.extend(page);
+                cursor = Some(iter.cursor());
+            }
+            assert_eq!(dates, expected, "{rrule_set} with pages of {page_size}");
+        }
+    }
+}
+
+#[test]
+fn rejects_cursor_of_other_set() {
+    let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=7"
+        .parse()
+        .unwrap();
+    let other_rrule_set: RRuleSet =
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=7\nRRULE:FREQ=WEEKLY;COUNT=2"
+            .parse()
+            .unwrap();
+
+    let cursor = other_rrule_set.into_iter().cursor();
+    assert!(rrule_set.iter_from_cursor(&cursor).is_err());
+}
+
+#[test]
+fn rejects_cursor_of_set_with_the_same_shape() {
+    let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=7"
+        .parse()
+        .unwrap();
+    let other_rrule_sets = [
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=8",
+        "DTSTART:20120202T093000Z\nRRULE:FREQ=DAILY;COUNT=7",
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=WEEKLY;COUNT=7",
+    ];
+
+    for other_rrule_set in other_rrule_sets {
+        let other_rrule_set: RRuleSet = other_rrule_set.parse().unwrap();
+        let mut iter = other_rrule_set.into_iter();
+        iter.next();
+        let cursor = iter.cursor().to_string().parse().unwrap();
+        assert!(
+            rrule_set.iter_from_cursor(&cursor).is_err(),
+            "{other_rrule_set}"
+        );
+    }
+}
+
+#[test]
+fn rejects_cursors_with_dates_out_of_range() {
+    let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=7"
+        .parse()
+        .unwrap();
+    let mut iter = rrule_set.into_iter();
+    iter.next();
+    let cursor = iter.cursor().to_string();
+    assert!(cursor.contains(";2012/2/2/9/30/0/"), "{cursor}");
+
+    for date in [
+        "2012/13/2/9/30/0",
+        "2012/0/2/9/30/0",
+        "2012/2/45/9/30/0",
+        "2012/2/0/9/30/0",
+        "999999/2/2/9/30/0",
+        "2012/2/2/24/30/0",
+        "2012/2/2/9/60/0",
+        "2012/2/2/9/30/60",
+    ] {
+        let tampered = cursor.replace("2012/2/2/9/30/0", date);
+        assert!(tampered.parse::<RRuleSetCursor>().is_err(), "{tampered}");
+    }
+    // Dates in the range of chrono are valid, and resuming from them mustn't panic.
+    for date in [
+        "262000/12/31/23/59/59",
+        "-262000/1/1/0/0/0",
+        "10001/2/2/9/30/0",
+    ] {
+        let tampered: RRuleSetCursor = cursor.replace("2012/2/2/9/30/0", date).parse().unwrap();
+        if let Ok(iter) = rrule_set.iter_from_cursor(&tampered) {
+            let _ = iter.collect::<Vec<_>>();
+        }
+    }
+}
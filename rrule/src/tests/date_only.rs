@@ -0,0 +1,199 @@
+use crate::tests::common::check_occurrences;
+use crate::{RRule, RRuleError, RRuleSet, Tz, ValidationError};
+use chrono::{NaiveDate, TimeZone};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn all_day_events_round_trip() {
+    let rrule_set_str = "DTSTART;VALUE=DATE:20230101\nRRULE:FREQ=WEEKLY;UNTIL=20230122;BYDAY=SU\nRDATE;VALUE=DATE:20230125\nEXDATE;VALUE=DATE:20230108";
+    let rrule_set: RRuleSet = rrule_set_str.parse().unwrap();
+
+    assert!(rrule_set.is_date_only());
+    assert_eq!(
+        rrule_set.iter_dates().collect::<Vec<_>>(),
+        vec![
+            ymd(2023, 1, 1),
+            ymd(2023, 1, 15),
+            ymd(2023, 1, 22),
+            ymd(2023, 1, 25)
+        ]
+    );
+    // Occurrences are at midnight UTC, independent of the local timezone.
+    check_occurrences(
+        &rrule_set.clone().all(10).dates,
+        &[
+            "2023-01-01T00:00:00+00:00",
+            "2023-01-15T00:00:00+00:00",
+            "2023-01-22T00:00:00+00:00",
+            "2023-01-25T00:00:00+00:00",
+        ],
+    );
+
+    assert_eq!(rrule_set.to_string(), rrule_set_str);
+    assert_eq!(
+        rrule_set.to_string().parse::<RRuleSet>().unwrap(),
+        rrule_set
+    );
+}
+
+#[test]
+fn all_day_events_with_timezone() {
+    let rrule_set: RRuleSet =
+        "DTSTART;TZID=Europe/Berlin;VALUE=DATE:20230330\nRRULE:FREQ=DAILY;UNTIL=20230402"
+            .parse()
+            .unwrap();
+
+    assert_eq!(
+        rrule_set.iter_dates().collect::<Vec<_>>(),
+        vec![
+            ymd(2023, 3, 30),
+            ymd(2023, 3, 31),
+            ymd(2023, 4, 1),
+            ymd(2023, 4, 2)
+        ]
+    );
+    // Dates can't have a `TZID`.
+    assert_eq!(
+        rrule_set.to_string(),
+        "DTSTART;VALUE=DATE:20230330\nRRULE:FREQ=DAILY;UNTIL=20230402"
+    );
+}
+
+#[test]
+fn all_day_events_on_days_without_midnight() {
+    // Sao Paulo skipped from midnight to 1:00 on 2018-11-04.
+    let rrule_set: RRuleSet = "DTSTART;TZID=America/Sao_Paulo;VALUE=DATE:20181102\nRRULE:FREQ=DAILY;COUNT=4\nRDATE;VALUE=DATE:20181110\nEXDATE;VALUE=DATE:20181105"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        rrule_set.iter_dates().collect::<Vec<_>>(),
+        vec![
+            ymd(2018, 11, 2),
+            ymd(2018, 11, 3),
+            ymd(2018, 11, 4),
+            ymd(2018, 11, 10)
+        ]
+    );
+    check_occurrences(
+        &rrule_set.all(10).dates,
+        &[
+            "2018-11-02T00:00:00-03:00",
+            "2018-11-03T00:00:00-03:00",
+            "2018-11-04T01:00:00-02:00",
+            "2018-11-10T00:00:00-02:00",
+        ],
+    );
+}
+
+#[test]
+fn date_exdates_in_date_time_set() {
+    let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20230101T000000\nRRULE:FREQ=DAILY;COUNT=3\nEXDATE;VALUE=DATE:20230102"
+        .parse()
+        .unwrap();
+
+    assert!(!rrule_set.is_date_only());
+    check_occurrences(
+        &rrule_set.all(10).dates,
+        &["2023-01-01T00:00:00+01:00", "2023-01-03T00:00:00+01:00"],
+    );
+}
+
+#[test]
+fn rejects_date_time_in_date_value() {
+    let res = "DTSTART;VALUE=DATE:20230101\nRDATE;VALUE=DATE:20230102T090000Z".parse::<RRuleSet>();
+    assert!(res.is_err());
+}
+
+#[test]
+fn builds_date_only_set() {
+    let dt_start = Tz::Europe__Berlin
+        .with_ymd_and_hms(2023, 3, 1, 15, 30, 0)
+        .unwrap();
+    let rrule_set = RRuleSet::new(dt_start)
+        .date_only()
+        .unwrap()
+        .rdate(
+            Tz::Europe__Berlin
+                .with_ymd_and_hms(2023, 3, 5, 9, 0, 0)
+                .unwrap(),
+        )
+        .exdate(Tz::UTC.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap());
+
+    assert_eq!(
+        rrule_set.get_dt_start(),
+        &Tz::Europe__Berlin
+            .with_ymd_and_hms(2023, 3, 1, 0, 0, 0)
+            .unwrap()
+    );
+    assert_eq!(
+        rrule_set.iter_dates().collect::<Vec<_>>(),
+        vec![ymd(2023, 3, 5)]
+    );
+    assert_eq!(
+        rrule_set.to_string(),
+        "DTSTART;VALUE=DATE:20230301\nRDATE;VALUE=DATE:20230305\nEXDATE;VALUE=DATE:20230301"
+    );
+}
+
+#[test]
+fn rejects_times_in_date_only_sets() {
+    for (rrule_set, by_rule) in [
+        (
+            "DTSTART;VALUE=DATE:20240101\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9,10",
+            "BYHOUR",
+        ),
+        (
+            "DTSTART;VALUE=DATE:20240101\nRRULE:FREQ=WEEKLY;BYMINUTE=30",
+            "BYMINUTE",
+        ),
+        (
+            "DTSTART;TZID=Europe/Berlin;VALUE=DATE:20240101\nRRULE:FREQ=HOURLY;BYSECOND=15",
+            "BYSECOND",
+        ),
+    ] {
+        assert_eq!(
+            rrule_set.parse::<RRuleSet>(),
+            Err(RRuleError::ValidationError(
+                ValidationError::ByTimeRuleWithDateStart(by_rule.into())
+            )),
+            "{rrule_set}"
+        );
+    }
+}
+
+#[test]
+fn date_only_rejects_rrules_with_times() {
+    let dt_start = Tz::Europe__Berlin
+        .with_ymd_and_hms(2023, 3, 1, 15, 30, 0)
+        .unwrap();
+    let rrule_set = "FREQ=DAILY;COUNT=3"
+        .parse::<RRule<_>>()
+        .unwrap()
+        .build(dt_start)
+        .unwrap();
+    assert_eq!(
+        rrule_set.date_only(),
+        Err(RRuleError::ValidationError(
+            ValidationError::ByTimeRuleWithDateStart("BYHOUR".into())
+        ))
+    );
+
+    let midnight = Tz::Europe__Berlin
+        .with_ymd_and_hms(2023, 3, 1, 0, 0, 0)
+        .unwrap();
+    let rrule_set = "FREQ=DAILY;COUNT=3"
+        .parse::<RRule<_>>()
+        .unwrap()
+        .build(midnight)
+        .unwrap()
+        .date_only()
+        .unwrap();
+    assert_eq!(
+        rrule_set.to_string(),
+        "DTSTART;VALUE=DATE:20230301\nRRULE:FREQ=DAILY;COUNT=3"
+    );
+}
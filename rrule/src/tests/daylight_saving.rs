@@ -144,3 +144,41 @@ fn daylight_savings_2() {
         ],
     );
 }
+
+#[test]
+fn daylight_savings_skipped_midnight() {
+    // Clocks in Sao Paulo went from 00:00 to 01:00 on 2018-11-04, so 00:30 is shifted
+    // by the length of the gap, like any other skipped time.
+    let dates = "DTSTART;TZID=America/Sao_Paulo:20181102T003000\nRRULE:FREQ=DAILY;COUNT=4"
+        .parse::<RRuleSet>()
+        .unwrap()
+        .all_unchecked();
+    check_occurrences(
+        &dates,
+        &[
+            "2018-11-02T00:30:00-03:00",
+            "2018-11-03T00:30:00-03:00",
+            "2018-11-04T01:30:00-02:00",
+            "2018-11-05T00:30:00-02:00",
+        ],
+    );
+}
+
+#[test]
+fn daylight_savings_repeated_midnight() {
+    // Clocks in Havana went from 01:00 back to 00:00 on 2023-11-05, so midnight and
+    // 00:30 happen twice. The earlier one is used, like for any other repeated time.
+    let dates = "DTSTART;TZID=America/Havana:20231103T003000\nRRULE:FREQ=DAILY;COUNT=4"
+        .parse::<RRuleSet>()
+        .unwrap()
+        .all_unchecked();
+    check_occurrences(
+        &dates,
+        &[
+            "2023-11-03T00:30:00-04:00",
+            "2023-11-04T00:30:00-04:00",
+            "2023-11-05T00:30:00-04:00",
+            "2023-11-06T00:30:00-05:00",
+        ],
+    );
+}
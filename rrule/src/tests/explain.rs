@@ -0,0 +1,145 @@
+use crate::tests::common::ymd_hms;
+use crate::{ExclusionSource, OccurrenceSource, RRulePart, RRuleSet};
+
+#[test]
+fn explains_rejected_dates() {
+    let rrule_set: RRuleSet =
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=3"
+            .parse()
+            .unwrap();
+    let rejected_by = |year, month, day, hour| {
+        rrule_set
+            .explain(&ymd_hms(year, month, day, hour, 0, 0))
+            .rrules[0]
+            .rejected_by()
+            .collect::<Vec<_>>()
+    };
+
+    let explanation = rrule_set.explain(&ymd_hms(2023, 1, 4, 9, 0, 0));
+    assert!(explanation.is_occurrence());
+    assert_eq!(explanation.source(), Some(OccurrenceSource::RRule(0)));
+    assert_eq!(
+        explanation.rrules[0]
+            .checks
+            .iter()
+            .map(|check| check.part)
+            .collect::<Vec<_>>(),
+        vec![
+            RRulePart::DtStart,
+            RRulePart::Interval,
+            RRulePart::ByWeekday,
+            RRulePart::ByHour,
+            RRulePart::ByMinute,
+            RRulePart::BySecond,
+            RRulePart::Count,
+        ]
+    );
+
+    assert_eq!(rejected_by(2023, 1, 9, 9), vec![RRulePart::Interval]);
+    assert_eq!(
+        rejected_by(2023, 1, 5, 10),
+        vec![RRulePart::ByWeekday, RRulePart::ByHour]
+    );
+    assert_eq!(rejected_by(2023, 1, 18, 9), vec![RRulePart::Count]);
+    assert_eq!(
+        rejected_by(2022, 12, 28, 9),
+        vec![RRulePart::DtStart, RRulePart::Interval]
+    );
+}
+
+#[test]
+fn explains_by_set_pos() {
+    let rrule_set: RRuleSet =
+        "DTSTART:20230131T090000Z\nRRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;UNTIL=20230401T000000Z"
+            .parse()
+            .unwrap();
+
+    let explanation = rrule_set.explain(&ymd_hms(2023, 2, 27, 9, 0, 0));
+    assert_eq!(
+        explanation.rrules[0].rejected_by().collect::<Vec<_>>(),
+        vec![RRulePart::BySetPos]
+    );
+    assert!(rrule_set
+        .explain(&ymd_hms(2023, 2, 28, 9, 0, 0))
+        .is_occurrence());
+    assert_eq!(
+        rrule_set.explain(&ymd_hms(2023, 4, 28, 9, 0, 0)).rrules[0]
+            .rejected_by()
+            .collect::<Vec<_>>(),
+        vec![RRulePart::Until]
+    );
+    // `BYSETPOS` isn't checked when another part already rejects the date.
+    assert_eq!(
+        rrule_set.explain(&ymd_hms(2023, 2, 26, 9, 0, 0)).rrules[0]
+            .rejected_by()
+            .collect::<Vec<_>>(),
+        vec![RRulePart::ByWeekday]
+    );
+}
+
+#[test]
+fn explains_rdates_and_exdates() {
+    let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nRRULE:FREQ=DAILY\n\
+        RDATE:20230105T120000Z\nEXDATE:20230103T090000Z,20230104T090000Z"
+        .parse()
+        .unwrap();
+
+    let explanation = rrule_set.explain(&ymd_hms(2023, 1, 4, 9, 0, 0));
+    assert!(explanation.rrules[0].matches());
+    assert_eq!(explanation.exdate, Some(1));
+    assert_eq!(explanation.excluded_by(), Some(ExclusionSource::ExDate(1)));
+    assert!(!explanation.is_occurrence());
+
+    let explanation = rrule_set.explain(&ymd_hms(2023, 1, 5, 12, 0, 0));
+    assert!(!explanation.rrules[0].matches());
+    assert_eq!(explanation.source(), Some(OccurrenceSource::RDate(0)));
+    assert!(explanation.is_occurrence());
+}
+
+#[cfg(feature = "exrule")]
+#[test]
+fn explains_exrules() {
+    let rrule_set: RRuleSet =
+        "DTSTART:20230101T090000Z\nRRULE:FREQ=DAILY\nEXRULE:FREQ=WEEKLY;BYDAY=SA,SU"
+            .parse()
+            .unwrap();
+
+    let explanation = rrule_set.explain(&ymd_hms(2023, 1, 7, 9, 0, 0));
+    assert!(explanation.exrules[0].matches());
+    assert_eq!(explanation.excluded_by(), Some(ExclusionSource::ExRule(0)));
+    assert!(!explanation.is_occurrence());
+    assert!(rrule_set
+        .explain(&ymd_hms(2023, 1, 9, 9, 0, 0))
+        .is_occurrence());
+}
+
+#[test]
+fn explanation_agrees_with_iteration() {
+    let rrule_sets = [
+        "DTSTART:20230101T093000Z\nRRULE:FREQ=YEARLY;BYMONTH=1,3;BYDAY=-1MO,1FR;BYHOUR=9,21",
+        "DTSTART:20230115T090000Z\nRRULE:FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=1,-1,15",
+        "DTSTART:20230101T090000Z\nRRULE:FREQ=YEARLY;BYWEEKNO=1,5;BYDAY=TU",
+        "DTSTART:20230101T090000Z\nRRULE:FREQ=YEARLY;BYYEARDAY=1,40,-300;BYHOUR=9,10",
+        "DTSTART:20230101T013000Z\nRRULE:FREQ=HOURLY;INTERVAL=5;BYHOUR=1,6,11,16;COUNT=30",
+        "DTSTART;TZID=Europe/Berlin:20230301T023000\nRRULE:FREQ=DAILY;BYSETPOS=1;BYHOUR=2,3",
+        "DTSTART:20230101T003000Z\nRRULE:FREQ=MINUTELY;INTERVAL=90;BYHOUR=1,4,9",
+    ];
+
+    for rrule_set in rrule_sets {
+        let rrule_set: RRuleSet = rrule_set.parse().unwrap();
+        let dates = rrule_set
+            .clone()
+            .before(ymd_hms(2023, 4, 1, 0, 0, 0))
+            .all(1000)
+            .dates;
+        assert!(!dates.is_empty());
+        for hour in 0..24 * 90 {
+            let date = ymd_hms(2023, 1, 1, 0, 30, 0) + chrono::Duration::hours(hour);
+            assert_eq!(
+                rrule_set.explain(&date).is_occurrence(),
+                dates.contains(&date),
+                "{date} in {rrule_set}"
+            );
+        }
+    }
+}
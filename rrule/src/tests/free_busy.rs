@@ -0,0 +1,174 @@
+use crate::tests::common::{parse, ymd_hms};
+use crate::{FreeBusyQuery, Limits, Period, TerminationReason, Tz, WorkingHours};
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Utc, Weekday};
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+fn periods(ranges: &[(DateTime<Tz>, DateTime<Tz>)]) -> Vec<Period> {
+    ranges
+        .iter()
+        .map(|(start, end)| Period::new(*start, *end))
+        .collect()
+}
+
+#[test]
+fn merges_busy_periods_of_all_sets() {
+    let night_shift = parse("DTSTART:20230101T220000Z\nDURATION:PT4H\nRRULE:FREQ=DAILY");
+    let meeting = parse(
+        "DTSTART;TZID=Europe/Berlin:20230102T100000\nDTEND;TZID=Europe/Berlin:20230102T113000\nRDATE;TZID=Europe/Berlin:20230102T100000,20230102T110000",
+    );
+    let reminder = parse("DTSTART:20230102T150000Z\nRRULE:FREQ=HOURLY");
+
+    let free_busy = FreeBusyQuery::new(ymd_hms(2023, 1, 2, 0, 0, 0), ymd_hms(2023, 1, 3, 0, 0, 0))
+        .compute([&night_shift, &meeting, &reminder]);
+
+    assert_eq!(
+        free_busy.busy,
+        periods(&[
+            // The shift of the 1st lasts until 02:00 on the 2nd.
+            (ymd_hms(2023, 1, 2, 0, 0, 0), ymd_hms(2023, 1, 2, 2, 0, 0)),
+            // The meetings overlap.
+            (ymd_hms(2023, 1, 2, 9, 0, 0), ymd_hms(2023, 1, 2, 11, 30, 0)),
+            (ymd_hms(2023, 1, 2, 22, 0, 0), ymd_hms(2023, 1, 3, 0, 0, 0)),
+        ])
+    );
+    assert_eq!(
+        free_busy.free,
+        periods(&[
+            (ymd_hms(2023, 1, 2, 2, 0, 0), ymd_hms(2023, 1, 2, 9, 0, 0)),
+            (
+                ymd_hms(2023, 1, 2, 11, 30, 0),
+                ymd_hms(2023, 1, 2, 22, 0, 0)
+            ),
+        ])
+    );
+    assert!(free_busy.unavailable.is_empty());
+    assert_eq!(free_busy.reason, TerminationReason::Finished);
+    assert!(!free_busy.limited);
+    assert_eq!(
+        free_busy.to_string(),
+        "FREEBUSY;FBTYPE=BUSY:20230102T000000Z/20230102T020000Z,20230102T090000Z/202\n 30102T113000Z,20230102T220000Z/20230103T000000Z\nFREEBUSY;FBTYPE=FREE:20230102T020000Z/20230102T090000Z,20230102T113000Z/202\n 30102T220000Z"
+    );
+}
+
+#[test]
+fn finds_free_slots_within_working_hours() {
+    let berlin = Tz::Europe__Berlin;
+    let standup = parse(
+        "DTSTART;TZID=Europe/Berlin:20230327T093000\nDURATION:PT30M\nRRULE:FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR",
+    );
+    let focus =
+        parse("DTSTART;TZID=Europe/Berlin:20230327T130000\nDURATION:PT3H\nRRULE:FREQ=WEEKLY");
+    let working_hours = WorkingHours::new(time(9, 0), time(17, 0), berlin)
+        .weekdays(vec![Weekday::Mon, Weekday::Sat]);
+    assert_eq!(working_hours.get_weekdays(), &[Weekday::Mon, Weekday::Sat]);
+
+    // The window starts on Saturday, before the start of DST, and ends on Tuesday.
+    let local = |day, hour, minute| {
+        berlin
+            .with_ymd_and_hms(2023, 3, day, hour, minute, 0)
+            .unwrap()
+            .with_timezone(&Tz::UTC)
+    };
+    let query = FreeBusyQuery::new(local(25, 12, 0), local(28, 0, 0))
+        .working_hours(working_hours)
+        .min_free_slot(Duration::minutes(45));
+    let free_busy = query.compute([&standup, &focus]);
+
+    assert_eq!(
+        free_busy.busy,
+        periods(&[
+            (local(27, 9, 30), local(27, 10, 0)),
+            (local(27, 13, 0), local(27, 16, 0)),
+        ])
+    );
+    assert_eq!(
+        free_busy.free,
+        periods(&[
+            (local(25, 12, 0), local(25, 17, 0)),
+            (local(27, 10, 0), local(27, 13, 0)),
+            // 09:00 to 09:30 and 16:00 to 17:00 on Monday are too short.
+            (local(27, 16, 0), local(27, 17, 0)),
+        ])
+    );
+    assert_eq!(
+        free_busy.unavailable,
+        periods(&[
+            (local(25, 17, 0), local(27, 9, 0)),
+            (local(27, 17, 0), local(28, 0, 0)),
+        ])
+    );
+    // Berlin is an hour ahead of UTC in winter, and two hours in summer.
+    assert_eq!(
+        free_busy.unavailable[0].get_end(),
+        Some(ymd_hms(2023, 3, 27, 7, 0, 0))
+    );
+}
+
+#[test]
+fn supports_working_hours_over_midnight() {
+    let free_busy = FreeBusyQuery::new(ymd_hms(2023, 1, 2, 0, 0, 0), ymd_hms(2023, 1, 3, 0, 0, 0))
+        .working_hours(
+            WorkingHours::new(time(22, 0), time(6, 0), Tz::UTC)
+                .weekdays(vec![Weekday::Sun, Weekday::Mon]),
+        )
+        .compute([]);
+
+    assert!(free_busy.busy.is_empty());
+    assert_eq!(
+        free_busy.free,
+        periods(&[
+            // The working hours of Sunday end on Monday.
+            (ymd_hms(2023, 1, 2, 0, 0, 0), ymd_hms(2023, 1, 2, 6, 0, 0)),
+            (ymd_hms(2023, 1, 2, 22, 0, 0), ymd_hms(2023, 1, 3, 0, 0, 0)),
+        ])
+    );
+    assert_eq!(
+        free_busy.unavailable,
+        periods(&[(ymd_hms(2023, 1, 2, 6, 0, 0), ymd_hms(2023, 1, 2, 22, 0, 0))])
+    );
+}
+
+#[test]
+fn ignores_occurrences_without_length() {
+    let reminder = parse("DTSTART:20230102T090000Z\nRRULE:FREQ=HOURLY");
+    let free_busy = FreeBusyQuery::new(ymd_hms(2023, 1, 2, 0, 0, 0), ymd_hms(2023, 1, 3, 0, 0, 0))
+        .compute([&reminder]);
+
+    assert!(free_busy.busy.is_empty());
+    assert_eq!(
+        free_busy.to_string(),
+        "FREEBUSY;FBTYPE=FREE:20230102T000000Z/20230103T000000Z"
+    );
+}
+
+#[test]
+fn finds_busy_periods_from_the_first_supported_date() {
+    let night_shift = parse("DTSTART:20230101T220000Z\nDURATION:PT4H\nRRULE:FREQ=DAILY;COUNT=2");
+    let start = DateTime::<Utc>::MIN_UTC.with_timezone(&Tz::UTC);
+    let free_busy = FreeBusyQuery::new(start, ymd_hms(2023, 1, 3, 0, 0, 0)).compute([&night_shift]);
+
+    assert_eq!(
+        free_busy.busy,
+        periods(&[
+            (ymd_hms(2023, 1, 1, 22, 0, 0), ymd_hms(2023, 1, 2, 2, 0, 0)),
+            (ymd_hms(2023, 1, 2, 22, 0, 0), ymd_hms(2023, 1, 3, 0, 0, 0)),
+        ])
+    );
+}
+
+#[test]
+fn reports_incomplete_busy_periods() {
+    // There is no 29th of February between 2096 and 2104.
+    let leap_day =
+        parse("DTSTART:20970101T090000Z\nDURATION:PT1H\nRRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29")
+            .limits(Limits::default().max_iterations(5));
+    let free_busy = FreeBusyQuery::new(ymd_hms(2097, 1, 1, 0, 0, 0), ymd_hms(2105, 1, 1, 0, 0, 0))
+        .compute([&leap_day]);
+
+    assert!(free_busy.busy.is_empty());
+    assert_eq!(free_busy.reason, TerminationReason::MaxIterations);
+    assert!(free_busy.limited);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "jcal")]
+
+use crate::{NWeekday, ParseError, RRule, RRuleError, RRuleSet, Unvalidated, Weekday};
+use serde_json::json;
+
+#[test]
+fn rrule_set_to_jcal() {
+    let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20240101T090000\n\
+        RRULE:FREQ=MONTHLY;INTERVAL=2;BYDAY=1MO,-1FR;UNTIL=20241231T230000Z\n\
+        RDATE;TZID=Europe/Berlin:20240105T120000,20240106T120000\n\
+        EXDATE;TZID=Europe/Berlin:20240301T090000"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        rrule_set.to_jcal(),
+        vec![
+            json!(["dtstart", {"tzid": "Europe/Berlin"}, "date-time", "2024-01-01T09:00:00"]),
+            json!(["rrule", {}, "recur", {
+                "freq": "MONTHLY",
+                "interval": 2,
+                "until": "2024-12-31T23:00:00Z",
+                "byday": ["-1FR", "1MO"],
+                "byhour": 9,
+                "byminute": 0,
+                "bysecond": 0,
+            }]),
+            json!(["rdate", {"tzid": "Europe/Berlin"}, "date-time", "2024-01-05T12:00:00", "2024-01-06T12:00:00"]),
+            json!(["exdate", {"tzid": "Europe/Berlin"}, "date-time", "2024-03-01T09:00:00"]),
+        ]
+    );
+}
+
+#[test]
+fn rrule_set_to_and_from_jcal() {
+    let test_cases = [
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=5",
+        "DTSTART;TZID=America/New_York:20120201T093000\nDTEND;TZID=America/New_York:20120201T103000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=1,-1;WKST=SU",
+        "DTSTART;VALUE=DATE:20120201\nRRULE:FREQ=YEARLY;UNTIL=20150201\nEXDATE;VALUE=DATE:20130201",
+        "DTSTART:20120201T093000Z\nDURATION:PT1H\nRDATE;VALUE=PERIOD:20120301T100000Z/PT2H,20120302T100000Z/20120302T110000Z",
+    ];
+
+    for test_str in test_cases {
+        let rrule_set: RRuleSet = test_str.parse().unwrap();
+        let jcal = rrule_set.to_jcal();
+
+        assert_eq!(RRuleSet::from_jcal(&jcal).unwrap(), rrule_set, "{jcal:?}");
+    }
+}
+
+#[test]
+fn rrule_from_jcal() {
+    let rrule = RRule::<Unvalidated>::from_jcal(&json!({
+        "freq": "YEARLY",
+        "count": 3,
+        "bymonth": [1, 7],
+        "byday": "1MO",
+        "until": "2030-01-01",
+    }))
+    .unwrap();
+
+    assert_eq!(rrule.get_count(), Some(3));
+    assert_eq!(rrule.get_by_month(), &[1, 7]);
+    assert_eq!(rrule.get_by_weekday(), &[NWeekday::Nth(1, Weekday::Mon)]);
+    assert!(rrule.get_until().is_some());
+}
+
+#[test]
+fn rejects_invalid_jcal() {
+    let test_cases = [
+        (
+            vec![json!({"dtstart": "2024-01-01"})],
+            ParseError::InvalidJCalProperty(r#"{"dtstart":"2024-01-01"}"#.into()),
+        ),
+        (
+            vec![
+                json!(["dtstart", {}, "date-time", "2024-01-01T09:00:00Z"]),
+                json!(["rrule", {}, "recur", {"freq": "DAILY", "byhour": [24]}]),
+            ],
+            ParseError::InvalidByHour("24".into()),
+        ),
+        (
+            vec![
+                json!(["dtstart", {}, "date-time", "2024-01-01T09:00:00Z"]),
+                json!(["rrule", {}, "recur", {"freq": "DAILY", "count": true}]),
+            ],
+            ParseError::InvalidJCalValue("true".into()),
+        ),
+        (
+            vec![
+                json!(["dtstart", {}, "date-time", "2024-01-01T09:00:00Z"]),
+                json!(["rrule", {}, "text", "FREQ=DAILY"]),
+            ],
+            ParseError::UnsupportedValueType {
+                property: "RRULE".into(),
+                value: "TEXT".into(),
+            },
+        ),
+        (
+            vec![json!(["rrule", {}, "recur", {"freq": "DAILY"}])],
+            ParseError::MissingStartDate,
+        ),
+        (
+            vec![json!(["dtstart", {}, "date-time", "2024-01-01T09:00:00Z"])],
+            ParseError::MissingDateGenerationRules,
+        ),
+        (
+            vec![
+                json!(["dtstart", {}, "date-time", "1997-01-01T18:00:00Z"]),
+                json!(["rdate", {}, "period", "1997-01-01T18:00:00Z/P99999999D"]),
+            ],
+            ParseError::InvalidPeriod("19970101T180000Z/P99999999D".into()),
+        ),
+        (
+            vec![
+                json!(["dtstart", {}, "date-time", "1997-01-01T18:00:00Z"]),
+                json!(["duration", {}, "duration", "P99999999D"]),
+                json!(["rrule", {}, "recur", {"freq": "DAILY"}]),
+            ],
+            ParseError::InvalidDuration("P99999999D".into()),
+        ),
+    ];
+
+    for (jcal, error) in test_cases {
+        assert_eq!(
+            RRuleSet::from_jcal(&jcal).unwrap_err(),
+            RRuleError::ParserError(error)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::tests::common::ymd_hms;
+use crate::{Limits, RRule, RRuleError, RRuleSet, Unvalidated, ValidationError};
+
+#[test]
+fn default_limits_match_validation_limits() {
+    let limits = Limits::default();
+    assert_eq!(limits.get_year_range(), -10_000..=10_000);
+    assert_eq!(limits.get_max_interval(), u16::MAX);
+    assert_eq!(limits.get_max_iterations(), 100_000);
+
+    let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+    assert_eq!(rrule_set.get_limits(), &limits);
+}
+
+#[test]
+fn year_range_is_clamped_to_chrono() {
+    let limits = Limits::default().year_range(i32::MIN..=i32::MAX);
+    assert!(*limits.get_year_range().start() > i32::MIN);
+    assert!(*limits.get_year_range().end() < i32::MAX);
+}
+
+#[test]
+fn rejects_too_big_interval() {
+    let rrule: RRule<Unvalidated> = "FREQ=DAILY;INTERVAL=100".parse().unwrap();
+    let limits = Limits::default().max_interval(99);
+
+    assert!(matches!(
+        rrule
+            .clone()
+            .validate_with_limits(ymd_hms(2012, 2, 1, 9, 30, 0), &limits),
+        Err(RRuleError::ValidationError(
+            ValidationError::TooBigInterval(100)
+        ))
+    ));
+    assert!(rrule
+        .validate_with_limits(ymd_hms(2012, 2, 1, 9, 30, 0), &limits.max_interval(100))
+        .is_ok());
+}
+
+#[test]
+fn rejects_start_year_out_of_range() {
+    let rrule: RRule<Unvalidated> = "FREQ=DAILY".parse().unwrap();
+    let limits = Limits::default().year_range(2000..=2100);
+
+    assert!(matches!(
+        rrule
+            .clone()
+            .validate_with_limits(ymd_hms(1999, 12, 31, 9, 0, 0), &limits),
+        Err(RRuleError::ValidationError(
+            ValidationError::StartYearOutOfRange(1999)
+        ))
+    ));
+    assert!(rrule
+        .validate_with_limits(ymd_hms(2000, 1, 1, 9, 0, 0), &limits)
+        .is_ok());
+}
+
+#[test]
+fn parses_set_with_limits() {
+    let rrule_set = "DTSTART:20120201T093000Z\nRRULE:FREQ=WEEKLY;INTERVAL=10";
+    let limits = Limits::default().max_interval(5);
+
+    assert!(rrule_set.parse::<RRuleSet>().is_ok());
+    assert!(matches!(
+        RRuleSet::from_str_with_limits(rrule_set, limits),
+        Err(RRuleError::ValidationError(
+            ValidationError::TooBigInterval(10)
+        ))
+    ));
+
+    let rrule_set = RRuleSet::from_str_with_limits(rrule_set, Limits::default()).unwrap();
+    assert_eq!(rrule_set.get_limits(), &Limits::default());
+}
+
+#[test]
+fn iteration_stops_at_end_of_year_range() {
+    let limits = Limits::default().year_range(2000..=2010);
+    let rrule_set =
+        RRuleSet::from_str_with_limits("DTSTART:20000101T090000Z\nRRULE:FREQ=MONTHLY", limits)
+            .unwrap();
+
+    let dates = rrule_set.clone().all_unchecked();
+    assert_eq!(dates.len(), 11 * 12);
+    assert_eq!(dates.last(), Some(&ymd_hms(2010, 12, 1, 9, 0, 0)));
+
+    let dates = rrule_set
+        .before(ymd_hms(2010, 12, 31, 0, 0, 0))
+        .iter_rev()
+        .unwrap();
+    assert_eq!(dates.count(), 11 * 12);
+}
+
+#[test]
+fn iteration_stops_after_max_iterations() {
+    // There is no 29th of February between 2096 and 2104, so 7 periods are looked at in vain.
+    let rrule_set: RRuleSet = "DTSTART:20970101T090000Z\nRRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        rrule_set.clone().all(1).dates,
+        vec![ymd_hms(2104, 2, 29, 9, 0, 0)]
+    );
+    let rrule_set = rrule_set.limits(Limits::default().max_iterations(5));
+    assert!(rrule_set.clone().all(1).dates.is_empty());
+    assert_eq!(
+        rrule_set.all_unchecked().first(),
+        Some(&ymd_hms(2104, 2, 29, 9, 0, 0))
+    );
+}
@@ -1,10 +1,26 @@
 #![cfg(test)]
 
+mod budget;
+mod calendar;
 mod common;
+mod conflict;
+mod cursor;
+mod date_only;
 mod datetime;
 mod daylight_saving;
+mod explain;
+mod free_busy;
+mod jcal;
+mod limits;
+mod occurrence;
+mod period;
 mod regression;
 mod rfc_tests;
 mod rrule;
 mod rruleset;
 mod serde;
+mod set_operation;
+mod split;
+mod termination;
+mod text;
+mod xcal;
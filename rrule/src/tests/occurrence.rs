@@ -0,0 +1,391 @@
+use crate::tests::common::ymd_hms;
+#[cfg(feature = "exrule")]
+use crate::SourcedDateTime;
+use crate::{
+    ExclusionSource, ICalDuration, Occurrence, OccurrenceSource, ParseError, RRuleError, RRuleSet,
+    Tz,
+};
+use chrono::{DateTime, TimeZone, Utc};
+
+#[test]
+fn derives_end_from_dtend() {
+    let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nDTEND:20230101T103000Z\nRRULE:FREQ=DAILY;COUNT=2\nRDATE:20230105T120000Z"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        rrule_set.occurrences().collect::<Vec<_>>(),
+        vec![
+            Occurrence {
+                start: ymd_hms(2023, 1, 1, 9, 0, 0),
+                end: ymd_hms(2023, 1, 1, 10, 30, 0),
+                source: OccurrenceSource::RRule(0),
+            },
+            Occurrence {
+                start: ymd_hms(2023, 1, 2, 9, 0, 0),
+                end: ymd_hms(2023, 1, 2, 10, 30, 0),
+                source: OccurrenceSource::RRule(0),
+            },
+            Occurrence {
+                start: ymd_hms(2023, 1, 5, 12, 0, 0),
+                end: ymd_hms(2023, 1, 5, 13, 30, 0),
+                source: OccurrenceSource::RDate(0),
+            },
+        ]
+    );
+}
+
+#[test]
+fn adds_nominal_duration_across_dst() {
+    let berlin = Tz::Europe__Berlin;
+    let rrule_set: RRuleSet =
+        "DTSTART;TZID=Europe/Berlin:20230325T100000\nDURATION:P1D\nRRULE:FREQ=DAILY;COUNT=2"
+            .parse()
+            .unwrap();
+
+    let occurrences = rrule_set.occurrences().collect::<Vec<_>>();
+    assert_eq!(
+        occurrences
+            .iter()
+            .map(|occurrence| occurrence.end)
+            .collect::<Vec<_>>(),
+        vec![
+            berlin.with_ymd_and_hms(2023, 3, 26, 10, 0, 0).unwrap(),
+            berlin.with_ymd_and_hms(2023, 3, 27, 10, 0, 0).unwrap(),
+        ]
+    );
+    // The day of the DST change only has 23 hours.
+    assert_eq!(occurrences[0].duration(), chrono::Duration::hours(23));
+    assert_eq!(occurrences[1].duration(), chrono::Duration::hours(24));
+}
+
+#[test]
+fn all_day_events_last_for_their_days() {
+    let rrule_set: RRuleSet = "DTSTART;VALUE=DATE:20230101\nRRULE:FREQ=WEEKLY;COUNT=2"
+        .parse()
+        .unwrap();
+    let occurrence = rrule_set.occurrences().next().unwrap();
+    assert_eq!(occurrence.end, ymd_hms(2023, 1, 2, 0, 0, 0));
+
+    let rrule_set: RRuleSet =
+        "DTSTART;VALUE=DATE:20230101\nDTEND;VALUE=DATE:20230104\nRRULE:FREQ=WEEKLY;COUNT=2"
+            .parse()
+            .unwrap();
+    let occurrence = rrule_set.occurrences().nth(1).unwrap();
+    assert_eq!(occurrence.start, ymd_hms(2023, 1, 8, 0, 0, 0));
+    assert_eq!(occurrence.end, ymd_hms(2023, 1, 11, 0, 0, 0));
+}
+
+#[test]
+fn periods_keep_their_own_end() {
+    let rrule_set: RRuleSet =
+        "DTSTART:20230101T090000Z\nDURATION:PT1H\nRDATE;VALUE=PERIOD:20230102T090000Z/PT3H"
+            .parse()
+            .unwrap();
+
+    assert_eq!(
+        rrule_set.occurrences().collect::<Vec<_>>(),
+        vec![Occurrence {
+            start: ymd_hms(2023, 1, 2, 9, 0, 0),
+            end: ymd_hms(2023, 1, 2, 12, 0, 0),
+            source: OccurrenceSource::RDatePeriod(0),
+        }]
+    );
+}
+
+#[test]
+fn end_defaults_to_start() {
+    let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nRRULE:FREQ=DAILY;COUNT=1"
+        .parse()
+        .unwrap();
+    let occurrence = rrule_set.occurrences().next().unwrap();
+    assert_eq!(occurrence.start, occurrence.end);
+}
+
+#[test]
+fn rejects_invalid_ends() {
+    let tests = [
+        (
+            "DTSTART:20230101T090000Z\nDTEND:20230101T100000Z\nDURATION:PT1H\nRRULE:FREQ=DAILY",
+            ParseError::DtEndWithDuration,
+        ),
+        (
+            "DTSTART:20230101T090000Z\nDTEND:20230101T080000Z\nRRULE:FREQ=DAILY",
+            ParseError::DtEndBeforeDtStart,
+        ),
+        (
+            "DTSTART:20230101T090000Z\nDURATION:1H\nRRULE:FREQ=DAILY",
+            ParseError::InvalidDuration("1H".into()),
+        ),
+        (
+            "DTSTART:20230101T090000Z\nDURATION:-PT1H\nRRULE:FREQ=DAILY",
+            ParseError::NegativeDuration,
+        ),
+        (
+            "DTSTART:20230101T090000Z\nDTEND:20230101T100000Z\nDTEND:20230101T110000Z\nRRULE:FREQ=DAILY",
+            ParseError::DuplicateProperty("DTEND".into()),
+        ),
+        (
+            "DTSTART:20230101T090000Z\nDURATION:PT1H\nDURATION:PT2H\nRRULE:FREQ=DAILY",
+            ParseError::DuplicateProperty("DURATION".into()),
+        ),
+        (
+            "DTSTART:20230101T090000Z\nDURATION:P99999999D\nRRULE:FREQ=DAILY",
+            ParseError::InvalidDuration("P99999999D".into()),
+        ),
+        (
+            "DTSTART:19970101T180000Z\nRDATE;VALUE=PERIOD:19970101T180000Z/P99999999D",
+            ParseError::InvalidPeriod("19970101T180000Z/P99999999D".into()),
+        ),
+        (
+            "DTSTART:19970101T180000Z\nRDATE;VALUE=PERIOD:19970101T180000Z/PT4294967295H",
+            ParseError::InvalidPeriod("19970101T180000Z/PT4294967295H".into()),
+        ),
+    ];
+    for (input, expected_error) in tests {
+        assert_eq!(
+            input.parse::<RRuleSet>(),
+            Err(RRuleError::ParserError(expected_error))
+        );
+    }
+}
+
+#[test]
+fn dtend_and_duration_round_trip() {
+    let rrule_set = RRuleSet::new(ymd_hms(2023, 1, 1, 9, 0, 0))
+        .dt_end(ymd_hms(2023, 1, 1, 10, 0, 0))
+        .unwrap();
+    let rrule_set = rrule_set.rdate(ymd_hms(2023, 1, 1, 9, 0, 0));
+    assert_eq!(
+        rrule_set.to_string(),
+        "DTSTART:20230101T090000Z\nDTEND:20230101T100000Z\nRDATE;VALUE=DATE-TIME:20230101T090000Z"
+    );
+    assert_eq!(
+        rrule_set.to_string().parse::<RRuleSet>().unwrap(),
+        rrule_set
+    );
+
+    let rrule_set = rrule_set
+        .duration(ICalDuration {
+            minutes: 45,
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(rrule_set.get_dt_end(), None);
+    assert_eq!(
+        rrule_set.to_string(),
+        "DTSTART:20230101T090000Z\nDURATION:PT45M\nRDATE;VALUE=DATE-TIME:20230101T090000Z"
+    );
+    assert_eq!(
+        rrule_set.to_string().parse::<RRuleSet>().unwrap(),
+        rrule_set
+    );
+}
+
+#[test]
+fn builder_rejects_invalid_ends() {
+    let rrule_set = RRuleSet::new(ymd_hms(2023, 1, 1, 9, 0, 0));
+    assert_eq!(
+        rrule_set.clone().dt_end(ymd_hms(2023, 1, 1, 8, 0, 0)),
+        Err(RRuleError::ParserError(ParseError::DtEndBeforeDtStart))
+    );
+    assert_eq!(
+        rrule_set.clone().duration(ICalDuration {
+            negative: true,
+            hours: 1,
+            ..Default::default()
+        }),
+        Err(RRuleError::ParserError(ParseError::NegativeDuration))
+    );
+    assert!(rrule_set.dt_end(ymd_hms(2023, 1, 1, 9, 0, 0)).is_ok());
+}
+
+#[test]
+fn finds_occurrences_overlapping_a_range() {
+    let rrule_set: RRuleSet = "DTSTART:20230101T220000Z\nDURATION:PT4H\nRRULE:FREQ=DAILY;COUNT=5\nRDATE;VALUE=PERIOD:20221230T000000Z/P3DT1H"
+        .parse()
+        .unwrap();
+
+    let result = rrule_set.overlapping(
+        ymd_hms(2023, 1, 2, 0, 0, 0),
+        ymd_hms(2023, 1, 3, 0, 0, 0),
+        10,
+    );
+    assert!(!result.limited);
+    assert_eq!(
+        result
+            .occurrences
+            .iter()
+            .map(|occurrence| (occurrence.start, occurrence.source))
+            .collect::<Vec<_>>(),
+        vec![
+            // The period lasts until the 2nd.
+            (
+                ymd_hms(2022, 12, 30, 0, 0, 0),
+                OccurrenceSource::RDatePeriod(0)
+            ),
+            // The occurrence of the 1st lasts until the 2nd.
+            (ymd_hms(2023, 1, 1, 22, 0, 0), OccurrenceSource::RRule(0)),
+            (ymd_hms(2023, 1, 2, 22, 0, 0), OccurrenceSource::RRule(0)),
+        ]
+    );
+
+    // The end of the range is excluded, and so is the end of an occurrence.
+    let result = rrule_set.overlapping(
+        ymd_hms(2023, 1, 2, 2, 0, 0),
+        ymd_hms(2023, 1, 2, 22, 0, 0),
+        10,
+    );
+    assert_eq!(result.occurrences, vec![]);
+}
+
+#[test]
+fn finds_instant_occurrences_in_a_range() {
+    let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nRRULE:FREQ=HOURLY"
+        .parse()
+        .unwrap();
+
+    let result = rrule_set.overlapping(
+        ymd_hms(2023, 1, 1, 10, 0, 0),
+        ymd_hms(2023, 1, 1, 12, 0, 0),
+        10,
+    );
+    assert_eq!(
+        result
+            .occurrences
+            .iter()
+            .map(|occurrence| occurrence.start)
+            .collect::<Vec<_>>(),
+        vec![ymd_hms(2023, 1, 1, 10, 0, 0), ymd_hms(2023, 1, 1, 11, 0, 0)]
+    );
+
+    let result = rrule_set.overlapping(
+        ymd_hms(2023, 1, 1, 10, 0, 0),
+        ymd_hms(2023, 1, 2, 0, 0, 0),
+        3,
+    );
+    assert_eq!(result.occurrences.len(), 3);
+    assert!(result.limited);
+}
+
+#[test]
+fn finds_occurrences_from_the_first_supported_date() {
+    let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\nDURATION:PT2H\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+
+    let result = rrule_set.overlapping(
+        DateTime::<Utc>::MIN_UTC.with_timezone(&Tz::UTC),
+        ymd_hms(2023, 1, 2, 0, 0, 0),
+        10,
+    );
+    assert_eq!(result.occurrences.len(), 1);
+    assert_eq!(result.occurrences[0].start, ymd_hms(2023, 1, 1, 9, 0, 0));
+}
+
+#[cfg(feature = "exrule")]
+#[test]
+fn reports_the_source_of_each_date() {
+    let rrule_set: RRuleSet = "DTSTART:20230101T090000Z\n\
+        RRULE:FREQ=DAILY;COUNT=4\n\
+        RRULE:FREQ=WEEKLY;COUNT=2;BYHOUR=12\n\
+        RDATE:20230103T100000Z\n\
+        EXRULE:FREQ=DAILY;INTERVAL=3;COUNT=2\n\
+        EXDATE:20230102T090000Z"
+        .parse()
+        .unwrap();
+    let sourced = |date, source, excluded_by| SourcedDateTime {
+        date,
+        source,
+        excluded_by,
+    };
+
+    assert_eq!(
+        rrule_set.iter_sourced().collect::<Vec<_>>(),
+        vec![
+            sourced(
+                ymd_hms(2023, 1, 1, 12, 0, 0),
+                OccurrenceSource::RRule(1),
+                None
+            ),
+            sourced(
+                ymd_hms(2023, 1, 3, 9, 0, 0),
+                OccurrenceSource::RRule(0),
+                None
+            ),
+            sourced(
+                ymd_hms(2023, 1, 3, 10, 0, 0),
+                OccurrenceSource::RDate(0),
+                None
+            ),
+            sourced(
+                ymd_hms(2023, 1, 8, 12, 0, 0),
+                OccurrenceSource::RRule(1),
+                None
+            ),
+        ]
+    );
+    assert_eq!(
+        rrule_set
+            .iter_sourced()
+            .with_exclusions()
+            .collect::<Vec<_>>(),
+        vec![
+            sourced(
+                ymd_hms(2023, 1, 1, 9, 0, 0),
+                OccurrenceSource::RRule(0),
+                Some(ExclusionSource::ExRule(0))
+            ),
+            sourced(
+                ymd_hms(2023, 1, 1, 12, 0, 0),
+                OccurrenceSource::RRule(1),
+                None
+            ),
+            sourced(
+                ymd_hms(2023, 1, 2, 9, 0, 0),
+                OccurrenceSource::RRule(0),
+                Some(ExclusionSource::ExDate(0))
+            ),
+            sourced(
+                ymd_hms(2023, 1, 3, 9, 0, 0),
+                OccurrenceSource::RRule(0),
+                None
+            ),
+            sourced(
+                ymd_hms(2023, 1, 3, 10, 0, 0),
+                OccurrenceSource::RDate(0),
+                None
+            ),
+            sourced(
+                ymd_hms(2023, 1, 4, 9, 0, 0),
+                OccurrenceSource::RRule(0),
+                Some(ExclusionSource::ExRule(0))
+            ),
+            sourced(
+                ymd_hms(2023, 1, 8, 12, 0, 0),
+                OccurrenceSource::RRule(1),
+                None
+            ),
+        ]
+    );
+}
+
+#[test]
+fn reports_excluded_rdates() {
+    let rrule_set: RRuleSet =
+        "DTSTART:20230101T090000Z\nRDATE:20230102T090000Z,20230103T090000Z\nEXDATE:20230101T090000Z,20230103T090000Z"
+            .parse()
+            .unwrap();
+
+    assert_eq!(
+        rrule_set
+            .iter_sourced()
+            .with_exclusions()
+            .map(|date| (date.source, date.excluded_by))
+            .collect::<Vec<_>>(),
+        vec![
+            (OccurrenceSource::RDate(0), None),
+            (OccurrenceSource::RDate(1), Some(ExclusionSource::ExDate(1))),
+        ]
+    );
+}
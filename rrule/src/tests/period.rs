@@ -0,0 +1,64 @@
+use crate::tests::common::ymd_hms;
+use crate::{ICalDuration, Period, RRuleSet};
+
+#[test]
+fn iterates_rdate_periods_with_their_end() {
+    let rrule_set: RRuleSet = "DTSTART:19970101T090000Z\nRRULE:FREQ=DAILY;COUNT=2\nRDATE;VALUE=PERIOD:19970101T180000Z/PT5H30M,19970102T070000Z/19970102T080000Z"
+        .parse()
+        .unwrap();
+
+    let mut iter = rrule_set.into_iter();
+    let mut occurrences = vec![];
+    while let Some(occurrence) = iter.next_with_end() {
+        occurrences.push(occurrence);
+    }
+
+    assert_eq!(
+        occurrences,
+        vec![
+            (ymd_hms(1997, 1, 1, 9, 0, 0), None),
+            (
+                ymd_hms(1997, 1, 1, 18, 0, 0),
+                Some(ymd_hms(1997, 1, 1, 23, 30, 0))
+            ),
+            (
+                ymd_hms(1997, 1, 2, 7, 0, 0),
+                Some(ymd_hms(1997, 1, 2, 8, 0, 0))
+            ),
+            (ymd_hms(1997, 1, 2, 9, 0, 0), None),
+        ]
+    );
+}
+
+#[test]
+fn excludes_rdate_periods() {
+    let rrule_set: RRuleSet = "DTSTART:19970101T090000Z\nRDATE;VALUE=PERIOD:19970101T180000Z/PT1H,19970102T180000Z/PT1H\nEXDATE:19970101T180000Z"
+        .parse()
+        .unwrap();
+
+    assert_eq!(rrule_set.all(10).dates, vec![ymd_hms(1997, 1, 2, 18, 0, 0)]);
+}
+
+#[test]
+fn rdate_periods_round_trip() {
+    let rrule_set = RRuleSet::new(ymd_hms(1997, 1, 1, 9, 0, 0))
+        .rdate_period(Period::new(
+            ymd_hms(1997, 1, 1, 18, 0, 0),
+            ymd_hms(1997, 1, 2, 7, 0, 0),
+        ))
+        .rdate_period(Period::with_duration(
+            ymd_hms(1997, 1, 3, 18, 0, 0),
+            ICalDuration {
+                days: 1,
+                hours: 2,
+                ..Default::default()
+            },
+        ));
+
+    let rrule_set_str = rrule_set.to_string();
+    assert_eq!(
+        rrule_set_str,
+        "DTSTART:19970101T090000Z\nRDATE;VALUE=PERIOD:19970101T180000Z/19970102T070000Z,19970103T180000Z/P1DT2\n H"
+    );
+    assert_eq!(rrule_set_str.parse::<RRuleSet>().unwrap(), rrule_set);
+}
@@ -1,5 +1,6 @@
 use crate::tests::common::{check_occurrences, test_recurring_rrule_set, ymd_hms};
-use crate::{Frequency, NWeekday, RRule, RRuleSet, Weekday};
+use crate::{Frequency, NWeekday, RRule, RRuleSet, Tz, Weekday};
+use chrono::{DateTime, TimeZone, Utc};
 
 #[test]
 #[cfg(feature = "exrule")]
@@ -658,3 +659,263 @@ fn yearly_with_interval_2() {
         &[ymd_hms(1960, 1, 1, 9, 0, 0), ymd_hms(1962, 1, 1, 9, 0, 0)],
     );
 }
+
+#[test]
+fn after_skips_to_the_same_dates() {
+    let rrule_sets = [
+        "DTSTART:19900101T090000Z\nRRULE:FREQ=YEARLY;INTERVAL=3;BYMONTH=2;BYMONTHDAY=29",
+        "DTSTART:19900131T090000Z\nRRULE:FREQ=MONTHLY;INTERVAL=5;BYDAY=-1FR",
+        "DTSTART:19900131T090000Z\nRRULE:FREQ=MONTHLY;BYMONTHDAY=31",
+        "DTSTART:19900103T090000Z\nRRULE:FREQ=WEEKLY;INTERVAL=3;WKST=SU;BYDAY=SU,TU,SA",
+        "DTSTART:19900101T090000Z\nRRULE:FREQ=DAILY;INTERVAL=11;BYSETPOS=1",
+        "DTSTART;TZID=Europe/Berlin:19900101T023000\nRRULE:FREQ=DAILY",
+        "DTSTART:19900101T090000Z\nRRULE:FREQ=HOURLY;INTERVAL=7;BYHOUR=1,9,17",
+        "DTSTART:19900101T090000Z\nRRULE:FREQ=MINUTELY;INTERVAL=1447",
+        "DTSTART:19900101T090000Z\nRRULE:FREQ=SECONDLY;INTERVAL=40009",
+        "DTSTART:19900101T090000Z\nRRULE:FREQ=DAILY;INTERVAL=3\nRRULE:FREQ=WEEKLY;BYDAY=MO\n\
+        EXRULE:FREQ=MONTHLY;BYMONTHDAY=1,2,3\nRDATE:19950101T100000Z,20010203T040506Z",
+    ];
+    let afters = [
+        ymd_hms(1990, 1, 1, 9, 0, 0),
+        ymd_hms(1995, 1, 1, 10, 0, 0),
+        ymd_hms(2001, 2, 3, 4, 5, 6),
+        ymd_hms(2008, 3, 30, 0, 30, 0),
+    ];
+
+    for rrule_set in rrule_sets {
+        let rrule_set: RRuleSet = rrule_set.parse().unwrap();
+        for after in afters {
+            let expected = rrule_set
+                .into_iter()
+                .skip_while(|date| *date < after)
+                .take(20)
+                .collect::<Vec<_>>();
+            let skipped = rrule_set
+                .clone()
+                .after(after)
+                .into_iter()
+                .take(20)
+                .collect::<Vec<_>>();
+            assert_eq!(skipped, expected, "{rrule_set} after {after}");
+            assert_eq!(rrule_set.clone().after(after).all(20).dates, expected);
+        }
+    }
+}
+
+#[test]
+fn after_matches_a_full_walk_for_every_frequency() {
+    let rules = [
+        "FREQ=YEARLY;INTERVAL=2",
+        "FREQ=MONTHLY;INTERVAL=5",
+        "FREQ=WEEKLY;INTERVAL=3",
+        "FREQ=DAILY;INTERVAL=11",
+        "FREQ=HOURLY;INTERVAL=5",
+        "FREQ=MINUTELY;INTERVAL=15",
+        "FREQ=MINUTELY;INTERVAL=97",
+        "FREQ=SECONDLY;INTERVAL=4001",
+    ];
+    let filters = [
+        "",
+        ";BYHOUR=2,3",
+        ";BYHOUR=2,3;BYMINUTE=7,38,49",
+        ";BYMINUTE=7,38,49",
+        ";BYSECOND=0,30",
+        ";BYDAY=MO,TH",
+        ";BYDAY=MO,TH;BYHOUR=2,3",
+    ];
+    let afters = [ymd_hms(1990, 3, 1, 0, 0, 0), ymd_hms(1990, 3, 25, 3, 0, 0)];
+
+    for rule in rules {
+        for filter in filters {
+            let rrule_set: RRuleSet =
+                format!("DTSTART;TZID=Europe/Berlin:19900101T000000\nRRULE:{rule}{filter}")
+                    .parse()
+                    .unwrap();
+            for after in afters {
+                let expected = rrule_set
+                    .into_iter()
+                    .skip_while(|date| *date < after)
+                    .take(10)
+                    .collect::<Vec<_>>();
+                let skipped = rrule_set.clone().after(after).all(10).dates;
+                assert_eq!(skipped, expected, "{rrule_set} after {after}");
+                assert_eq!(
+                    rrule_set.next_after(after, true),
+                    expected.first().copied(),
+                    "{rrule_set} after {after}"
+                );
+                assert_eq!(
+                    rrule_set.previous_before(after, false),
+                    rrule_set
+                        .into_iter()
+                        .take_while(|date| *date < after)
+                        .last(),
+                    "{rrule_set} before {after}"
+                );
+                assert!(expected.iter().all(|date| rrule_set.contains(date)));
+            }
+        }
+    }
+}
+
+#[test]
+fn after_keeps_counting_from_the_start() {
+    let rrule_set: RRuleSet = "DTSTART:20200101T090000Z\nRRULE:FREQ=DAILY;COUNT=10"
+        .parse()
+        .unwrap();
+
+    let dates = rrule_set.after(ymd_hms(2020, 1, 8, 9, 0, 0)).all(10).dates;
+    assert_eq!(
+        dates,
+        [
+            ymd_hms(2020, 1, 8, 9, 0, 0),
+            ymd_hms(2020, 1, 9, 9, 0, 0),
+            ymd_hms(2020, 1, 10, 9, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn after_skips_far_ahead() {
+    // Generating every second since 1990 would take far too long.
+    let rrule_set: RRuleSet = "DTSTART:19900101T090000Z\nRRULE:FREQ=SECONDLY;INTERVAL=7"
+        .parse()
+        .unwrap();
+
+    let dates = rrule_set.after(ymd_hms(2030, 1, 1, 0, 0, 0)).all(2).dates;
+    assert_eq!(
+        dates,
+        [ymd_hms(2030, 1, 1, 0, 0, 5), ymd_hms(2030, 1, 1, 0, 0, 12)]
+    );
+}
+
+#[test]
+fn after_the_last_supported_date() {
+    let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:19970902T090000\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+    let max = DateTime::<Utc>::MAX_UTC.with_timezone(&Tz::UTC);
+    let min = DateTime::<Utc>::MIN_UTC.with_timezone(&Tz::UTC);
+
+    assert_eq!(rrule_set.clone().after(max).into_iter().next(), None);
+    assert_eq!(rrule_set.next_after(max, true), None);
+    assert_eq!(rrule_set.previous_before(min, true), None);
+    assert!(rrule_set.split_at(max).is_err());
+    assert_eq!(
+        rrule_set.clone().before(max).iter_rev().unwrap().next(),
+        None
+    );
+    assert_eq!(
+        rrule_set.next_after(min, true),
+        Some(
+            Tz::Europe__Berlin
+                .with_ymd_and_hms(1997, 9, 2, 9, 0, 0)
+                .unwrap()
+        )
+    );
+}
+
+#[test]
+fn iter_rev_returns_the_dates_in_reverse() {
+    let rrule_sets = [
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=YEARLY;UNTIL=20100101T000000Z;BYMONTH=2;BYMONTHDAY=29",
+        "DTSTART:19970131T090000Z\nRRULE:FREQ=MONTHLY;INTERVAL=5;BYDAY=-1FR;UNTIL=20050101T000000Z",
+        "DTSTART:19970103T090000Z\nRRULE:FREQ=WEEKLY;INTERVAL=3;WKST=SU;BYDAY=SU,TU,SA;COUNT=40",
+        "DTSTART:19970101T090000Z\nRRULE:FREQ=DAILY;INTERVAL=11;UNTIL=19990101T000000Z",
+        "DTSTART;TZID=Europe/Berlin:19970301T023000\nRRULE:FREQ=DAILY;UNTIL=19971101T000000Z",
+        "DTSTART:19970101T090000Z\nRRULE:FREQ=HOURLY;INTERVAL=7;BYHOUR=1,9,17;UNTIL=19970301T000000Z",
+        "DTSTART:19970101T090000Z\nRRULE:FREQ=MINUTELY;INTERVAL=1447;UNTIL=19970301T000000Z",
+        "DTSTART:19970101T090000Z\nRRULE:FREQ=DAILY;INTERVAL=3;COUNT=100\n\
+        RRULE:FREQ=WEEKLY;BYDAY=MO;UNTIL=19980101T000000Z\n\
+        EXRULE:FREQ=MONTHLY;BYMONTHDAY=1,2,3\nRDATE:19950101T100000Z,19970203T040506Z\n\
+        EXDATE:19970414T090000Z",
+    ];
+
+    for rrule_set in rrule_sets {
+        let rrule_set: RRuleSet = rrule_set.parse().unwrap();
+        let mut expected = rrule_set.clone().all(u16::MAX).dates;
+        expected.reverse();
+
+        let dates = rrule_set.iter_rev().unwrap().collect::<Vec<_>>();
+        assert_eq!(dates, expected, "{rrule_set}");
+    }
+}
+
+#[test]
+fn iter_rev_starts_at_before() {
+    let rrule_set: RRuleSet = "DTSTART:20200101T090000Z\nRRULE:FREQ=SECONDLY;INTERVAL=7"
+        .parse()
+        .unwrap();
+    assert!(rrule_set.iter_rev().is_err());
+
+    let dates = rrule_set
+        .before(ymd_hms(2030, 1, 1, 0, 0, 0))
+        .after(ymd_hms(2029, 12, 31, 23, 59, 40))
+        .iter_rev()
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dates,
+        [
+            ymd_hms(2029, 12, 31, 23, 59, 56),
+            ymd_hms(2029, 12, 31, 23, 59, 49),
+            ymd_hms(2029, 12, 31, 23, 59, 42),
+        ]
+    );
+}
+
+#[test]
+fn finds_next_and_previous_recurrences() {
+    let rrule_set: RRuleSet = "DTSTART:19970901T090000Z\nRRULE:FREQ=DAILY;INTERVAL=2\n\
+        RDATE:20300102T120000Z\nEXDATE:20300103T090000Z"
+        .parse()
+        .unwrap();
+
+    let dt = ymd_hms(2030, 1, 1, 9, 0, 0);
+    assert!(rrule_set.contains(&dt));
+    assert_eq!(rrule_set.next_after(dt, true), Some(dt));
+    assert_eq!(
+        rrule_set.next_after(dt, false),
+        Some(ymd_hms(2030, 1, 2, 12, 0, 0))
+    );
+    assert_eq!(rrule_set.previous_before(dt, true), Some(dt));
+    assert_eq!(
+        rrule_set.previous_before(dt, false),
+        Some(ymd_hms(2029, 12, 30, 9, 0, 0))
+    );
+
+    // Excluded recurrences are skipped.
+    let excluded = ymd_hms(2030, 1, 3, 9, 0, 0);
+    assert!(!rrule_set.contains(&excluded));
+    assert_eq!(
+        rrule_set.next_after(excluded, true),
+        Some(ymd_hms(2030, 1, 5, 9, 0, 0))
+    );
+    assert_eq!(
+        rrule_set.previous_before(excluded, true),
+        Some(ymd_hms(2030, 1, 2, 12, 0, 0))
+    );
+
+    // There is nothing before the start.
+    assert_eq!(
+        rrule_set.previous_before(ymd_hms(1997, 9, 1, 9, 0, 0), false),
+        None
+    );
+}
+
+#[test]
+fn finds_no_next_recurrence_after_the_end() {
+    let rrule_set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=3"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        rrule_set.next_after(ymd_hms(1997, 9, 4, 9, 0, 0), false),
+        None
+    );
+    assert_eq!(
+        rrule_set.previous_before(ymd_hms(2030, 1, 1, 0, 0, 0), false),
+        Some(ymd_hms(1997, 9, 4, 9, 0, 0))
+    );
+    assert!(!rrule_set.contains(&ymd_hms(1997, 9, 5, 9, 0, 0)));
+}
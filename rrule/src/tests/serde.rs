@@ -21,8 +21,7 @@ fn rrule_set_to_and_from_str() {
 #[cfg(feature = "serde")]
 #[test]
 fn serialize_deserialize_json_to_and_from_rrule_set() {
-    #[derive(orig_serde::Deserialize, orig_serde::Serialize, PartialEq, Eq, Debug)]
-    #[serde(crate = "orig_serde")]
+    #[derive(::serde::Deserialize, ::serde::Serialize, PartialEq, Eq, Debug)]
     struct RruleTest {
         rrule: RRuleSet,
     }
@@ -43,3 +42,133 @@ fn serialize_deserialize_json_to_and_from_rrule_set() {
         assert_eq!(src_obj, final_obj);
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_deserialize_json_to_and_from_cursor() {
+    use crate::RRuleSetCursor;
+
+    let rrule_set =
+        RRuleSet::from_str("DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=5").unwrap();
+    let mut iter = rrule_set.into_iter();
+    iter.next();
+
+    let cursor = iter.cursor();
+    let json = serde_json::to_string(&cursor).unwrap();
+    assert_eq!(
+        serde_json::from_str::<RRuleSetCursor>(&json).unwrap(),
+        cursor
+    );
+
+    let resumed = rrule_set.iter_from_cursor(&cursor).unwrap();
+    assert_eq!(resumed.count(), 4);
+}
+
+#[cfg(feature = "serde")]
+#[derive(::serde::Deserialize, ::serde::Serialize, PartialEq, Debug)]
+struct StructuredTest {
+    #[serde(with = "crate::structured")]
+    rrule_set: RRuleSet,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_structured_rrule_set() {
+    let rrule_set = RRuleSet::from_str(
+        "DTSTART;TZID=Europe/Berlin:20230102T090000\n\
+         RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,-1FR;UNTIL=20230301T000000Z\n\
+         EXDATE;TZID=Europe/Berlin:20230116T090000",
+    )
+    .unwrap();
+    let json = serde_json::to_value(StructuredTest { rrule_set }).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "rrule_set": {
+                "dtStart": "20230102T090000",
+                "tzid": "Europe/Berlin",
+                "rrule": [{
+                    "freq": "WEEKLY",
+                    "interval": 2,
+                    "until": "20230301T000000Z",
+                    "byWeekday": ["MO", "-1FR"],
+                    "byHour": [9],
+                    "byMinute": [0],
+                    "bySecond": [0],
+                }],
+                "exdate": ["20230116T090000"],
+            }
+        })
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn structured_rrule_set_round_trip() {
+    let test_cases = [
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=5",
+        "DTSTART;TZID=America/New_York:20120201T093000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=1,-1;WKST=SU\nRDATE;TZID=America/New_York:20120315T120000",
+        "DTSTART;VALUE=DATE:20120201\nRRULE:FREQ=YEARLY;UNTIL=20150201\nEXDATE;VALUE=DATE:20130201",
+        "DTSTART:20120201T093000Z\nDURATION:PT1H\nRDATE;VALUE=PERIOD:20120301T100000Z/PT2H",
+    ];
+
+    for test_str in test_cases {
+        let rrule_set = RRuleSet::from_str(test_str).unwrap();
+        let src_obj = StructuredTest { rrule_set };
+
+        let json = serde_json::to_string(&src_obj).unwrap();
+        let final_obj = serde_json::from_str::<StructuredTest>(&json).unwrap();
+
+        assert_eq!(src_obj, final_obj, "{json}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn structured_rrule() {
+    use crate::{NWeekday, RRule, Unvalidated, Weekday};
+
+    #[derive(::serde::Deserialize, ::serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::structured")]
+        rrule: RRule<Unvalidated>,
+    }
+
+    let json = r#"{"rrule":{"freq":"MONTHLY","count":3,"byWeekday":["1MO"]}}"#;
+    let test = serde_json::from_str::<Test>(json).unwrap();
+    assert_eq!(test.rrule.get_count(), Some(3));
+    assert_eq!(
+        test.rrule.get_by_weekday(),
+        &[NWeekday::Nth(1, Weekday::Mon)]
+    );
+    assert_eq!(serde_json::to_string(&test).unwrap(), json);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn structured_rrule_set_is_validated() {
+    let test_cases = [
+        (
+            r#"{"rrule_set":{"dtStart":"20120201T093000Z","rrule":[{"freq":"DAILY","byHour":[24]}]}}"#,
+            "`24` is not a valid BYHOUR value",
+        ),
+        (
+            r#"{"rrule_set":{"dtStart":"20120201T093000Z","tzid":"Mars/Olympus","rdate":["20120202T093000"]}}"#,
+            "`Mars/Olympus` is not a valid timezone",
+        ),
+        (
+            r#"{"rrule_set":{"dtStart":"20120201T093000Z"}}"#,
+            "Missing date generation property",
+        ),
+        (
+            r#"{"rrule_set":{"dtStart":"20120201T093000Z","rrule":[{"freq":"DAILY","byDay":["MO"]}]}}"#,
+            "unknown field `byDay`",
+        ),
+    ];
+
+    for (json, message) in test_cases {
+        let err = serde_json::from_str::<StructuredTest>(json).unwrap_err();
+        assert!(err.to_string().contains(message), "{err}");
+    }
+}
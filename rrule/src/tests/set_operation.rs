@@ -0,0 +1,105 @@
+use chrono::{DateTime, Datelike, TimeZone};
+
+use crate::tests::common::{parse, ymd_hms};
+use crate::{SetOperations, Tz};
+
+fn days(dates: impl Iterator<Item = DateTime<Tz>>) -> Vec<u32> {
+    dates.map(|date| date.day()).collect()
+}
+
+#[test]
+fn combines_two_sets() {
+    let odd = parse("DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;INTERVAL=2;COUNT=5");
+    let first_week = parse("DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;COUNT=7");
+
+    assert_eq!(
+        days((&odd).into_iter().union(&first_week)),
+        vec![1, 2, 3, 4, 5, 6, 7, 9]
+    );
+    assert_eq!(
+        days((&odd).into_iter().intersection(&first_week)),
+        vec![1, 3, 5, 7]
+    );
+    assert_eq!(days((&odd).into_iter().difference(&first_week)), vec![9]);
+    assert_eq!(
+        days((&first_week).into_iter().difference(&odd)),
+        vec![2, 4, 6]
+    );
+    assert_eq!(
+        days((&odd).into_iter().symmetric_difference(&first_week)),
+        vec![2, 4, 6, 9]
+    );
+}
+
+#[test]
+fn compares_dates_across_timezones() {
+    let utc = parse("DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;COUNT=3");
+    let berlin = parse("DTSTART;TZID=Europe/Berlin:20240102T100000\nRRULE:FREQ=DAILY;COUNT=3");
+
+    let dates = (&utc).into_iter().intersection(&berlin).collect::<Vec<_>>();
+    assert_eq!(
+        dates,
+        vec![ymd_hms(2024, 1, 2, 9, 0, 0), ymd_hms(2024, 1, 3, 9, 0, 0)]
+    );
+    // The date of the left set is returned.
+    assert_eq!(dates[0].timezone(), Tz::UTC);
+
+    let dates = (&berlin).into_iter().union(&utc).collect::<Vec<_>>();
+    assert_eq!(dates.len(), 4);
+    assert_eq!(dates[0], ymd_hms(2024, 1, 1, 9, 0, 0));
+    assert_eq!(dates[1].timezone(), Tz::Europe__Berlin);
+}
+
+#[test]
+fn removes_duplicates() {
+    let dates = vec![
+        ymd_hms(2024, 1, 1, 9, 0, 0),
+        ymd_hms(2024, 1, 1, 9, 0, 0),
+        Tz::Europe__Berlin
+            .with_ymd_and_hms(2024, 1, 1, 10, 0, 0)
+            .unwrap(),
+        ymd_hms(2024, 1, 2, 9, 0, 0),
+    ];
+    let other = vec![ymd_hms(2024, 1, 2, 9, 0, 0), ymd_hms(2024, 1, 2, 9, 0, 0)];
+
+    assert_eq!(
+        days(dates.clone().into_iter().union(other.clone())),
+        vec![1, 2]
+    );
+    assert_eq!(days(dates.into_iter().symmetric_difference(other)), vec![1]);
+}
+
+#[test]
+fn stops_on_infinite_sets() {
+    let daily = parse("DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY");
+    let few = parse("DTSTART:20240101T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3");
+
+    assert_eq!(
+        days((&daily).into_iter().intersection(&few)),
+        vec![1, 8, 15]
+    );
+    assert_eq!(
+        days((&few).into_iter().difference(&daily)),
+        Vec::<u32>::new()
+    );
+    assert_eq!(
+        days((&daily).into_iter().union(&few).take(3)),
+        vec![1, 2, 3]
+    );
+}
+
+#[test]
+fn composes_operations() {
+    let standups = parse("DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;COUNT=10");
+    let holidays = parse("DTSTART:20240101T090000Z\nRDATE:20240101T090000Z,20240105T090000Z");
+    let extra = parse("DTSTART:20240101T090000Z\nRDATE:20240120T090000Z");
+    let mondays = parse("DTSTART:20240101T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO")
+        .before(ymd_hms(2024, 2, 1, 0, 0, 0));
+
+    let dates = (&standups)
+        .into_iter()
+        .difference(&holidays)
+        .union(&extra)
+        .difference(&mondays);
+    assert_eq!(days(dates), vec![2, 3, 4, 6, 7, 9, 10, 20]);
+}
@@ -0,0 +1,232 @@
+use crate::tests::common::{parse, ymd_hms};
+use crate::{RRuleError, RRuleSet, Tz, ValidationError};
+use chrono::{DateTime, TimeZone};
+
+/// Checks that the two parts have the recurrences of the set before and from `dt`.
+fn assert_split(rrule_set: &RRuleSet, dt: DateTime<Tz>) -> (RRuleSet, RRuleSet) {
+    let (first, second) = rrule_set.split_at(dt).unwrap();
+    let dates = rrule_set.clone().all(200).dates;
+    let first_dates = first.clone().all(200).dates;
+    let second_dates = second.clone().all(200).dates;
+
+    assert_eq!(second_dates.first(), Some(&dt));
+    assert_eq!(
+        first_dates,
+        dates
+            .iter()
+            .filter(|date| **date < dt)
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        [first_dates, second_dates].concat()[..dates.len()],
+        dates[..]
+    );
+    (first, second)
+}
+
+#[test]
+fn splits_count_into_both_parts() {
+    let rrule_set = parse(
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY;COUNT=10\nRRULE:FREQ=WEEKLY;COUNT=4;BYHOUR=12\nRDATE:20230103T100000Z,20230110T100000Z\nEXDATE:20230104T090000Z,20230108T090000Z",
+    );
+    let (first, second) = assert_split(&rrule_set, ymd_hms(2023, 1, 6, 9, 0, 0));
+
+    assert_eq!(
+        first.to_string(),
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY;COUNT=4;BYHOUR=9;BYMINUTE=0;BYSECOND=0\nRRULE:FREQ=WEEKLY;COUNT=1;BYHOUR=12;BYMINUTE=0;BYSECOND=0;BYDAY=MO\nRDATE;VALUE=DATE-TIME:20230103T100000Z\nEXDATE;VALUE=DATE-TIME:20230104T090000Z"
+    );
+    assert_eq!(
+        second.to_string(),
+        "DTSTART:20230106T090000Z\nRRULE:FREQ=DAILY;COUNT=6;BYHOUR=9;BYMINUTE=0;BYSECOND=0\nRRULE:FREQ=WEEKLY;COUNT=3;BYHOUR=12;BYMINUTE=0;BYSECOND=0;BYDAY=MO\nRDATE;VALUE=DATE-TIME:20230110T100000Z\nEXDATE;VALUE=DATE-TIME:20230108T090000Z"
+    );
+}
+
+#[test]
+fn ends_first_part_with_until() {
+    let rrule_set = parse(
+        "DTSTART;TZID=America/New_York:20230301T180000\nDURATION:PT1H\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=WE,FR",
+    );
+    let dt = Tz::America__New_York
+        .with_ymd_and_hms(2023, 3, 15, 18, 0, 0)
+        .unwrap();
+    let (first, second) = assert_split(&rrule_set.clone().before(ymd_hms(2023, 6, 1, 0, 0, 0)), dt);
+
+    // The last recurrence before the split is on the 3rd at 18:00 EST.
+    assert_eq!(
+        first.get_rrule()[0].get_until(),
+        Some(&ymd_hms(2023, 3, 3, 23, 0, 0))
+    );
+    assert_eq!(first.all(10).dates.len(), 2);
+    assert_eq!(second.get_dt_start().timezone(), Tz::America__New_York);
+    assert_eq!(second.get_rrule()[0].get_until(), None);
+    assert_eq!(second.get_duration(), rrule_set.get_duration());
+    assert!(second.to_string().starts_with(
+        "DTSTART;TZID=America/New_York:20230315T180000\nDURATION:PT1H\nRRULE:FREQ=WEEKLY;INTERVAL=2;"
+    ));
+}
+
+#[test]
+fn keeps_until_in_second_part() {
+    let rrule_set = parse(
+        "DTSTART:20230101T090000\nDTEND:20230101T100000\nRRULE:FREQ=MONTHLY;UNTIL=20231201T090000",
+    );
+    let dt = Tz::LOCAL.with_ymd_and_hms(2023, 6, 1, 9, 0, 0).unwrap();
+    let (first, second) = assert_split(&rrule_set, dt);
+
+    assert_eq!(
+        first.to_string(),
+        "DTSTART:20230101T090000\nDTEND:20230101T100000\nRRULE:FREQ=MONTHLY;UNTIL=20230501T090000;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=0;B\n YSECOND=0"
+    );
+    assert_eq!(
+        second.to_string(),
+        "DTSTART:20230601T090000\nDTEND:20230601T100000\nRRULE:FREQ=MONTHLY;UNTIL=20231201T090000;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=0;B\n YSECOND=0"
+    );
+}
+
+#[test]
+fn splits_date_only_sets() {
+    let rrule_set = parse("DTSTART;VALUE=DATE:20230101\nRRULE:FREQ=YEARLY");
+    let dt = Tz::UTC.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let (first, second) = assert_split(&rrule_set.before(ymd_hms(2030, 1, 1, 0, 0, 0)), dt);
+
+    assert_eq!(
+        first.to_string(),
+        "DTSTART;VALUE=DATE:20230101\nRRULE:FREQ=YEARLY;UNTIL=20240101;BYMONTH=1;BYMONTHDAY=1"
+    );
+    assert_eq!(
+        second.to_string(),
+        "DTSTART;VALUE=DATE:20250101\nRRULE:FREQ=YEARLY;BYMONTH=1;BYMONTHDAY=1"
+    );
+}
+
+#[test]
+fn leaves_out_rrules_without_recurrences_in_a_part() {
+    let rrule_set = parse(
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY;COUNT=2\nRRULE:FREQ=DAILY;COUNT=5;BYHOUR=12\nRDATE:20230201T090000Z",
+    );
+    let (first, second) = assert_split(&rrule_set, ymd_hms(2023, 1, 5, 12, 0, 0));
+    assert_eq!(first.get_rrule().len(), 2);
+    assert_eq!(second.get_rrule().len(), 1);
+    assert_eq!(second.get_rrule()[0].get_count(), Some(2));
+
+    let (first, second) = assert_split(&rrule_set, ymd_hms(2023, 2, 1, 9, 0, 0));
+    assert_eq!(first.get_rrule().len(), 2);
+    assert!(first.get_rdate().is_empty());
+    assert!(second.get_rrule().is_empty());
+    assert_eq!(second.get_rdate(), &vec![ymd_hms(2023, 2, 1, 9, 0, 0)]);
+}
+
+#[test]
+fn rejects_dates_which_are_not_recurrences() {
+    let rrule_set = parse("DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY\nEXDATE:20230104T090000Z");
+
+    for dt in [
+        ymd_hms(2023, 1, 2, 9, 0, 0),
+        ymd_hms(2023, 1, 3, 10, 0, 0),
+        ymd_hms(2023, 1, 4, 9, 0, 0),
+    ] {
+        assert_eq!(
+            rrule_set.split_at(dt),
+            Err(RRuleError::ValidationError(
+                ValidationError::InvalidSplitDate(dt.to_rfc3339())
+            ))
+        );
+    }
+}
+
+#[test]
+fn keeps_the_dates_of_rrules_out_of_step_with_the_split() {
+    let rrule_set = parse(
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;UNTIL=20230301T090000Z",
+    );
+    // The second week isn't a period of the rrule with `INTERVAL=2`.
+    let (first, second) = assert_split(&rrule_set, ymd_hms(2023, 1, 9, 9, 0, 0));
+    assert_eq!(first.get_rrule().len(), 2);
+    assert_eq!(second.get_rrule().len(), 1);
+    assert_eq!(
+        second.get_rdate(),
+        &vec![
+            ymd_hms(2023, 1, 17, 9, 0, 0),
+            ymd_hms(2023, 1, 31, 9, 0, 0),
+            ymd_hms(2023, 2, 14, 9, 0, 0),
+            ymd_hms(2023, 2, 28, 9, 0, 0),
+        ]
+    );
+
+    // The third week is, so the rrule is kept.
+    let (_, second) = assert_split(&rrule_set, ymd_hms(2023, 1, 16, 9, 0, 0));
+    assert_eq!(second.get_rrule().len(), 2);
+    assert!(second.get_rdate().is_empty());
+
+    let rrule_set = parse(
+        "DTSTART:20230115T090000Z\nRRULE:FREQ=MONTHLY;INTERVAL=3;COUNT=6\nRDATE:20230215T090000Z",
+    );
+    let (first, second) = assert_split(&rrule_set, ymd_hms(2023, 2, 15, 9, 0, 0));
+    assert_eq!(first.get_rrule()[0].get_count(), Some(1));
+    assert!(second.get_rrule().is_empty());
+    assert_eq!(second.get_rdate().len(), 6);
+}
+
+#[test]
+fn rejects_endless_rrules_out_of_step_with_the_split() {
+    let rrule_set = parse(
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU",
+    );
+    let dt = ymd_hms(2023, 1, 9, 9, 0, 0);
+    assert_eq!(
+        rrule_set.split_at(dt),
+        Err(RRuleError::ValidationError(
+            ValidationError::MisalignedSplitDate {
+                rrule: rrule_set.get_rrule()[1].to_string(),
+                dt: dt.to_rfc3339(),
+            }
+        ))
+    );
+}
+
+#[cfg(feature = "exrule")]
+#[test]
+fn keeps_the_dates_of_exrules_out_of_step_with_the_split() {
+    let rrule_set = parse(
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY\nEXRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO;UNTIL=20230301T090000Z",
+    );
+    let (first, second) = assert_split(&rrule_set, ymd_hms(2023, 1, 9, 9, 0, 0));
+    assert_eq!(first.get_exrule(), rrule_set.get_exrule());
+    assert!(second.get_exrule().is_empty());
+    assert_eq!(
+        second.get_exdate(),
+        &vec![
+            ymd_hms(2023, 1, 16, 9, 0, 0),
+            ymd_hms(2023, 1, 30, 9, 0, 0),
+            ymd_hms(2023, 2, 13, 9, 0, 0),
+            ymd_hms(2023, 2, 27, 9, 0, 0),
+        ]
+    );
+
+    let (_, second) = assert_split(&rrule_set, ymd_hms(2023, 1, 17, 9, 0, 0));
+    assert_eq!(second.get_exrule(), rrule_set.get_exrule());
+
+    let rrule_set =
+        parse("DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY\nEXRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO");
+    assert!(matches!(
+        rrule_set.split_at(ymd_hms(2023, 1, 9, 9, 0, 0)),
+        Err(RRuleError::ValidationError(
+            ValidationError::MisalignedSplitDate { .. }
+        ))
+    ));
+}
+
+#[cfg(feature = "exrule")]
+#[test]
+fn splits_exrules_with_count() {
+    let rrule_set = parse(
+        "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY;COUNT=10\nEXRULE:FREQ=DAILY;INTERVAL=3;COUNT=3",
+    );
+    let (first, second) = assert_split(&rrule_set, ymd_hms(2023, 1, 6, 9, 0, 0));
+
+    assert_eq!(first.get_exrule(), rrule_set.get_exrule());
+    // The exrule removes the 2nd, 5th and 8th.
+    assert!(second.get_exrule().is_empty());
+    assert_eq!(second.get_exdate(), &vec![ymd_hms(2023, 1, 8, 9, 0, 0)]);
+}
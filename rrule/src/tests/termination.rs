@@ -0,0 +1,90 @@
+use crate::tests::common::ymd_hms;
+use crate::{CancellationToken, Limits, RRuleSet, TerminationReason};
+
+#[test]
+fn reports_finished_and_limit() {
+    let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3"
+        .parse()
+        .unwrap();
+
+    let result = rrule_set.clone().all(3);
+    assert_eq!(result.dates.len(), 3);
+    assert_eq!(result.reason, TerminationReason::Limit);
+    assert!(result.limited);
+
+    let result = rrule_set.clone().all(4);
+    assert_eq!(result.dates.len(), 3);
+    assert_eq!(result.reason, TerminationReason::Finished);
+    assert!(!result.limited);
+
+    let result = rrule_set.before(ymd_hms(2012, 2, 2, 9, 30, 0)).all(10);
+    assert_eq!(result.dates.len(), 2);
+    assert_eq!(result.reason, TerminationReason::Finished);
+    assert!(!result.limited);
+}
+
+#[test]
+fn reports_max_iterations() {
+    // There is no 29th of February between 2096 and 2104.
+    let rrule_set: RRuleSet =
+        "DTSTART:20970101T090000Z\nRRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29;COUNT=1"
+            .parse::<RRuleSet>()
+            .unwrap()
+            .limits(Limits::default().max_iterations(5));
+
+    let result = rrule_set.clone().all(10);
+    assert!(result.dates.is_empty());
+    assert_eq!(result.reason, TerminationReason::MaxIterations);
+    assert!(result.limited);
+
+    let mut iter = rrule_set.limit().into_iter();
+    assert_eq!(iter.termination_reason(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(
+        iter.termination_reason(),
+        Some(TerminationReason::MaxIterations)
+    );
+}
+
+#[test]
+fn reports_out_of_range() {
+    let rrule_set = RRuleSet::from_str_with_limits(
+        "DTSTART:20000101T090000Z\nRRULE:FREQ=YEARLY",
+        Limits::default().year_range(2000..=2010),
+    )
+    .unwrap();
+
+    let result = rrule_set.clone().all(100);
+    assert_eq!(result.dates.len(), 11);
+    assert_eq!(result.reason, TerminationReason::OutOfRange);
+    assert!(!result.limited);
+
+    let mut iter = rrule_set
+        .before(ymd_hms(2020, 1, 1, 0, 0, 0))
+        .iter_rev()
+        .unwrap();
+    assert_eq!(iter.next(), None);
+    assert_eq!(
+        iter.termination_reason(),
+        Some(TerminationReason::OutOfRange)
+    );
+}
+
+#[test]
+fn reports_timed_out() {
+    let token = CancellationToken::new();
+    let rrule_set: RRuleSet = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY"
+        .parse::<RRuleSet>()
+        .unwrap()
+        .cancellation_token(token.clone());
+
+    let mut iter = rrule_set.clone().into_iter();
+    assert!(iter.next().is_some());
+    token.cancel();
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.termination_reason(), Some(TerminationReason::TimedOut));
+
+    let result = rrule_set.all(10);
+    assert_eq!(result.reason, TerminationReason::TimedOut);
+    assert!(result.timed_out());
+}
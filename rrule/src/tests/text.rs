@@ -0,0 +1,350 @@
+use crate::{Locale, ParseError, RRule, RRuleSet, Tz};
+use chrono::TimeZone;
+
+#[test]
+fn describes_rrules_in_english() {
+    let tests = [
+        ("FREQ=DAILY", "every day at 09:00"),
+        (
+            "FREQ=DAILY;INTERVAL=3;COUNT=1",
+            "every 3 days at 09:00 once",
+        ),
+        (
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR",
+            "every 2 weeks on Monday and Friday at 09:00",
+        ),
+        ("FREQ=WEEKLY", "every week on Monday at 09:00"),
+        (
+            "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=9,17;BYMINUTE=30",
+            "every weekday at 09:30 and 17:30",
+        ),
+        (
+            "FREQ=MONTHLY;COUNT=5",
+            "every month on the 2nd at 09:00 for 5 times",
+        ),
+        (
+            "FREQ=MONTHLY;BYMONTHDAY=1,-1",
+            "every month on the 1st and last day at 09:00",
+        ),
+        (
+            "FREQ=MONTHLY;BYDAY=2TU,-1FR",
+            "every month on the last Friday and the 2nd Tuesday at 09:00",
+        ),
+        (
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            "every month on Monday, Tuesday, Wednesday, Thursday and Friday at 09:00, \
+            only the last occurrence of each month",
+        ),
+        (
+            "FREQ=YEARLY;UNTIL=20301231T000000Z",
+            "every year in January on the 2nd at 09:00 until December 31, 2030",
+        ),
+        (
+            "FREQ=YEARLY;BYMONTH=3,11;BYDAY=1SU",
+            "every year in March and November on the 1st Sunday at 09:00",
+        ),
+        (
+            "FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+            "every year in week 20 on Monday at 09:00",
+        ),
+        (
+            "FREQ=YEARLY;BYYEARDAY=1,100,-1",
+            "every year on the 1st, 100th and last day of the year at 09:00",
+        ),
+        ("FREQ=HOURLY;INTERVAL=4", "every 4 hours"),
+        (
+            "FREQ=HOURLY;BYHOUR=9,17;BYMINUTE=15",
+            "every hour at hours 9 and 17 and minute 15",
+        ),
+        (
+            "FREQ=MINUTELY;INTERVAL=15;COUNT=4",
+            "every 15 minutes for 4 times",
+        ),
+        (
+            "FREQ=SECONDLY;BYSECOND=0,30",
+            "every second at seconds 0 and 30",
+        ),
+    ];
+
+    for (rrule, expected) in tests {
+        let rrule_set: RRuleSet = format!("DTSTART:20230102T090000Z\nRRULE:{rrule}")
+            .parse()
+            .unwrap();
+        assert_eq!(rrule_set.get_rrule()[0].to_text(), expected, "{rrule}");
+        assert_eq!(describe(rrule, &crate::English), expected, "{rrule}");
+    }
+}
+
+#[test]
+fn describes_sets_in_english() {
+    let rrule_set: RRuleSet = "DTSTART:20230102T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO\n\
+        RRULE:FREQ=MONTHLY;BYMONTHDAY=15;BYHOUR=12\nRDATE:20230105T103000Z,20230104T120000Z\n\
+        EXDATE:20230109T090000Z"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        rrule_set.to_text(),
+        "every week on Monday at 09:00 and every month on the 15th at 12:00, \
+        also on January 4, 2023 at 12:00 and January 5, 2023 at 10:30, \
+        except on January 9, 2023 at 09:00"
+    );
+
+    let rrule_set: RRuleSet = "DTSTART;VALUE=DATE:20230102\nRRULE:FREQ=YEARLY\n\
+        EXDATE;VALUE=DATE:20240102"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        rrule_set.to_text(),
+        "every year in January on the 2nd, except on January 2, 2024"
+    );
+
+    let rrule_set: RRuleSet = "DTSTART:20230102T090000Z\nRDATE:20230103T090000Z"
+        .parse()
+        .unwrap();
+    assert_eq!(rrule_set.to_text(), "on January 3, 2023 at 09:00");
+}
+
+fn describe(rrule: &str, locale: &dyn Locale) -> String {
+    let rrule_set: RRuleSet = format!("DTSTART:20230102T090000Z\nRRULE:{rrule}")
+        .parse()
+        .unwrap();
+    rrule_set.get_rrule()[0].to_localized_text(locale)
+}
+
+#[cfg(feature = "locale-de")]
+#[test]
+fn describes_rrules_in_german() {
+    let tests = [
+        ("FREQ=DAILY;COUNT=5", "jeden Tag um 09:00 5 Mal"),
+        (
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR",
+            "alle 2 Wochen am Montag und Freitag um 09:00",
+        ),
+        (
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            "jeden Monat am Montag, Dienstag, Mittwoch, Donnerstag und Freitag um 09:00, \
+            nur das letzte Vorkommen jedes Monats",
+        ),
+        (
+            "FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU;UNTIL=20301231T000000Z",
+            "jedes Jahr im März am letzten Sonntag um 09:00 bis zum 31. Dezember 2030",
+        ),
+        ("FREQ=HOURLY;BYHOUR=9,17", "jede Stunde in Stunden 9 und 17"),
+    ];
+    for (rrule, expected) in tests {
+        assert_eq!(describe(rrule, &crate::German), expected, "{rrule}");
+    }
+
+    let rrule_set: RRuleSet = "DTSTART:20230102T090000Z\nRRULE:FREQ=DAILY\nEXDATE:20230105T090000Z"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        rrule_set.to_localized_text(&crate::German),
+        "jeden Tag um 09:00, außer am 5. Januar 2023 um 09:00"
+    );
+}
+
+#[cfg(feature = "locale-fr")]
+#[test]
+fn describes_rrules_in_french() {
+    let tests = [
+        ("FREQ=DAILY;COUNT=1", "chaque jour à 09:00 une seule fois"),
+        (
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR",
+            "toutes les 2 semaines le lundi et le vendredi à 09:00",
+        ),
+        (
+            "FREQ=MONTHLY;BYMONTHDAY=1,-1",
+            "chaque mois le 1er et dernier jour à 09:00",
+        ),
+        (
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1,-1",
+            "chaque mois le lundi, le mardi, le mercredi, le jeudi et le vendredi à 09:00, \
+            uniquement les 1re et dernière occurrences de chaque mois",
+        ),
+        (
+            "FREQ=YEARLY;INTERVAL=2;UNTIL=20300101T000000Z",
+            "tous les 2 ans en janvier le 2 à 09:00 jusqu'au 1er janvier 2030",
+        ),
+        ("FREQ=HOURLY;BYHOUR=9,17", "chaque heure aux heures 9 et 17"),
+    ];
+    for (rrule, expected) in tests {
+        assert_eq!(describe(rrule, &crate::French), expected, "{rrule}");
+    }
+}
+
+#[cfg(feature = "locale-es")]
+#[test]
+fn describes_rrules_in_spanish() {
+    let tests = [
+        ("FREQ=DAILY;COUNT=3", "cada día a las 09:00 3 veces"),
+        (
+            "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR",
+            "de lunes a viernes a las 09:00",
+        ),
+        (
+            "FREQ=MONTHLY;BYMONTHDAY=15,-1",
+            "cada mes el día 15 y el último día a las 09:00",
+        ),
+        (
+            "FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;UNTIL=20301231T000000Z",
+            "cada año en noviembre el 4º jueves a las 09:00 hasta el 31 de diciembre de 2030",
+        ),
+        (
+            "FREQ=MINUTELY;INTERVAL=15;BYSECOND=30",
+            "cada 15 minutos en el segundo 30",
+        ),
+    ];
+    for (rrule, expected) in tests {
+        assert_eq!(describe(rrule, &crate::Spanish), expected, "{rrule}");
+    }
+}
+
+#[cfg(feature = "locale-nl")]
+#[test]
+fn describes_rrules_in_dutch() {
+    let tests = [
+        (
+            "FREQ=DAILY;INTERVAL=3;COUNT=4",
+            "om de 3 dagen om 09:00 4 keer",
+        ),
+        (
+            "FREQ=WEEKLY;BYDAY=SA,SU",
+            "elke week op zaterdag en zondag om 09:00",
+        ),
+        (
+            "FREQ=MONTHLY;BYDAY=-2FR",
+            "elke maand op de voorlaatste vrijdag om 09:00",
+        ),
+        (
+            "FREQ=YEARLY;BYYEARDAY=100;UNTIL=20301231T000000Z",
+            "elk jaar op de 100e dag van het jaar om 09:00 tot en met 31 december 2030",
+        ),
+        ("FREQ=HOURLY;INTERVAL=2", "om de 2 uur"),
+    ];
+    for (rrule, expected) in tests {
+        assert_eq!(describe(rrule, &crate::Dutch), expected, "{rrule}");
+    }
+}
+
+#[test]
+fn parses_rrules_from_english() {
+    let reference = Tz::UTC.with_ymd_and_hms(2023, 5, 10, 8, 0, 0).unwrap();
+    let tests = [
+        (
+            "every other Tuesday until March 3",
+            "FREQ=WEEKLY;UNTIL=20240303T235959Z;INTERVAL=2;BYDAY=TU",
+        ),
+        ("the last Friday of each month", "FREQ=MONTHLY;BYDAY=-1FR"),
+        (
+            "every weekday at 9am",
+            "FREQ=WEEKLY;BYHOUR=9;BYMINUTE=0;BYSECOND=0;BYDAY=MO,TU,WE,TH,FR",
+        ),
+        ("Daily for 10 times", "FREQ=DAILY;COUNT=10"),
+        ("every 3 hours", "FREQ=HOURLY;INTERVAL=3"),
+        ("every second", "FREQ=SECONDLY"),
+        (
+            "every 2nd week on Mon, Wed and Fri",
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR",
+        ),
+        (
+            "every month on the 1st and 15th at noon and 6pm",
+            "FREQ=MONTHLY;BYMONTHDAY=1,15;BYHOUR=12,18;BYMINUTE=0;BYSECOND=0",
+        ),
+        (
+            "every month on the second to last day",
+            "FREQ=MONTHLY;BYMONTHDAY=-2",
+        ),
+        (
+            "every year on the 2nd Sunday of May and June",
+            "FREQ=YEARLY;BYMONTH=5,6;BYDAY=2SU",
+        ),
+        (
+            "every year on the 100th day of the year, 5 occurrences",
+            "FREQ=YEARLY;COUNT=5;BYYEARDAY=100",
+        ),
+        (
+            "weekly on weekends at 10:30 until 2023-12-31",
+            "FREQ=WEEKLY;UNTIL=20231231T235959Z;BYHOUR=10;BYMINUTE=30;BYSECOND=0;BYDAY=SA,SU",
+        ),
+    ];
+    for (text, expected) in tests {
+        let (rrule, dt_start) = RRule::from_text(text, &reference).unwrap();
+        assert_eq!(rrule.to_string(), expected, "{text}");
+        assert_eq!(dt_start, None, "{text}");
+    }
+}
+
+#[test]
+fn parses_start_date_from_english() {
+    let reference = Tz::Europe__Berlin
+        .with_ymd_and_hms(2023, 5, 10, 8, 0, 0)
+        .unwrap();
+    let (rrule, dt_start) = RRule::from_text(
+        "every day at 9:30 starting 1st of June until June 3",
+        &reference,
+    )
+    .unwrap();
+    assert_eq!(
+        dt_start,
+        Some(
+            Tz::Europe__Berlin
+                .with_ymd_and_hms(2023, 6, 1, 9, 30, 0)
+                .unwrap()
+        )
+    );
+    // The end of June 3 in Berlin.
+    assert_eq!(
+        rrule.get_until(),
+        Some(&Tz::UTC.with_ymd_and_hms(2023, 6, 3, 21, 59, 59).unwrap())
+    );
+
+    let rrule_set =
+        RRuleSet::from_text("every day at 9:30 starting June 1 until June 3", &reference).unwrap();
+    assert_eq!(
+        rrule_set.all(10).dates,
+        vec![
+            Tz::Europe__Berlin
+                .with_ymd_and_hms(2023, 6, 1, 9, 30, 0)
+                .unwrap(),
+            Tz::Europe__Berlin
+                .with_ymd_and_hms(2023, 6, 2, 9, 30, 0)
+                .unwrap(),
+            Tz::Europe__Berlin
+                .with_ymd_and_hms(2023, 6, 3, 9, 30, 0)
+                .unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn rejects_invalid_english() {
+    let reference = Tz::UTC.with_ymd_and_hms(2023, 5, 10, 8, 0, 0).unwrap();
+    let invalid = |token: &str, position| ParseError::InvalidRecurrenceText {
+        token: token.into(),
+        position,
+    };
+    let tests = [
+        ("every fortnight", invalid("fortnight", 6)),
+        ("every day at 25:00", invalid("25:00", 13)),
+        ("every day until Smarch 3", invalid("Smarch", 16)),
+        ("every day daily", invalid("daily", 10)),
+        ("every day at 9am and 5:30pm", invalid("at", 10)),
+        ("every 0 days", invalid("0", 6)),
+        (
+            "every day until +262142-12-31",
+            invalid("+262142-12-31", 16),
+        ),
+        (
+            "the last Friday",
+            ParseError::IncompleteRecurrenceText("the last Friday".into()),
+        ),
+        (
+            "every day until",
+            ParseError::IncompleteRecurrenceText("every day until".into()),
+        ),
+    ];
+    for (text, expected) in tests {
+        assert_eq!(RRule::from_text(text, &reference), Err(expected), "{text}");
+    }
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "xcal")]
+
+use crate::{NWeekday, ParseError, RRule, RRuleError, RRuleSet, Unvalidated, Weekday};
+
+#[test]
+fn rrule_set_to_xcal() {
+    let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20240101T090000\n\
+        RRULE:FREQ=MONTHLY;INTERVAL=2;BYDAY=1MO,-1FR;UNTIL=20241231T230000Z\n\
+        EXDATE;TZID=Europe/Berlin:20240301T090000"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        rrule_set.to_xcal(),
+        "<properties>\
+            <dtstart>\
+                <parameters><tzid><text>Europe/Berlin</text></tzid></parameters>\
+                <date-time>2024-01-01T09:00:00</date-time>\
+            </dtstart>\
+            <rrule><recur>\
+                <freq>MONTHLY</freq>\
+                <until>2024-12-31T23:00:00Z</until>\
+                <interval>2</interval>\
+                <bysecond>0</bysecond>\
+                <byminute>0</byminute>\
+                <byhour>9</byhour>\
+                <byday>-1FR</byday>\
+                <byday>1MO</byday>\
+            </recur></rrule>\
+            <exdate>\
+                <parameters><tzid><text>Europe/Berlin</text></tzid></parameters>\
+                <date-time>2024-03-01T09:00:00</date-time>\
+            </exdate>\
+        </properties>"
+    );
+}
+
+#[test]
+fn rrule_set_to_and_from_xcal() {
+    let test_cases = [
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=5",
+        "DTSTART;TZID=America/New_York:20120201T093000\nDTEND;TZID=America/New_York:20120201T103000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=1,-1;WKST=SU",
+        "DTSTART;VALUE=DATE:20120201\nRRULE:FREQ=YEARLY;UNTIL=20150201\nEXDATE;VALUE=DATE:20130201",
+        "DTSTART:20120201T093000Z\nDURATION:PT1H\nRDATE;VALUE=PERIOD:20120301T100000Z/PT2H,20120302T100000Z/20120302T110000Z",
+    ];
+
+    for test_str in test_cases {
+        let rrule_set: RRuleSet = test_str.parse().unwrap();
+        let xcal = rrule_set.to_xcal();
+
+        assert_eq!(RRuleSet::from_xcal(&xcal).unwrap(), rrule_set, "{xcal}");
+    }
+}
+
+#[test]
+fn rrule_set_from_xcal_document() {
+    let xcal = r#"<?xml version="1.0" encoding="utf-8"?>
+<icalendar xmlns="urn:ietf:params:xml:ns:icalendar-2.0">
+  <vcalendar>
+    <components>
+      <vevent>
+        <properties>
+          <summary><text>Planning &amp; review</text></summary>
+          <dtstart>
+            <parameters><tzid><text>America/New_York</text></tzid></parameters>
+            <date-time>2024-01-02T10:00:00</date-time>
+          </dtstart>
+          <rrule>
+            <recur>
+              <freq>WEEKLY</freq>
+              <count>4</count>
+              <byday>TU</byday>
+              <byday>TH</byday>
+            </recur>
+          </rrule>
+        </properties>
+      </vevent>
+    </components>
+  </vcalendar>
+</icalendar>"#;
+
+    let rrule_set = RRuleSet::from_xcal(xcal).unwrap();
+    let expected: RRuleSet = "DTSTART;TZID=America/New_York:20240102T100000\n\
+        RRULE:FREQ=WEEKLY;COUNT=4;BYDAY=TU,TH"
+        .parse()
+        .unwrap();
+    assert_eq!(rrule_set, expected);
+}
+
+#[test]
+fn rrule_from_xcal() {
+    let rrule = RRule::<Unvalidated>::from_xcal(
+        "<rrule><recur><freq>YEARLY</freq><count>3</count><bymonth>1</bymonth><bymonth>7</bymonth>\
+         <byday>1MO</byday><until>2030-01-01</until></recur></rrule>",
+    )
+    .unwrap();
+
+    assert_eq!(rrule.get_count(), Some(3));
+    assert_eq!(rrule.get_by_month(), &[1, 7]);
+    assert_eq!(rrule.get_by_weekday(), &[NWeekday::Nth(1, Weekday::Mon)]);
+    assert!(rrule.get_until().is_some());
+}
+
+#[test]
+fn rejects_invalid_xcal() {
+    let start = "<dtstart><date-time>2024-01-01T09:00:00Z</date-time></dtstart>";
+    let test_cases = [
+        (
+            format!("<properties>{start}<rrule><recur><freq>DAILY</freq><byhour>24</byhour></recur></rrule></properties>"),
+            ParseError::InvalidByHour("24".into()),
+        ),
+        (
+            format!("<properties>{start}<rrule><text>FREQ=DAILY</text></rrule></properties>"),
+            ParseError::UnsupportedValueType {
+                property: "RRULE".into(),
+                value: "TEXT".into(),
+            },
+        ),
+        (
+            format!("<properties>{start}<rdate><date>2024-01-02</date><date-time>2024-01-03T09:00:00Z</date-time></rdate></properties>"),
+            ParseError::InvalidXCalProperty("rdate".into()),
+        ),
+        (
+            "<properties><rrule><recur><freq>DAILY</freq></recur></rrule></properties>".into(),
+            ParseError::MissingStartDate,
+        ),
+        (
+            format!("<vevent>{start}</vevent>"),
+            ParseError::MissingProperty("properties".into()),
+        ),
+        (
+            format!("<properties>{start}<rdate><period><start>2024-01-01T09:00:00Z</start><duration>P99999999D</duration></period></rdate></properties>"),
+            ParseError::InvalidPeriod("20240101T090000Z/P99999999D".into()),
+        ),
+        (
+            format!("<properties>{start}<duration><duration>P99999999D</duration></duration><rrule><recur><freq>DAILY</freq></recur></rrule></properties>"),
+            ParseError::InvalidDuration("P99999999D".into()),
+        ),
+    ];
+
+    for (xcal, error) in test_cases {
+        assert_eq!(
+            RRuleSet::from_xcal(&xcal).unwrap_err(),
+            RRuleError::ParserError(error)
+        );
+    }
+    assert!(matches!(
+        RRuleSet::from_xcal("<properties>"),
+        Err(RRuleError::ParserError(ParseError::InvalidXml(_)))
+    ));
+}
+
+#[test]
+fn rejects_deeply_nested_xcal() {
+    let nested = format!("{}{}", "<x>".repeat(10_000), "</x>".repeat(10_000));
+    assert_eq!(
+        RRuleSet::from_xcal(&nested).unwrap_err(),
+        RRuleError::ParserError(ParseError::InvalidXml(
+            "elements nested more than 64 levels deep at position 192".into()
+        ))
+    );
+
+    // Documents within the limit are parsed as usual.
+    let start = "<dtstart><date-time>2024-01-01T09:00:00Z</date-time></dtstart>";
+    let properties =
+        format!("<properties>{start}<rrule><recur><freq>DAILY</freq></recur></rrule></properties>");
+    let xcal = format!("{}{properties}{}", "<x>".repeat(50), "</x>".repeat(50));
+    assert!(RRuleSet::from_xcal(&xcal).is_ok());
+}
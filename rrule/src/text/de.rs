@@ -0,0 +1,229 @@
+use super::{month_of, Locale};
+use crate::{Frequency, NWeekday};
+use chrono::{Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+/// Describes recurrences in German.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct German;
+
+impl German {
+    fn ordinals(&self, numbers: &[i32]) -> String {
+        self.join(numbers.iter().map(|n| self.ordinal(*n)).collect())
+    }
+
+    /// The ordinal after a definite article in the nominative singular, like "das letzte".
+    fn nominative_ordinal(n: i32) -> String {
+        match n {
+            -1 => "letzte".into(),
+            -2 => "vorletzte".into(),
+            ..=-3 => format!("{}.-letzte", -n),
+            _ => format!("{n}."),
+        }
+    }
+}
+
+impl Locale for German {
+    fn and(&self) -> String {
+        "und".into()
+    }
+
+    fn month(&self, month: Month) -> String {
+        match month {
+            Month::January => "Januar",
+            Month::February => "Februar",
+            Month::March => "März",
+            Month::April => "April",
+            Month::May => "Mai",
+            Month::June => "Juni",
+            Month::July => "Juli",
+            Month::August => "August",
+            Month::September => "September",
+            Month::October => "Oktober",
+            Month::November => "November",
+            Month::December => "Dezember",
+        }
+        .into()
+    }
+
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Mon => "Montag",
+            Weekday::Tue => "Dienstag",
+            Weekday::Wed => "Mittwoch",
+            Weekday::Thu => "Donnerstag",
+            Weekday::Fri => "Freitag",
+            Weekday::Sat => "Samstag",
+            Weekday::Sun => "Sonntag",
+        }
+        .into()
+    }
+
+    /// The ordinal in the dative, like "am letzten".
+    fn ordinal(&self, n: i32) -> String {
+        match n {
+            -1 => "letzten".into(),
+            -2 => "vorletzten".into(),
+            ..=-3 => format!("{}.-letzten", -n),
+            _ => format!("{n}."),
+        }
+    }
+
+    fn unit(&self, freq: Frequency, count: u32) -> String {
+        let (singular, plural) = match freq {
+            Frequency::Yearly => ("Jahr", "Jahre"),
+            Frequency::Monthly => ("Monat", "Monate"),
+            Frequency::Weekly => ("Woche", "Wochen"),
+            Frequency::Daily => ("Tag", "Tage"),
+            Frequency::Hourly => ("Stunde", "Stunden"),
+            Frequency::Minutely => ("Minute", "Minuten"),
+            Frequency::Secondly => ("Sekunde", "Sekunden"),
+        };
+        if count == 1 { singular } else { plural }.into()
+    }
+
+    fn every(&self, freq: Frequency, interval: u16) -> String {
+        if interval != 1 {
+            return format!("alle {interval} {}", self.unit(freq, interval.into()));
+        }
+        let every = match freq {
+            Frequency::Yearly => "jedes",
+            Frequency::Monthly | Frequency::Daily => "jeden",
+            _ => "jede",
+        };
+        format!("{every} {}", self.unit(freq, 1))
+    }
+
+    fn every_weekday(&self) -> String {
+        "jeden Werktag".into()
+    }
+
+    fn in_months(&self, months: &[Month]) -> String {
+        let months = months.iter().map(|month| self.month(*month)).collect();
+        format!("im {}", self.join(months))
+    }
+
+    fn in_weeks(&self, weeks: &[i32]) -> String {
+        let week = if weeks.len() == 1 {
+            "in Kalenderwoche"
+        } else {
+            "in den Kalenderwochen"
+        };
+        let weeks = weeks.iter().map(ToString::to_string).collect();
+        format!("{week} {}", self.join(weeks))
+    }
+
+    fn on_year_days(&self, days: &[i32]) -> String {
+        format!("am {} Tag des Jahres", self.ordinals(days))
+    }
+
+    fn on_month_days(&self, days: &[i32]) -> String {
+        let day = if days.iter().any(|day| *day < 0) {
+            " Tag"
+        } else {
+            ""
+        };
+        format!("am {}{day}", self.ordinals(days))
+    }
+
+    fn on_weekdays(&self, weekdays: &[NWeekday]) -> String {
+        let weekdays = weekdays
+            .iter()
+            .map(|weekday| match weekday {
+                NWeekday::Every(weekday) => self.weekday(*weekday),
+                NWeekday::Nth(n, weekday) => {
+                    format!("{} {}", self.ordinal((*n).into()), self.weekday(*weekday))
+                }
+            })
+            .collect();
+        format!("am {}", self.join(weekdays))
+    }
+
+    fn easter(&self, days: i16) -> String {
+        let n = days.unsigned_abs();
+        let day = if n == 1 { "Tag" } else { "Tage" };
+        match days {
+            0 => "am Ostersonntag".into(),
+            1.. => format!("{n} {day} nach Ostersonntag"),
+            _ => format!("{n} {day} vor Ostersonntag"),
+        }
+    }
+
+    fn at_times(&self, times: &[NaiveTime]) -> String {
+        let times = times.iter().map(|time| self.time(*time)).collect();
+        format!("um {}", self.join(times))
+    }
+
+    fn at_time_units(&self, units: &[(Frequency, Vec<u8>)]) -> String {
+        let units = units
+            .iter()
+            .map(|(unit, values)| {
+                let count = u32::try_from(values.len()).unwrap_or(u32::MAX);
+                let values = values.iter().map(ToString::to_string).collect();
+                format!("{} {}", self.unit(*unit, count), self.join(values))
+            })
+            .collect();
+        format!("in {}", self.join(units))
+    }
+
+    fn set_positions(&self, positions: &[i32], freq: Frequency) -> String {
+        let period = match freq {
+            Frequency::Yearly => "jedes Jahres",
+            Frequency::Monthly => "jedes Monats",
+            Frequency::Weekly => "jeder Woche",
+            Frequency::Daily => "jedes Tages",
+            Frequency::Hourly => "jeder Stunde",
+            Frequency::Minutely => "jeder Minute",
+            Frequency::Secondly => "jeder Sekunde",
+        };
+        if let [position] = positions {
+            format!(
+                "nur das {} Vorkommen {period}",
+                Self::nominative_ordinal(*position)
+            )
+        } else {
+            // The plural takes the same form as the dative.
+            format!("nur die {} Vorkommen {period}", self.ordinals(positions))
+        }
+    }
+
+    fn count(&self, count: u32) -> String {
+        if count == 1 {
+            "einmal".into()
+        } else {
+            format!("{count} Mal")
+        }
+    }
+
+    fn until(&self, until: NaiveDate) -> String {
+        format!("bis zum {}", self.date(until))
+    }
+
+    fn date(&self, date: NaiveDate) -> String {
+        format!(
+            "{}. {} {}",
+            date.day(),
+            self.month(month_of(date)),
+            date.year()
+        )
+    }
+
+    fn date_time(&self, date_time: NaiveDateTime) -> String {
+        format!(
+            "{} um {}",
+            self.date(date_time.date()),
+            self.time(date_time.time())
+        )
+    }
+
+    fn on_dates(&self, dates: Vec<String>) -> String {
+        format!("am {}", self.join(dates))
+    }
+
+    fn also(&self, dates: String) -> String {
+        format!("zusätzlich {dates}")
+    }
+
+    fn except(&self, exclusions: Vec<String>) -> String {
+        format!("außer {}", self.join(exclusions))
+    }
+}
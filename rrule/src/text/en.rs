@@ -0,0 +1,245 @@
+use super::{month_of, Locale};
+use crate::{Frequency, NWeekday};
+use chrono::{Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+/// Describes recurrences in English.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct English;
+
+impl English {
+    fn ordinals(&self, numbers: &[i32]) -> String {
+        self.join(numbers.iter().map(|n| self.ordinal(*n)).collect())
+    }
+}
+
+impl Locale for English {
+    fn and(&self) -> String {
+        "and".into()
+    }
+
+    fn month(&self, month: Month) -> String {
+        month.name().into()
+    }
+
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Saturday",
+            Weekday::Sun => "Sunday",
+        }
+        .into()
+    }
+
+    fn ordinal(&self, n: i32) -> String {
+        match n {
+            -1 => "last".into(),
+            ..=-2 => format!("{} to last", self.ordinal(-n)),
+            _ => {
+                let suffix = match (n % 10, n % 100) {
+                    (1, 11) | (2, 12) | (3, 13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{n}{suffix}")
+            }
+        }
+    }
+
+    fn unit(&self, freq: Frequency, count: u32) -> String {
+        let unit = match freq {
+            Frequency::Yearly => "year",
+            Frequency::Monthly => "month",
+            Frequency::Weekly => "week",
+            Frequency::Daily => "day",
+            Frequency::Hourly => "hour",
+            Frequency::Minutely => "minute",
+            Frequency::Secondly => "second",
+        };
+        if count == 1 {
+            unit.into()
+        } else {
+            format!("{unit}s")
+        }
+    }
+
+    fn every(&self, freq: Frequency, interval: u16) -> String {
+        if interval == 1 {
+            format!("every {}", self.unit(freq, 1))
+        } else {
+            format!("every {interval} {}", self.unit(freq, interval.into()))
+        }
+    }
+
+    fn every_weekday(&self) -> String {
+        "every weekday".into()
+    }
+
+    fn in_months(&self, months: &[Month]) -> String {
+        let months = months.iter().map(|month| self.month(*month)).collect();
+        format!("in {}", self.join(months))
+    }
+
+    fn in_weeks(&self, weeks: &[i32]) -> String {
+        let week = if weeks.len() == 1 { "week" } else { "weeks" };
+        let weeks = weeks.iter().map(ToString::to_string).collect();
+        format!("in {week} {}", self.join(weeks))
+    }
+
+    fn on_year_days(&self, days: &[i32]) -> String {
+        format!("on the {} day of the year", self.ordinals(days))
+    }
+
+    fn on_month_days(&self, days: &[i32]) -> String {
+        let day = if days.iter().any(|day| *day < 0) {
+            " day"
+        } else {
+            ""
+        };
+        format!("on the {}{day}", self.ordinals(days))
+    }
+
+    fn on_weekdays(&self, weekdays: &[NWeekday]) -> String {
+        let weekdays = weekdays
+            .iter()
+            .map(|weekday| match weekday {
+                NWeekday::Every(weekday) => self.weekday(*weekday),
+                NWeekday::Nth(n, weekday) => {
+                    format!(
+                        "the {} {}",
+                        self.ordinal((*n).into()),
+                        self.weekday(*weekday)
+                    )
+                }
+            })
+            .collect();
+        format!("on {}", self.join(weekdays))
+    }
+
+    fn easter(&self, days: i16) -> String {
+        let n = days.unsigned_abs();
+        let day = if n == 1 { "day" } else { "days" };
+        match days {
+            0 => "on Easter Sunday".into(),
+            1.. => format!("{n} {day} after Easter Sunday"),
+            _ => format!("{n} {day} before Easter Sunday"),
+        }
+    }
+
+    fn at_times(&self, times: &[NaiveTime]) -> String {
+        let times = times.iter().map(|time| self.time(*time)).collect();
+        format!("at {}", self.join(times))
+    }
+
+    fn at_time_units(&self, units: &[(Frequency, Vec<u8>)]) -> String {
+        let units = units
+            .iter()
+            .map(|(unit, values)| {
+                let count = u32::try_from(values.len()).unwrap_or(u32::MAX);
+                let values = values.iter().map(ToString::to_string).collect();
+                format!("{} {}", self.unit(*unit, count), self.join(values))
+            })
+            .collect();
+        format!("at {}", self.join(units))
+    }
+
+    fn set_positions(&self, positions: &[i32], freq: Frequency) -> String {
+        let occurrence = if positions.len() == 1 {
+            "occurrence"
+        } else {
+            "occurrences"
+        };
+        format!(
+            "only the {} {occurrence} of each {}",
+            self.ordinals(positions),
+            self.unit(freq, 1)
+        )
+    }
+
+    fn count(&self, count: u32) -> String {
+        if count == 1 {
+            "once".into()
+        } else {
+            format!("for {count} times")
+        }
+    }
+
+    fn until(&self, until: NaiveDate) -> String {
+        format!("until {}", self.date(until))
+    }
+
+    fn date(&self, date: NaiveDate) -> String {
+        format!(
+            "{} {}, {}",
+            self.month(month_of(date)),
+            date.day(),
+            date.year()
+        )
+    }
+
+    fn date_time(&self, date_time: NaiveDateTime) -> String {
+        format!(
+            "{} at {}",
+            self.date(date_time.date()),
+            self.time(date_time.time())
+        )
+    }
+
+    fn on_dates(&self, dates: Vec<String>) -> String {
+        format!("on {}", self.join(dates))
+    }
+
+    fn also(&self, dates: String) -> String {
+        format!("also {dates}")
+    }
+
+    fn except(&self, exclusions: Vec<String>) -> String {
+        format!("except {}", self.join(exclusions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordinals() {
+        let ordinals = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101, 111, -1, -2, -3]
+            .map(|n| English.ordinal(n))
+            .to_vec();
+        assert_eq!(
+            ordinals,
+            vec![
+                "1st",
+                "2nd",
+                "3rd",
+                "4th",
+                "11th",
+                "12th",
+                "13th",
+                "21st",
+                "22nd",
+                "23rd",
+                "101st",
+                "111th",
+                "last",
+                "2nd to last",
+                "3rd to last"
+            ]
+        );
+    }
+
+    #[test]
+    fn joins_lists() {
+        let list = |items: &[&str]| English.join(items.iter().map(ToString::to_string).collect());
+        assert_eq!(list(&[]), "");
+        assert_eq!(list(&["a"]), "a");
+        assert_eq!(list(&["a", "b"]), "a and b");
+        assert_eq!(list(&["a", "b", "c"]), "a, b and c");
+    }
+}
@@ -0,0 +1,235 @@
+use super::{month_of, Locale};
+use crate::{Frequency, NWeekday};
+use chrono::{Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+/// Describes recurrences in Spanish.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Spanish;
+
+impl Spanish {
+    fn ordinals(&self, numbers: &[i32]) -> String {
+        self.join(numbers.iter().map(|n| self.ordinal(*n)).collect())
+    }
+
+    /// The feminine ordinal, like "la última".
+    fn feminine_ordinal(n: i32) -> String {
+        match n {
+            -1 => "última".into(),
+            -2 => "penúltima".into(),
+            ..=-3 => format!("{}ª desde el final", -n),
+            _ => format!("{n}ª"),
+        }
+    }
+}
+
+impl Locale for Spanish {
+    fn and(&self) -> String {
+        "y".into()
+    }
+
+    fn month(&self, month: Month) -> String {
+        match month {
+            Month::January => "enero",
+            Month::February => "febrero",
+            Month::March => "marzo",
+            Month::April => "abril",
+            Month::May => "mayo",
+            Month::June => "junio",
+            Month::July => "julio",
+            Month::August => "agosto",
+            Month::September => "septiembre",
+            Month::October => "octubre",
+            Month::November => "noviembre",
+            Month::December => "diciembre",
+        }
+        .into()
+    }
+
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Mon => "lunes",
+            Weekday::Tue => "martes",
+            Weekday::Wed => "miércoles",
+            Weekday::Thu => "jueves",
+            Weekday::Fri => "viernes",
+            Weekday::Sat => "sábado",
+            Weekday::Sun => "domingo",
+        }
+        .into()
+    }
+
+    fn ordinal(&self, n: i32) -> String {
+        match n {
+            -1 => "último".into(),
+            -2 => "penúltimo".into(),
+            ..=-3 => format!("{}º desde el final", -n),
+            _ => format!("{n}º"),
+        }
+    }
+
+    fn unit(&self, freq: Frequency, count: u32) -> String {
+        let (singular, plural) = match freq {
+            Frequency::Yearly => ("año", "años"),
+            Frequency::Monthly => ("mes", "meses"),
+            Frequency::Weekly => ("semana", "semanas"),
+            Frequency::Daily => ("día", "días"),
+            Frequency::Hourly => ("hora", "horas"),
+            Frequency::Minutely => ("minuto", "minutos"),
+            Frequency::Secondly => ("segundo", "segundos"),
+        };
+        if count == 1 { singular } else { plural }.into()
+    }
+
+    fn every(&self, freq: Frequency, interval: u16) -> String {
+        if interval == 1 {
+            format!("cada {}", self.unit(freq, 1))
+        } else {
+            format!("cada {interval} {}", self.unit(freq, interval.into()))
+        }
+    }
+
+    fn every_weekday(&self) -> String {
+        "de lunes a viernes".into()
+    }
+
+    fn in_months(&self, months: &[Month]) -> String {
+        let months = months.iter().map(|month| self.month(*month)).collect();
+        format!("en {}", self.join(months))
+    }
+
+    fn in_weeks(&self, weeks: &[i32]) -> String {
+        let week = if weeks.len() == 1 {
+            "en la semana"
+        } else {
+            "en las semanas"
+        };
+        let weeks = weeks.iter().map(ToString::to_string).collect();
+        format!("{week} {}", self.join(weeks))
+    }
+
+    fn on_year_days(&self, days: &[i32]) -> String {
+        format!("el {} día del año", self.ordinals(days))
+    }
+
+    fn on_month_days(&self, days: &[i32]) -> String {
+        let days = days
+            .iter()
+            .map(|day| {
+                if *day < 0 {
+                    format!("el {} día", self.ordinal(*day))
+                } else {
+                    format!("el día {day}")
+                }
+            })
+            .collect();
+        self.join(days)
+    }
+
+    fn on_weekdays(&self, weekdays: &[NWeekday]) -> String {
+        let weekdays = weekdays
+            .iter()
+            .map(|weekday| match weekday {
+                NWeekday::Every(weekday) => format!("el {}", self.weekday(*weekday)),
+                NWeekday::Nth(n, weekday) => {
+                    format!(
+                        "el {} {}",
+                        self.ordinal((*n).into()),
+                        self.weekday(*weekday)
+                    )
+                }
+            })
+            .collect();
+        self.join(weekdays)
+    }
+
+    fn easter(&self, days: i16) -> String {
+        let n = days.unsigned_abs();
+        let day = if n == 1 { "día" } else { "días" };
+        match days {
+            0 => "el domingo de Pascua".into(),
+            1.. => format!("{n} {day} después del domingo de Pascua"),
+            _ => format!("{n} {day} antes del domingo de Pascua"),
+        }
+    }
+
+    fn at_times(&self, times: &[NaiveTime]) -> String {
+        let times = times.iter().map(|time| self.time(*time)).collect();
+        format!("a las {}", self.join(times))
+    }
+
+    fn at_time_units(&self, units: &[(Frequency, Vec<u8>)]) -> String {
+        let units = units
+            .iter()
+            .map(|(unit, values)| {
+                let at = match (unit, values.len()) {
+                    (Frequency::Hourly, 1) => "en la hora",
+                    (Frequency::Hourly, _) => "en las horas",
+                    (Frequency::Minutely, 1) => "en el minuto",
+                    (Frequency::Minutely, _) => "en los minutos",
+                    (_, 1) => "en el segundo",
+                    _ => "en los segundos",
+                };
+                let values = values.iter().map(ToString::to_string).collect();
+                format!("{at} {}", self.join(values))
+            })
+            .collect();
+        self.join(units)
+    }
+
+    fn set_positions(&self, positions: &[i32], freq: Frequency) -> String {
+        let plural = positions.len() > 1;
+        let positions = self.join(
+            positions
+                .iter()
+                .map(|position| Self::feminine_ordinal(*position))
+                .collect(),
+        );
+        let period = self.unit(freq, 1);
+        if plural {
+            format!("solo las {positions} ocurrencias de cada {period}")
+        } else {
+            format!("solo la {positions} ocurrencia de cada {period}")
+        }
+    }
+
+    fn count(&self, count: u32) -> String {
+        if count == 1 {
+            "una vez".into()
+        } else {
+            format!("{count} veces")
+        }
+    }
+
+    fn until(&self, until: NaiveDate) -> String {
+        format!("hasta el {}", self.date(until))
+    }
+
+    fn date(&self, date: NaiveDate) -> String {
+        format!(
+            "{} de {} de {}",
+            date.day(),
+            self.month(month_of(date)),
+            date.year()
+        )
+    }
+
+    fn date_time(&self, date_time: NaiveDateTime) -> String {
+        format!(
+            "{} a las {}",
+            self.date(date_time.date()),
+            self.time(date_time.time())
+        )
+    }
+
+    fn on_dates(&self, dates: Vec<String>) -> String {
+        format!("el {}", self.join(dates))
+    }
+
+    fn also(&self, dates: String) -> String {
+        format!("además {dates}")
+    }
+
+    fn except(&self, exclusions: Vec<String>) -> String {
+        format!("excepto {}", self.join(exclusions))
+    }
+}
@@ -0,0 +1,260 @@
+use super::{month_of, Locale};
+use crate::{Frequency, NWeekday};
+use chrono::{Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+/// Describes recurrences in French.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct French;
+
+impl French {
+    fn ordinals(&self, numbers: &[i32]) -> String {
+        self.join(numbers.iter().map(|n| self.ordinal(*n)).collect())
+    }
+
+    /// The feminine ordinal, like "la dernière".
+    fn feminine_ordinal(n: i32) -> String {
+        match n {
+            1 => "1re".into(),
+            -1 => "dernière".into(),
+            -2 => "avant-dernière".into(),
+            ..=-3 => format!("{}e en partant de la fin", -n),
+            _ => format!("{n}e"),
+        }
+    }
+
+    /// The day of the month, like "1er" or "15".
+    fn day(n: u32) -> String {
+        if n == 1 {
+            "1er".into()
+        } else {
+            n.to_string()
+        }
+    }
+}
+
+impl Locale for French {
+    fn and(&self) -> String {
+        "et".into()
+    }
+
+    fn month(&self, month: Month) -> String {
+        match month {
+            Month::January => "janvier",
+            Month::February => "février",
+            Month::March => "mars",
+            Month::April => "avril",
+            Month::May => "mai",
+            Month::June => "juin",
+            Month::July => "juillet",
+            Month::August => "août",
+            Month::September => "septembre",
+            Month::October => "octobre",
+            Month::November => "novembre",
+            Month::December => "décembre",
+        }
+        .into()
+    }
+
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Mon => "lundi",
+            Weekday::Tue => "mardi",
+            Weekday::Wed => "mercredi",
+            Weekday::Thu => "jeudi",
+            Weekday::Fri => "vendredi",
+            Weekday::Sat => "samedi",
+            Weekday::Sun => "dimanche",
+        }
+        .into()
+    }
+
+    fn ordinal(&self, n: i32) -> String {
+        match n {
+            1 => "1er".into(),
+            -1 => "dernier".into(),
+            -2 => "avant-dernier".into(),
+            ..=-3 => format!("{}e en partant de la fin", -n),
+            _ => format!("{n}e"),
+        }
+    }
+
+    fn unit(&self, freq: Frequency, count: u32) -> String {
+        let (singular, plural) = match freq {
+            Frequency::Yearly => ("an", "ans"),
+            Frequency::Monthly => ("mois", "mois"),
+            Frequency::Weekly => ("semaine", "semaines"),
+            Frequency::Daily => ("jour", "jours"),
+            Frequency::Hourly => ("heure", "heures"),
+            Frequency::Minutely => ("minute", "minutes"),
+            Frequency::Secondly => ("seconde", "secondes"),
+        };
+        if count == 1 { singular } else { plural }.into()
+    }
+
+    fn every(&self, freq: Frequency, interval: u16) -> String {
+        if interval == 1 {
+            let unit = match freq {
+                Frequency::Yearly => "année".into(),
+                _ => self.unit(freq, 1),
+            };
+            return format!("chaque {unit}");
+        }
+        let every = match freq {
+            Frequency::Weekly | Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                "toutes les"
+            }
+            _ => "tous les",
+        };
+        format!("{every} {interval} {}", self.unit(freq, interval.into()))
+    }
+
+    fn every_weekday(&self) -> String {
+        "du lundi au vendredi".into()
+    }
+
+    fn in_months(&self, months: &[Month]) -> String {
+        let months = months.iter().map(|month| self.month(*month)).collect();
+        format!("en {}", self.join(months))
+    }
+
+    fn in_weeks(&self, weeks: &[i32]) -> String {
+        let week = if weeks.len() == 1 {
+            "semaine"
+        } else {
+            "semaines"
+        };
+        let weeks = weeks.iter().map(ToString::to_string).collect();
+        format!("en {week} {}", self.join(weeks))
+    }
+
+    fn on_year_days(&self, days: &[i32]) -> String {
+        format!("le {} jour de l'année", self.ordinals(days))
+    }
+
+    fn on_month_days(&self, days: &[i32]) -> String {
+        let day = if days.iter().any(|day| *day < 0) {
+            " jour"
+        } else {
+            ""
+        };
+        let days = days
+            .iter()
+            .map(|day| match u32::try_from(*day) {
+                Ok(day) => Self::day(day),
+                Err(_) => self.ordinal(*day),
+            })
+            .collect();
+        format!("le {}{day}", self.join(days))
+    }
+
+    fn on_weekdays(&self, weekdays: &[NWeekday]) -> String {
+        let weekdays = weekdays
+            .iter()
+            .map(|weekday| match weekday {
+                NWeekday::Every(weekday) => format!("le {}", self.weekday(*weekday)),
+                NWeekday::Nth(n, weekday) => {
+                    format!(
+                        "le {} {}",
+                        self.ordinal((*n).into()),
+                        self.weekday(*weekday)
+                    )
+                }
+            })
+            .collect();
+        self.join(weekdays)
+    }
+
+    fn easter(&self, days: i16) -> String {
+        let n = days.unsigned_abs();
+        let day = if n == 1 { "jour" } else { "jours" };
+        match days {
+            0 => "le dimanche de Pâques".into(),
+            1.. => format!("{n} {day} après le dimanche de Pâques"),
+            _ => format!("{n} {day} avant le dimanche de Pâques"),
+        }
+    }
+
+    fn at_times(&self, times: &[NaiveTime]) -> String {
+        let times = times.iter().map(|time| self.time(*time)).collect();
+        format!("à {}", self.join(times))
+    }
+
+    fn at_time_units(&self, units: &[(Frequency, Vec<u8>)]) -> String {
+        let units = units
+            .iter()
+            .map(|(unit, values)| {
+                let at = match (unit, values.len()) {
+                    (Frequency::Hourly, 1) => "à l'heure",
+                    (Frequency::Hourly, _) => "aux heures",
+                    (Frequency::Minutely, 1) => "à la minute",
+                    (Frequency::Minutely, _) => "aux minutes",
+                    (_, 1) => "à la seconde",
+                    _ => "aux secondes",
+                };
+                let values = values.iter().map(ToString::to_string).collect();
+                format!("{at} {}", self.join(values))
+            })
+            .collect();
+        self.join(units)
+    }
+
+    fn set_positions(&self, positions: &[i32], freq: Frequency) -> String {
+        let period = match freq {
+            Frequency::Yearly => "année".into(),
+            _ => self.unit(freq, 1),
+        };
+        let plural = positions.len() > 1;
+        let positions = self.join(
+            positions
+                .iter()
+                .map(|position| Self::feminine_ordinal(*position))
+                .collect(),
+        );
+        if plural {
+            format!("uniquement les {positions} occurrences de chaque {period}")
+        } else {
+            format!("uniquement la {positions} occurrence de chaque {period}")
+        }
+    }
+
+    fn count(&self, count: u32) -> String {
+        if count == 1 {
+            "une seule fois".into()
+        } else {
+            format!("{count} fois")
+        }
+    }
+
+    fn until(&self, until: NaiveDate) -> String {
+        format!("jusqu'au {}", self.date(until))
+    }
+
+    fn date(&self, date: NaiveDate) -> String {
+        format!(
+            "{} {} {}",
+            Self::day(date.day()),
+            self.month(month_of(date)),
+            date.year()
+        )
+    }
+
+    fn date_time(&self, date_time: NaiveDateTime) -> String {
+        format!(
+            "{} à {}",
+            self.date(date_time.date()),
+            self.time(date_time.time())
+        )
+    }
+
+    fn on_dates(&self, dates: Vec<String>) -> String {
+        format!("le {}", self.join(dates))
+    }
+
+    fn also(&self, dates: String) -> String {
+        format!("ainsi que {dates}")
+    }
+
+    fn except(&self, exclusions: Vec<String>) -> String {
+        format!("sauf {}", self.join(exclusions))
+    }
+}
@@ -0,0 +1,334 @@
+//! Describes recurrence rules in natural language, like "every 2 weeks on Monday and Friday".
+//!
+//! The sentence is built by the [`Locale`] from the parts of the rule.
+
+#[cfg(feature = "locale-de")]
+mod de;
+mod en;
+#[cfg(feature = "locale-es")]
+mod es;
+#[cfg(feature = "locale-fr")]
+mod fr;
+#[cfg(feature = "locale-nl")]
+mod nl;
+
+#[cfg(feature = "locale-de")]
+pub use de::German;
+pub use en::English;
+#[cfg(feature = "locale-es")]
+pub use es::Spanish;
+#[cfg(feature = "locale-fr")]
+pub use fr::French;
+#[cfg(feature = "locale-nl")]
+pub use nl::Dutch;
+
+use crate::{Frequency, NWeekday, RRule, RRuleSet, Tz};
+use chrono::{DateTime, Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+/// The words and grammar used to describe recurrences in a language.
+///
+/// Each method returns a part of the description, which is joined with the other parts
+/// by spaces. Negative numbers count from the end, where `-1` is the last one.
+///
+/// [`English`] is always available, other languages are enabled with the `locale-de`,
+/// `locale-es`, `locale-fr` and `locale-nl` features.
+pub trait Locale {
+    /// The word used to join the last two items of a list, like "and".
+    fn and(&self) -> String;
+
+    /// Joins the items of a list, like "a, b and c".
+    fn join(&self, mut items: Vec<String>) -> String {
+        match items.len() {
+            0 => String::new(),
+            1 => items.remove(0),
+            _ => {
+                let last = items.pop().unwrap_or_default();
+                format!("{} {} {last}", items.join(", "), self.and())
+            }
+        }
+    }
+
+    /// The name of a month, like "January".
+    fn month(&self, month: Month) -> String;
+
+    /// The name of a weekday, like "Monday".
+    fn weekday(&self, weekday: Weekday) -> String;
+
+    /// The ordinal of a number, like "2nd" or "last".
+    fn ordinal(&self, n: i32) -> String;
+
+    /// The unit of a frequency in the singular or plural form for `count`, like "week"
+    /// or "weeks".
+    fn unit(&self, freq: Frequency, count: u32) -> String;
+
+    /// The start of the description, like "every 2 weeks".
+    fn every(&self, freq: Frequency, interval: u16) -> String;
+
+    /// The start of the description of a weekly rule on Monday to Friday, like "every weekday".
+    fn every_weekday(&self) -> String;
+
+    /// `BYMONTH`, like "in January and March".
+    fn in_months(&self, months: &[Month]) -> String;
+
+    /// `BYWEEKNO`, like "in weeks 1 and 5".
+    fn in_weeks(&self, weeks: &[i32]) -> String;
+
+    /// `BYYEARDAY`, like "on the 1st and last day of the year".
+    fn on_year_days(&self, days: &[i32]) -> String;
+
+    /// `BYMONTHDAY`, like "on the 1st and 15th".
+    fn on_month_days(&self, days: &[i32]) -> String;
+
+    /// `BYDAY`, like "on Monday and the last Friday".
+    fn on_weekdays(&self, weekdays: &[NWeekday]) -> String;
+
+    /// `BYEASTER`, like "on Easter Sunday" or "2 days after Easter Sunday".
+    fn easter(&self, days: i16) -> String;
+
+    /// The times of a rule with a frequency of daily or lower, like "at 09:00 and 17:30".
+    fn at_times(&self, times: &[NaiveTime]) -> String;
+
+    /// `BYHOUR`, `BYMINUTE` and `BYSECOND` of a rule with a frequency of hourly or higher,
+    /// with the unit of their values, like "at hours 9 and 17 and minute 15".
+    fn at_time_units(&self, units: &[(Frequency, Vec<u8>)]) -> String;
+
+    /// `BYSETPOS` for the periods of `freq`, like "only the last occurrence of each month".
+    fn set_positions(&self, positions: &[i32], freq: Frequency) -> String;
+
+    /// `COUNT`, like "for 5 times".
+    fn count(&self, count: u32) -> String;
+
+    /// `UNTIL`, like "until January 31, 2023".
+    fn until(&self, until: NaiveDate) -> String;
+
+    /// A date, like "January 31, 2023".
+    fn date(&self, date: NaiveDate) -> String;
+
+    /// A time, like "09:00".
+    fn time(&self, time: NaiveTime) -> String {
+        if time.second() == 0 {
+            time.format("%H:%M").to_string()
+        } else {
+            time.format("%H:%M:%S").to_string()
+        }
+    }
+
+    /// A date with a time, like "January 31, 2023 at 09:00".
+    fn date_time(&self, date_time: NaiveDateTime) -> String;
+
+    /// The `RDATE`s and `EXDATE`s, like "on January 4, 2023 and January 5, 2023".
+    fn on_dates(&self, dates: Vec<String>) -> String;
+
+    /// The `RDATE`s of a set with rrules, like "also on January 4, 2023".
+    fn also(&self, dates: String) -> String;
+
+    /// The `EXRULE`s and `EXDATE`s, like "except every Sunday and on January 4, 2023".
+    fn except(&self, exclusions: Vec<String>) -> String;
+}
+
+const WEEKDAYS: [Weekday; 5] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+];
+
+/// Returns the text of `rrule`. The times are left out if `date_only` is set.
+pub(crate) fn rrule_to_text(rrule: &RRule, date_only: bool, locale: &dyn Locale) -> String {
+    let mut parts = vec![];
+    if is_every_weekday(rrule) {
+        parts.push(locale.every_weekday());
+    } else {
+        parts.push(locale.every(rrule.freq, rrule.interval));
+    }
+
+    if !rrule.by_month.is_empty() {
+        let months = rrule
+            .by_month
+            .iter()
+            .filter_map(|month| Month::try_from(*month).ok())
+            .collect::<Vec<_>>();
+        parts.push(locale.in_months(&months));
+    }
+
+    if !rrule.by_week_no.is_empty() {
+        let weeks = sorted(rrule.by_week_no.iter().map(|week| i32::from(*week)));
+        parts.push(locale.in_weeks(&weeks));
+    }
+
+    if !rrule.by_year_day.is_empty() {
+        let days = sorted(rrule.by_year_day.iter().map(|day| i32::from(*day)));
+        parts.push(locale.on_year_days(&days));
+    }
+
+    if !rrule.by_month_day.is_empty() || !rrule.by_n_month_day.is_empty() {
+        let days = rrule
+            .by_month_day
+            .iter()
+            .chain(&rrule.by_n_month_day)
+            .map(|day| i32::from(*day));
+        parts.push(locale.on_month_days(&sorted(days)));
+    }
+
+    if !rrule.by_weekday.is_empty() && !is_every_weekday(rrule) {
+        parts.push(locale.on_weekdays(&rrule.by_weekday));
+    }
+
+    #[cfg(feature = "by-easter")]
+    if let Some(by_easter) = rrule.by_easter {
+        parts.push(locale.easter(by_easter));
+    }
+
+    if !date_only {
+        if let Some(times) = times_text(rrule, locale) {
+            parts.push(times);
+        }
+    }
+
+    let mut text = parts.join(" ");
+
+    if !rrule.by_set_pos.is_empty() {
+        let positions = sorted(rrule.by_set_pos.iter().copied());
+        text.push_str(&format!(
+            ", {}",
+            locale.set_positions(&positions, rrule.freq)
+        ));
+    }
+
+    if let Some(count) = rrule.count {
+        text.push_str(&format!(" {}", locale.count(count)));
+    }
+
+    if let Some(until) = &rrule.until {
+        text.push_str(&format!(" {}", locale.until(until.date_naive())));
+    }
+
+    text
+}
+
+/// Returns the text of `rrule_set`, with its rrules, rdates, exrules and exdates.
+pub(crate) fn rrule_set_to_text(rrule_set: &RRuleSet, locale: &dyn Locale) -> String {
+    let date_only = rrule_set.date_only;
+    let on_dates = |mut dates: Vec<DateTime<Tz>>| {
+        dates.sort();
+        let dates = dates
+            .iter()
+            .map(|date| {
+                if date_only {
+                    locale.date(date.date_naive())
+                } else {
+                    locale.date_time(date.naive_local())
+                }
+            })
+            .collect();
+        locale.on_dates(dates)
+    };
+
+    let mut parts = vec![];
+    let rrules = rrule_set
+        .rrule
+        .iter()
+        .map(|rrule| rrule_to_text(rrule, date_only, locale))
+        .collect::<Vec<_>>();
+    if !rrules.is_empty() {
+        parts.push(locale.join(rrules));
+    }
+
+    let rdates = rrule_set
+        .rdate
+        .iter()
+        .copied()
+        .chain(
+            rrule_set
+                .rdate_period
+                .iter()
+                .map(|period| *period.get_start()),
+        )
+        .collect::<Vec<_>>();
+    if !rdates.is_empty() {
+        let rdates = on_dates(rdates);
+        if parts.is_empty() {
+            parts.push(rdates);
+        } else {
+            parts.push(locale.also(rdates));
+        }
+    }
+
+    let mut exclusions = rrule_set
+        .exrule
+        .iter()
+        .map(|exrule| rrule_to_text(exrule, date_only, locale))
+        .collect::<Vec<_>>();
+    if !rrule_set.exdate.is_empty() {
+        exclusions.push(on_dates(rrule_set.exdate.clone()));
+    }
+    if !exclusions.is_empty() {
+        parts.push(locale.except(exclusions));
+    }
+
+    parts.join(", ")
+}
+
+/// Returns `true` if the rule is weekly on Monday to Friday.
+fn is_every_weekday(rrule: &RRule) -> bool {
+    rrule.freq == Frequency::Weekly
+        && rrule.interval == 1
+        && rrule.by_weekday.len() == WEEKDAYS.len()
+        && WEEKDAYS
+            .iter()
+            .all(|weekday| rrule.by_weekday.contains(&NWeekday::Every(*weekday)))
+}
+
+/// Returns the text of the times of the rule, if it has any.
+fn times_text(rrule: &RRule, locale: &dyn Locale) -> Option<String> {
+    if rrule.freq < Frequency::Hourly {
+        let times = rrule
+            .by_hour
+            .iter()
+            .flat_map(|hour| {
+                rrule.by_minute.iter().flat_map(move |minute| {
+                    rrule.by_second.iter().filter_map(move |second| {
+                        NaiveTime::from_hms_opt(
+                            u32::from(*hour),
+                            u32::from(*minute),
+                            u32::from(*second),
+                        )
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        return (!times.is_empty()).then(|| locale.at_times(&times));
+    }
+
+    let only_zero = |values: &[u8]| values == [0];
+    // Occurrences at the start of the hour don't need their minute.
+    let on_the_hour = only_zero(&rrule.by_minute) && only_zero(&rrule.by_second);
+    let mut units = vec![];
+    if !rrule.by_hour.is_empty() {
+        units.push((Frequency::Hourly, rrule.by_hour.clone()));
+    }
+    if !rrule.by_minute.is_empty() && !on_the_hour {
+        units.push((Frequency::Minutely, rrule.by_minute.clone()));
+    }
+    if !rrule.by_second.is_empty() && !only_zero(&rrule.by_second) {
+        units.push((Frequency::Secondly, rrule.by_second.clone()));
+    }
+    (!units.is_empty()).then(|| locale.at_time_units(&units))
+}
+
+/// Sorts the numbers by counting from the start first and then from the end, like
+/// `1, 15, -1, -2`.
+fn sorted(numbers: impl Iterator<Item = i32>) -> Vec<i32> {
+    let mut numbers = numbers.collect::<Vec<_>>();
+    numbers.sort_by_key(|n| (*n < 0, n.abs()));
+    numbers
+}
+
+/// Returns the month of `date`.
+fn month_of(date: NaiveDate) -> Month {
+    u8::try_from(date.month())
+        .ok()
+        .and_then(|month| Month::try_from(month).ok())
+        .expect("the month of a date is always valid")
+}
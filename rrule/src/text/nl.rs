@@ -0,0 +1,205 @@
+use super::{month_of, Locale};
+use crate::{Frequency, NWeekday};
+use chrono::{Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+/// Describes recurrences in Dutch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Dutch;
+
+impl Dutch {
+    fn ordinals(&self, numbers: &[i32]) -> String {
+        self.join(numbers.iter().map(|n| self.ordinal(*n)).collect())
+    }
+}
+
+impl Locale for Dutch {
+    fn and(&self) -> String {
+        "en".into()
+    }
+
+    fn month(&self, month: Month) -> String {
+        match month {
+            Month::January => "januari",
+            Month::February => "februari",
+            Month::March => "maart",
+            Month::April => "april",
+            Month::May => "mei",
+            Month::June => "juni",
+            Month::July => "juli",
+            Month::August => "augustus",
+            Month::September => "september",
+            Month::October => "oktober",
+            Month::November => "november",
+            Month::December => "december",
+        }
+        .into()
+    }
+
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Mon => "maandag",
+            Weekday::Tue => "dinsdag",
+            Weekday::Wed => "woensdag",
+            Weekday::Thu => "donderdag",
+            Weekday::Fri => "vrijdag",
+            Weekday::Sat => "zaterdag",
+            Weekday::Sun => "zondag",
+        }
+        .into()
+    }
+
+    fn ordinal(&self, n: i32) -> String {
+        match n {
+            -1 => "laatste".into(),
+            -2 => "voorlaatste".into(),
+            ..=-3 => format!("{}e van achteren", -n),
+            _ => format!("{n}e"),
+        }
+    }
+
+    fn unit(&self, freq: Frequency, count: u32) -> String {
+        let (singular, plural) = match freq {
+            // Units of time keep the singular after a number.
+            Frequency::Yearly => ("jaar", "jaar"),
+            Frequency::Monthly => ("maand", "maanden"),
+            Frequency::Weekly => ("week", "weken"),
+            Frequency::Daily => ("dag", "dagen"),
+            Frequency::Hourly => ("uur", "uur"),
+            Frequency::Minutely => ("minuut", "minuten"),
+            Frequency::Secondly => ("seconde", "seconden"),
+        };
+        if count == 1 { singular } else { plural }.into()
+    }
+
+    fn every(&self, freq: Frequency, interval: u16) -> String {
+        if interval != 1 {
+            return format!("om de {interval} {}", self.unit(freq, interval.into()));
+        }
+        let every = match freq {
+            Frequency::Yearly | Frequency::Hourly => "elk",
+            _ => "elke",
+        };
+        format!("{every} {}", self.unit(freq, 1))
+    }
+
+    fn every_weekday(&self) -> String {
+        "elke werkdag".into()
+    }
+
+    fn in_months(&self, months: &[Month]) -> String {
+        let months = months.iter().map(|month| self.month(*month)).collect();
+        format!("in {}", self.join(months))
+    }
+
+    fn in_weeks(&self, weeks: &[i32]) -> String {
+        let week = if weeks.len() == 1 { "week" } else { "weken" };
+        let weeks = weeks.iter().map(ToString::to_string).collect();
+        format!("in {week} {}", self.join(weeks))
+    }
+
+    fn on_year_days(&self, days: &[i32]) -> String {
+        format!("op de {} dag van het jaar", self.ordinals(days))
+    }
+
+    fn on_month_days(&self, days: &[i32]) -> String {
+        let day = if days.iter().any(|day| *day < 0) {
+            " dag"
+        } else {
+            ""
+        };
+        format!("op de {}{day}", self.ordinals(days))
+    }
+
+    fn on_weekdays(&self, weekdays: &[NWeekday]) -> String {
+        let weekdays = weekdays
+            .iter()
+            .map(|weekday| match weekday {
+                NWeekday::Every(weekday) => self.weekday(*weekday),
+                NWeekday::Nth(n, weekday) => {
+                    format!(
+                        "de {} {}",
+                        self.ordinal((*n).into()),
+                        self.weekday(*weekday)
+                    )
+                }
+            })
+            .collect();
+        format!("op {}", self.join(weekdays))
+    }
+
+    fn easter(&self, days: i16) -> String {
+        let n = days.unsigned_abs();
+        let day = if n == 1 { "dag" } else { "dagen" };
+        match days {
+            0 => "op Paaszondag".into(),
+            1.. => format!("{n} {day} na Paaszondag"),
+            _ => format!("{n} {day} voor Paaszondag"),
+        }
+    }
+
+    fn at_times(&self, times: &[NaiveTime]) -> String {
+        let times = times.iter().map(|time| self.time(*time)).collect();
+        format!("om {}", self.join(times))
+    }
+
+    fn at_time_units(&self, units: &[(Frequency, Vec<u8>)]) -> String {
+        let units = units
+            .iter()
+            .map(|(unit, values)| {
+                let count = u32::try_from(values.len()).unwrap_or(u32::MAX);
+                let values = values.iter().map(ToString::to_string).collect();
+                format!("{} {}", self.unit(*unit, count), self.join(values))
+            })
+            .collect();
+        format!("op {}", self.join(units))
+    }
+
+    fn set_positions(&self, positions: &[i32], freq: Frequency) -> String {
+        format!(
+            "alleen de {} keer per {}",
+            self.ordinals(positions),
+            self.unit(freq, 1)
+        )
+    }
+
+    fn count(&self, count: u32) -> String {
+        if count == 1 {
+            "eenmalig".into()
+        } else {
+            format!("{count} keer")
+        }
+    }
+
+    fn until(&self, until: NaiveDate) -> String {
+        format!("tot en met {}", self.date(until))
+    }
+
+    fn date(&self, date: NaiveDate) -> String {
+        format!(
+            "{} {} {}",
+            date.day(),
+            self.month(month_of(date)),
+            date.year()
+        )
+    }
+
+    fn date_time(&self, date_time: NaiveDateTime) -> String {
+        format!(
+            "{} om {}",
+            self.date(date_time.date()),
+            self.time(date_time.time())
+        )
+    }
+
+    fn on_dates(&self, dates: Vec<String>) -> String {
+        format!("op {}", self.join(dates))
+    }
+
+    fn also(&self, dates: String) -> String {
+        format!("ook {dates}")
+    }
+
+    fn except(&self, exclusions: Vec<String>) -> String {
+        format!("behalve {}", self.join(exclusions))
+    }
+}
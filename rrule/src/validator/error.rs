@@ -32,6 +32,8 @@ pub enum ValidationError {
     },
     #[error("`{by_rule}` can not be used with the current frequency ({freq}).")]
     InvalidByRuleAndFrequency { by_rule: String, freq: Frequency },
+    #[error("`{0}` can not be used when `DTSTART` is a date.")]
+    ByTimeRuleWithDateStart(String),
     #[error("`UNTIL` is `{until}`, but `DTSTART` (`{dt_start}`) is later. That should not be happening.")]
     UntilBeforeStart { until: String, dt_start: String },
     #[error(
@@ -59,4 +61,12 @@ pub enum ValidationError {
         until_tz: String,
         expected: Vec<String>,
     },
+    #[error(
+        "`{0}` is not a recurrence of the set after `DTSTART`, so the set can't be split there."
+    )]
+    InvalidSplitDate(String),
+    #[error(
+        "`{rrule}` has no end and doesn't recur in step with `{dt}`, so the set can't be split there."
+    )]
+    MisalignedSplitDate { rrule: String, dt: String },
 }
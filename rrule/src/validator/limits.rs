@@ -0,0 +1,110 @@
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate};
+
+use super::ValidationError;
+
+/// Limits used when validating and iterating rrules.
+///
+/// They prevent rules from generating dates far outside of the expected range, and
+/// iterations from looping for a long time without finding a date.
+/// The defaults are the limits described in 'validator limits' in the docs.
+///
+/// # Usage
+///
+/// ```
+/// use rrule::{Limits, RRuleSet};
+///
+/// let limits = Limits::default().year_range(1900..=2100).max_iterations(1_000);
+/// let rrule_set = RRuleSet::from_str_with_limits(
+///     "DTSTART:20000101T090000Z\nRRULE:FREQ=YEARLY",
+///     limits,
+/// )
+/// .unwrap();
+/// assert_eq!(rrule_set.all(u16::MAX).dates.len(), 101);
+///
+/// assert!(RRuleSet::from_str_with_limits(
+///     "DTSTART:22000101T090000Z\nRRULE:FREQ=YEARLY",
+///     limits,
+/// )
+/// .is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub(crate) min_year: i32,
+    pub(crate) max_year: i32,
+    pub(crate) max_interval: u16,
+    pub(crate) max_iterations: u32,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            min_year: -10_000,
+            max_year: 10_000,
+            max_interval: u16::MAX,
+            max_iterations: 100_000,
+        }
+    }
+}
+
+impl Limits {
+    /// Sets the range of years that `DTSTART` and the generated dates can be in.
+    /// The range is clamped to the years supported by `chrono`.
+    /// Default: `-10_000..=10_000`
+    #[must_use]
+    pub fn year_range(mut self, years: RangeInclusive<i32>) -> Self {
+        self.min_year = (*years.start()).max(NaiveDate::MIN.year());
+        self.max_year = (*years.end()).min(NaiveDate::MAX.year());
+        self
+    }
+
+    /// Sets the highest `INTERVAL` that an rrule can have.
+    /// Default: `u16::MAX`, so any interval is accepted.
+    #[must_use]
+    pub fn max_interval(mut self, max_interval: u16) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Sets the number of periods a limited iterator looks through for the next date,
+    /// before it stops.
+    /// Default: `100_000`
+    #[must_use]
+    pub fn max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Returns the range of years that `DTSTART` and the generated dates can be in.
+    #[must_use]
+    pub fn get_year_range(&self) -> RangeInclusive<i32> {
+        self.min_year..=self.max_year
+    }
+
+    /// Returns the highest `INTERVAL` that an rrule can have.
+    #[must_use]
+    pub fn get_max_interval(&self) -> u16 {
+        self.max_interval
+    }
+
+    /// Returns the number of periods a limited iterator looks through for the next date.
+    #[must_use]
+    pub fn get_max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    /// Checks that `year` is in the year range.
+    pub(crate) fn check_year(&self, year: i32) -> Result<(), ValidationError> {
+        if self.get_year_range().contains(&year) {
+            Ok(())
+        } else {
+            Err(ValidationError::InvalidFieldValueRange {
+                field: "YEAR".into(),
+                value: year.to_string(),
+                start_idx: self.min_year.to_string(),
+                end_idx: self.max_year.to_string(),
+            })
+        }
+    }
+}
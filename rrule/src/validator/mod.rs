@@ -2,7 +2,7 @@
 //! And in turn create a [`crate::core::RRule<Validated>`].
 
 mod error;
+mod limits;
 pub(crate) mod validate_rrule;
 pub use error::ValidationError;
-
-pub(crate) use validate_rrule::YEAR_RANGE;
+pub use limits::Limits;
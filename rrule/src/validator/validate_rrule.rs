@@ -1,5 +1,7 @@
 use std::ops::RangeInclusive;
 
+use chrono::Timelike;
+
 use crate::{Frequency, NWeekday, RRule, Tz, Unvalidated};
 
 use super::ValidationError;
@@ -8,10 +10,6 @@ use super::ValidationError;
 /// Range: `1..=12`
 pub(crate) static MONTH_RANGE: RangeInclusive<u8> = 1..=12;
 
-/// Range of values that a year can be.
-/// Range: `-10_000..=10_000`
-pub(crate) static YEAR_RANGE: RangeInclusive<i32> = -10_000..=10_000;
-
 type Validator =
     &'static dyn Fn(&RRule<Unvalidated>, &chrono::DateTime<Tz>) -> Result<(), ValidationError>;
 
@@ -44,6 +42,37 @@ pub(crate) fn validate_rrule_forced(
         .try_for_each(|validator| validator(rrule, dt_start))
 }
 
+// Rrules of a date-only set (`DTSTART;VALUE=DATE`):
+// - Can't have `BYHOUR`, `BYMINUTE` or `BYSECOND`, as defined by the RFC. Validated rrules
+//   have the time of `dt_start` in them, which is the start of its day.
+pub(crate) fn validate_date_only_times<S>(
+    rrule: &RRule<S>,
+    dt_start: &chrono::DateTime<Tz>,
+) -> Result<(), ValidationError> {
+    let time_rules = [
+        ("BYHOUR", &rrule.by_hour, dt_start.hour(), Frequency::Hourly),
+        (
+            "BYMINUTE",
+            &rrule.by_minute,
+            dt_start.minute(),
+            Frequency::Minutely,
+        ),
+        (
+            "BYSECOND",
+            &rrule.by_second,
+            dt_start.second(),
+            Frequency::Secondly,
+        ),
+    ];
+    for (by_rule, values, start, freq) in time_rules {
+        let is_from_start = rrule.freq < freq && values.iter().map(|v| u32::from(*v)).eq([start]);
+        if !values.is_empty() && !is_from_start {
+            return Err(ValidationError::ByTimeRuleWithDateStart(by_rule.into()));
+        }
+    }
+    Ok(())
+}
+
 // Until:
 // - Timezones are correctly synced as specified in the RFC
 // - Value should be later than `dt_start`.